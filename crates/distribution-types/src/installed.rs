@@ -290,6 +290,32 @@ impl InstalledDist {
         }
     }
 
+    /// Return the wheel tags (e.g., `cp312-cp312-manylinux_2_17_x86_64`) of the distribution, as
+    /// recorded in the `WHEEL` file of a `.dist-info` directory.
+    ///
+    /// Returns `None` if the distribution wasn't installed from a wheel, or lacks a `WHEEL` file.
+    pub fn tags(&self) -> Result<Option<Vec<String>>> {
+        match self {
+            Self::Registry(_) | Self::Url(_) => {
+                let path = self.path().join("WHEEL");
+                let contents = match fs::read_to_string(&path) {
+                    Ok(contents) => contents,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                    Err(err) => return Err(err.into()),
+                };
+                Ok(Some(
+                    contents
+                        .lines()
+                        .filter_map(|line| line.split_once(':'))
+                        .filter(|(key, _)| key.trim() == "Tag")
+                        .map(|(_, value)| value.trim().to_string())
+                        .collect(),
+                ))
+            }
+            Self::EggInfoFile(_) | Self::EggInfoDirectory(_) | Self::LegacyEditable(_) => Ok(None),
+        }
+    }
+
     /// Return the `INSTALLER` of the distribution.
     pub fn installer(&self) -> Result<Option<String>> {
         let path = self.path().join("INSTALLER");
@@ -1,7 +1,7 @@
 //! Like `wheel.rs`, but for installing wheels that have already been unzipped, rather than
 //! reading from a zip file.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::SystemTime;
 
@@ -17,6 +17,7 @@ use pep440_rs::Version;
 use pypi_types::DirectUrl;
 use uv_normalize::PackageName;
 
+use crate::record::write_record;
 use crate::script::{scripts_from_ini, Script};
 use crate::wheel::{
     extra_dist_info, install_data, parse_metadata, parse_wheel_file, read_record_file,
@@ -24,7 +25,8 @@ use crate::wheel::{
 };
 use crate::{Error, Layout};
 
-/// Install the given wheel to the given venv
+/// Install the given wheel to the given venv, returning the path to the installed `.dist-info`
+/// directory.
 ///
 /// The caller must ensure that the wheel is compatible to the environment.
 ///
@@ -39,7 +41,7 @@ pub fn install_wheel(
     direct_url: Option<&DirectUrl>,
     installer: Option<&str>,
     link_mode: LinkMode,
-) -> Result<(), Error> {
+) -> Result<PathBuf, Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
     let metadata = dist_info_metadata(&dist_info_prefix, &wheel)?;
     let (name, version) = parse_metadata(&dist_info_prefix, &metadata)?;
@@ -131,16 +133,48 @@ pub fn install_wheel(
     )?;
 
     debug!(name, "Writing record");
-    let mut record_writer = csv::WriterBuilder::new()
-        .has_headers(false)
-        .escape(b'"')
-        .from_path(site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD")))?;
-    record.sort();
-    for entry in record {
-        record_writer.serialize(entry)?;
-    }
+    let dist_info = site_packages.join(format!("{dist_info_prefix}.dist-info"));
+    write_record(&dist_info.join("RECORD"), record)?;
 
-    Ok(())
+    Ok(dist_info)
+}
+
+/// Returns the paths of the files that installing the given (unzipped) wheel would write to
+/// `site-packages`, as listed in its `RECORD`.
+///
+/// Excludes the `.dist-info` directory itself, along with any files installed outside of
+/// `site-packages` (e.g., scripts and data files).
+pub fn site_packages_files(
+    layout: &Layout,
+    wheel: impl AsRef<Path>,
+) -> Result<Vec<PathBuf>, Error> {
+    let dist_info_prefix = find_dist_info(&wheel)?;
+
+    let wheel_text = fs::read_to_string(
+        wheel
+            .as_ref()
+            .join(format!("{dist_info_prefix}.dist-info/WHEEL")),
+    )?;
+    let site_packages = match parse_wheel_file(&wheel_text)? {
+        LibKind::Pure => &layout.scheme.purelib,
+        LibKind::Plat => &layout.scheme.platlib,
+    };
+
+    let mut record_file = File::open(
+        wheel
+            .as_ref()
+            .join(format!("{dist_info_prefix}.dist-info/RECORD")),
+    )?;
+    let record = read_record_file(&mut record_file)?;
+
+    let dist_info = format!("{dist_info_prefix}.dist-info/");
+    let data = format!("{dist_info_prefix}.data/");
+    Ok(record
+        .into_iter()
+        .filter(|entry| entry.is_in_site_packages())
+        .filter(|entry| !entry.path.starts_with(&dist_info) && !entry.path.starts_with(&data))
+        .map(|entry| site_packages.join(entry.path))
+        .collect())
 }
 
 /// Find the `dist-info` directory in an unzipped wheel.
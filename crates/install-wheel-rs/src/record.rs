@@ -1,4 +1,12 @@
+use std::path::{Component, Path, PathBuf};
+
+use data_encoding::BASE64URL_NOPAD;
+use fs_err as fs;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::wheel::read_record_file;
+use crate::Error;
 
 /// Line in a RECORD file
 /// <https://www.python.org/dev/peps/pep-0376/#record>
@@ -7,10 +15,98 @@ use serde::{Deserialize, Serialize};
 /// tqdm/cli.py,sha256=x_c8nmc4Huc-lKEsAXj78ZiyqSJ9hJ71j7vltY67icw,10509
 /// tqdm-4.62.3.dist-info/RECORD,,
 /// ```
-#[derive(Deserialize, Serialize, PartialOrd, PartialEq, Ord, Eq)]
-pub(crate) struct RecordEntry {
-    pub(crate) path: String,
-    pub(crate) hash: Option<String>,
-    #[allow(dead_code)]
-    pub(crate) size: Option<u64>,
+#[derive(Debug, Clone, Deserialize, Serialize, PartialOrd, PartialEq, Ord, Eq)]
+pub struct RecordEntry {
+    /// The path of the file, relative to the `site-packages` directory.
+    pub path: String,
+    pub hash: Option<String>,
+    pub size: Option<u64>,
+}
+
+impl RecordEntry {
+    /// Returns `true` if the entry refers to a path within the `site-packages` directory (as
+    /// opposed to, e.g., a script in `bin`).
+    pub fn is_in_site_packages(&self) -> bool {
+        Path::new(&self.path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    }
+}
+
+/// Read the `RECORD` file of the installed distribution at the given `.dist-info` directory.
+pub fn read_record(dist_info: &Path) -> Result<Vec<RecordEntry>, Error> {
+    let record_path = dist_info.join("RECORD");
+    let mut record_file = match fs::File::open(&record_path) {
+        Ok(record_file) => record_file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(Error::MissingRecord(record_path));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    read_record_file(&mut record_file)
+}
+
+/// Add the given files to the `RECORD` of the installed distribution at the given `.dist-info`
+/// directory, e.g., to record bytecode that was compiled after installation.
+///
+/// The files must be absolute paths within the `site-packages` directory that contains the
+/// `.dist-info` directory. Files that are already recorded are skipped.
+///
+/// Returns the number of entries added to the `RECORD`.
+pub fn extend_record(dist_info: &Path, files: &[PathBuf]) -> Result<usize, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    let mut record = read_record(dist_info)?;
+
+    let mut added = 0;
+    for file in files {
+        let Ok(relative) = file.strip_prefix(site_packages) else {
+            return Err(Error::RecordFile(format!(
+                "{} is not in {}",
+                file.display(),
+                site_packages.display()
+            )));
+        };
+        let path = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if record.iter().any(|entry| entry.path == path) {
+            continue;
+        }
+
+        let content = fs::read(file)?;
+        let hash = Sha256::new().chain_update(&content).finalize();
+        record.push(RecordEntry {
+            path,
+            hash: Some(format!("sha256={}", BASE64URL_NOPAD.encode(&hash))),
+            size: Some(content.len() as u64),
+        });
+        added += 1;
+    }
+
+    if added > 0 {
+        write_record(&dist_info.join("RECORD"), record)?;
+    }
+
+    Ok(added)
+}
+
+/// Write the `RECORD` file, sorting its entries.
+pub(crate) fn write_record(path: &Path, mut record: Vec<RecordEntry>) -> Result<(), Error> {
+    let mut record_writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .escape(b'"')
+        .from_path(path)?;
+    record.sort();
+    for entry in record {
+        record_writer.serialize(entry)?;
+    }
+    record_writer.flush()?;
+    Ok(())
 }
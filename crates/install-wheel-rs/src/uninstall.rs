@@ -7,7 +7,7 @@ use std::sync::Mutex;
 use tracing::debug;
 use uv_fs::write_atomic_sync;
 
-use crate::wheel::read_record_file;
+use crate::record::read_record;
 use crate::Error;
 
 /// Uninstall the wheel represented by the given `.dist-info` directory.
@@ -19,17 +19,7 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
     };
 
     // Read the RECORD file.
-    let record = {
-        let record_path = dist_info.join("RECORD");
-        let mut record_file = match fs::File::open(&record_path) {
-            Ok(record_file) => record_file,
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                return Err(Error::MissingRecord(record_path));
-            }
-            Err(err) => return Err(err.into()),
-        };
-        read_record_file(&mut record_file)?
-    };
+    let record = read_record(dist_info)?;
 
     let mut file_count = 0usize;
     let mut dir_count = 0usize;
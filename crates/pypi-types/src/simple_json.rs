@@ -9,10 +9,21 @@ use crate::lenient_requirement::LenientVersionSpecifiers;
 
 /// A collection of "files" from `PyPI`'s JSON API for a single package.
 #[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct SimpleJson {
     /// The list of [`File`]s available for download sorted by filename.
     #[serde(deserialize_with = "sorted_simple_json_files")]
     pub files: Vec<File>,
+    /// The project URLs on other repositories that this project is a mirror of.
+    ///
+    /// <https://peps.python.org/pep-0708/#tracks-metadata>
+    #[serde(default)]
+    pub tracks: Vec<String>,
+    /// The project URLs on other repositories that share this project's namespace.
+    ///
+    /// <https://peps.python.org/pep-0708/#alternate-locations-metadata>
+    #[serde(default)]
+    pub alternate_locations: Vec<String>,
 }
 
 /// Deserializes a sequence of "simple" files from `PyPI` and ensures that they
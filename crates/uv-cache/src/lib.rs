@@ -13,7 +13,7 @@ use tracing::debug;
 pub use archive::ArchiveId;
 use distribution_types::InstalledDist;
 use pypi_types::Metadata23;
-use uv_fs::{cachedir, directories};
+use uv_fs::{cachedir, directories, LockedFile};
 use uv_normalize::PackageName;
 
 pub use crate::by_timestamp::CachedByTimestamp;
@@ -371,7 +371,44 @@ impl Cache {
             }
         }
 
-        // Second, remove any unused archives (by searching for archives that are not symlinked).
+        // Second, remove any cached environments. These are cheap to recreate, and would otherwise
+        // accumulate an environment for every combination of interpreter and requirements.
+        match fs::read_dir(self.bucket(CacheBucket::CachedEnvironments)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let path = entry?.path();
+
+                    // Lockfiles are removed alongside their environments, below. Any lockfile
+                    // without an environment (e.g., if creating the environment failed) is
+                    // removed here, once it's no longer held by a concurrent invocation.
+                    if path.extension().is_some_and(|ext| ext == "lock") {
+                        let environment = path.with_extension("");
+                        if environment.exists() || !path.exists() {
+                            continue;
+                        }
+                        let _lock = LockedFile::acquire(&path, environment.display())?;
+                        if !environment.exists() {
+                            debug!("Removing dangling cache entry: {}", path.display());
+                            remove_lockfile(&path)?;
+                        }
+                        continue;
+                    }
+
+                    // Acquire the environment's lock before removing it, to avoid removing an
+                    // environment that's being created or used. The lockfile itself is removed
+                    // while the lock is still held.
+                    let lockfile = path.with_extension("lock");
+                    let _lock = LockedFile::acquire(&lockfile, path.display())?;
+                    debug!("Removing cached environment: {}", path.display());
+                    summary += rm_rf(&path)?;
+                    remove_lockfile(&lockfile)?;
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
+        // Third, remove any unused archives (by searching for archives that are not symlinked).
         // TODO(charlie): Remove any unused source distributions. This requires introspecting the
         // cache contents, e.g., reading and deserializing the manifests.
         let mut references = FxHashSet::default();
@@ -401,6 +438,15 @@ impl Cache {
     }
 }
 
+/// Remove a lockfile, ignoring it if it was already removed (e.g., by a concurrent prune).
+fn remove_lockfile(path: &Path) -> Result<(), io::Error> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
 /// The different kinds of data in the cache are stored in different bucket, which in our case
 /// are subdirectories of the cache root.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -635,6 +681,13 @@ pub enum CacheBucket {
     Archive,
     /// Ephemeral virtual environments used to execute PEP 517 builds and other operations.
     Environments,
+    /// Reusable virtual environments used to run commands with ephemeral requirements (e.g.,
+    /// `uv run --with`, `uv tool run`), keyed by the interpreter and requirements.
+    ///
+    /// Cache structure:
+    ///  * `cached-environments-v0/<digest(interpreter, requirements)>/`
+    ///  * `cached-environments-v0/<digest(interpreter, requirements)>.lock`
+    CachedEnvironments,
 }
 
 impl CacheBucket {
@@ -643,11 +696,12 @@ impl CacheBucket {
             Self::BuiltWheels => "built-wheels-v3",
             Self::FlatIndex => "flat-index-v0",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v2",
-            Self::Simple => "simple-v9",
+            Self::Interpreter => "interpreter-v3",
+            Self::Simple => "simple-v10",
             Self::Wheels => "wheels-v1",
             Self::Archive => "archive-v0",
             Self::Environments => "environments-v0",
+            Self::CachedEnvironments => "cached-environments-v0",
         }
     }
 
@@ -761,6 +815,9 @@ impl CacheBucket {
             Self::Environments => {
                 // Nothing to do.
             }
+            Self::CachedEnvironments => {
+                // Nothing to do.
+            }
         }
         Ok(summary)
     }
@@ -776,6 +833,7 @@ impl CacheBucket {
             Self::Simple,
             Self::Archive,
             Self::Environments,
+            Self::CachedEnvironments,
         ]
         .iter()
         .copied()
@@ -42,7 +42,7 @@ pub struct FlatIndexEntries {
 
 impl FlatIndexEntries {
     /// Create a [`FlatIndexEntries`] from a list of `--find-links` entries.
-    fn from_entries(entries: Vec<(DistFilename, File, IndexUrl)>) -> Self {
+    pub fn from_entries(entries: Vec<(DistFilename, File, IndexUrl)>) -> Self {
         Self {
             entries,
             offline: false,
@@ -58,7 +58,7 @@ impl FlatIndexEntries {
     }
 
     /// Extend this list of `--find-links` entries with another list.
-    fn extend(&mut self, other: Self) {
+    pub fn extend(&mut self, other: Self) {
         self.entries.extend(other.entries);
         self.offline |= other.offline;
     }
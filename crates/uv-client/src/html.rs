@@ -15,6 +15,10 @@ pub(crate) struct SimpleHtml {
     pub(crate) base: BaseUrl,
     /// The list of [`File`]s available for download sorted by filename.
     pub(crate) files: Vec<File>,
+    /// The project URLs declared via `<meta name="pypi:tracks">`, per PEP 708.
+    pub(crate) tracks: Vec<String>,
+    /// The project URLs declared via `<meta name="pypi:alternate-locations">`, per PEP 708.
+    pub(crate) alternate_locations: Vec<String>,
 }
 
 impl SimpleHtml {
@@ -56,7 +60,36 @@ impl SimpleHtml {
         // probably be the thing that does the sorting.)
         files.sort_unstable_by(|f1, f2| f1.filename.cmp(&f2.filename));
 
-        Ok(Self { base, files })
+        // Parse the PEP 708 `<meta>` tags, which declare relationships to other repositories.
+        let mut tracks = Vec::new();
+        let mut alternate_locations = Vec::new();
+        for meta in dom
+            .nodes()
+            .iter()
+            .filter_map(|node| node.as_tag())
+            .filter(|tag| tag.name().as_bytes() == b"meta")
+        {
+            let Some(Some(name)) = meta.attributes().get("name") else {
+                continue;
+            };
+            let Some(Some(content)) = meta.attributes().get("content") else {
+                continue;
+            };
+            let content = std::str::from_utf8(content.as_bytes())?;
+            let content = html_escape::decode_html_entities(content).into_owned();
+            match name.as_bytes() {
+                b"pypi:tracks" => tracks.push(content),
+                b"pypi:alternate-locations" => alternate_locations.push(content),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            base,
+            files,
+            tracks,
+            alternate_locations,
+        })
     }
 
     /// Parse the `href` from a `<base>` tag.
@@ -323,6 +356,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -381,6 +416,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -442,6 +479,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -500,6 +539,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -558,6 +599,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -614,6 +657,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -670,6 +715,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -760,6 +807,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -816,6 +865,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -924,6 +975,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -1041,6 +1094,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -1109,6 +1164,8 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
@@ -1258,7 +1315,41 @@ mod tests {
                     yanked: None,
                 },
             ],
+            tracks: [],
+            alternate_locations: [],
         }
         "###);
     }
+
+    #[test]
+    fn parse_pep708_metadata() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+  <head>
+    <meta name="pypi:repository-version" content="1.2">
+    <meta name="pypi:tracks" content="https://pypi.org/simple/jinja2/">
+    <meta name="pypi:alternate-locations" content="https://example.com/simple/jinja2/">
+    <meta name="pypi:alternate-locations" content="https://download.pytorch.org/whl/jinja2/">
+  </head>
+  <body>
+    <h1>Links for jinja2</h1>
+    <a href="/whl/Jinja2-3.1.2-py3-none-any.whl">Jinja2-3.1.2-py3-none-any.whl</a><br/>
+  </body>
+</html>
+        "#;
+        let base = Url::parse("https://download.pytorch.org/whl/jinja2/").unwrap();
+        let result = SimpleHtml::parse(text, &base).unwrap();
+        insta::assert_debug_snapshot!((result.tracks, result.alternate_locations), @r###"
+        (
+            [
+                "https://pypi.org/simple/jinja2/",
+            ],
+            [
+                "https://example.com/simple/jinja2/",
+                "https://download.pytorch.org/whl/jinja2/",
+            ],
+        )
+        "###);
+    }
 }
@@ -165,6 +165,19 @@ impl<'a> RegistryClientBuilder<'a> {
     }
 }
 
+/// Format the URL for a project on a PEP 503-compatible index.
+fn project_url(index: &IndexUrl, package_name: &PackageName) -> Url {
+    let mut url: Url = index.clone().into();
+    url.path_segments_mut()
+        .unwrap()
+        .pop_if_empty()
+        .push(package_name.as_ref())
+        // The URL *must* end in a trailing slash for proper relative path behavior
+        // ref https://github.com/servo/rust-url/issues/333
+        .push("");
+    url
+}
+
 /// A client for fetching packages from a `PyPI`-compatible index.
 #[derive(Debug, Clone)]
 pub struct RegistryClient {
@@ -218,16 +231,39 @@ impl RegistryClient {
             return Err(ErrorKind::NoIndex(package_name.to_string()).into());
         }
 
-        let mut results = Vec::new();
+        let mut results: Vec<(IndexUrl, OwnedArchive<SimpleMetadata>)> = Vec::new();
         for index in it {
+            // If we're only using the first match, we can stop here, unless a matching index
+            // declared (via PEP 708) that it shares the project's namespace with another index.
+            let related_only =
+                self.index_strategy == IndexStrategy::FirstIndex && !results.is_empty();
+            if related_only
+                && !results
+                    .iter()
+                    .all(|(_, metadata)| metadata.has_project_relationships())
+            {
+                break;
+            }
+
             match self.simple_single_index(package_name, index).await? {
                 Ok(metadata) => {
-                    results.push((index.clone(), metadata));
-
-                    // If we're only using the first match, we can stop here.
-                    if self.index_strategy == IndexStrategy::FirstIndex {
-                        break;
+                    // When using the first match, only merge in subsequent indexes that every
+                    // index matched so far declared a relationship with.
+                    if related_only {
+                        let url = project_url(index, package_name);
+                        if !results.iter().all(|(prev_index, prev_metadata)| {
+                            prev_metadata.is_related(
+                                &project_url(prev_index, package_name),
+                                &metadata,
+                                &url,
+                            )
+                        }) {
+                            trace!("Ignoring {package_name} from unrelated index: {index}");
+                            continue;
+                        }
                     }
+
+                    results.push((index.clone(), metadata));
                 }
                 Err(CachedClientError::Client(err)) => match err.into_kind() {
                     ErrorKind::Offline(_) => continue,
@@ -267,15 +303,7 @@ impl RegistryClient {
         package_name: &PackageName,
         index: &IndexUrl,
     ) -> Result<Result<OwnedArchive<SimpleMetadata>, CachedClientError<Error>>, Error> {
-        // Format the URL for PyPI.
-        let mut url: Url = index.clone().into();
-        url.path_segments_mut()
-            .unwrap()
-            .pop_if_empty()
-            .push(package_name.as_ref())
-            // The URL *must* end in a trailing slash for proper relative path behavior
-            // ref https://github.com/servo/rust-url/issues/333
-            .push("");
+        let url = project_url(index, package_name);
 
         trace!("Fetching metadata for {package_name} from {url}");
 
@@ -347,7 +375,7 @@ impl RegistryClient {
                         let data: SimpleJson = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
 
-                        SimpleMetadata::from_files(data.files, package_name, &url)
+                        SimpleMetadata::from_json(data, package_name, &url)
                     }
                     MediaType::Html => {
                         let text = response.text().await.map_err(ErrorKind::from)?;
@@ -437,6 +465,11 @@ impl RegistryClient {
                 };
 
                 match location {
+                    // Wheels discovered in the cache (e.g., in `--offline` mode) are already
+                    // unzipped, so read the metadata from the `.dist-info` directory directly.
+                    WheelLocation::Path(path) if path.is_dir() => {
+                        read_flat_metadata(&wheel.filename, built_dist.to_string(), &path)?
+                    }
                     WheelLocation::Path(path) => {
                         let file = fs_err::tokio::File::open(&path)
                             .await
@@ -710,6 +743,22 @@ async fn read_metadata_async_seek(
     Ok(metadata)
 }
 
+/// Read the `METADATA` file from an unzipped wheel.
+fn read_flat_metadata(
+    filename: &WheelFilename,
+    debug_source: String,
+    path: &Path,
+) -> Result<Metadata23, Error> {
+    let dist_info = install_wheel_rs::metadata::find_flat_dist_info(filename, path)
+        .map_err(ErrorKind::DistInfo)?;
+    let contents = install_wheel_rs::metadata::read_dist_info_metadata(&dist_info, path)
+        .map_err(ErrorKind::DistInfo)?;
+    let metadata = Metadata23::parse_metadata(&contents).map_err(|err| {
+        ErrorKind::MetadataParseError(filename.clone(), debug_source, Box::new(err))
+    })?;
+    Ok(metadata)
+}
+
 /// Like [`read_metadata_async_seek`], but doesn't use seek.
 async fn read_metadata_async_stream<R: futures::AsyncRead + Unpin>(
     filename: &WheelFilename,
@@ -809,7 +858,14 @@ pub struct VersionSourceDist {
 )]
 #[archive(check_bytes)]
 #[archive_attr(derive(Debug))]
-pub struct SimpleMetadata(Vec<SimpleMetadatum>);
+pub struct SimpleMetadata {
+    /// The distributions available for the package, grouped by version.
+    versions: Vec<SimpleMetadatum>,
+    /// The project URLs that this project tracks, per PEP 708.
+    tracks: Vec<String>,
+    /// The project URLs that share this project's namespace, per PEP 708.
+    alternate_locations: Vec<String>,
+}
 
 #[derive(Debug, Serialize, Deserialize, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[archive(check_bytes)]
@@ -821,7 +877,7 @@ pub struct SimpleMetadatum {
 
 impl SimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &SimpleMetadatum> {
-        self.0.iter()
+        self.versions.iter()
     }
 
     fn from_files(files: Vec<pypi_types::File>, package_name: &PackageName, base: &Url) -> Self {
@@ -856,23 +912,68 @@ impl SimpleMetadata {
                 }
             }
         }
-        Self(
-            map.into_iter()
+        Self {
+            versions: map
+                .into_iter()
                 .map(|(version, files)| SimpleMetadatum { version, files })
                 .collect(),
+            tracks: Vec::new(),
+            alternate_locations: Vec::new(),
+        }
+    }
+
+    /// Read the [`SimpleMetadata`] from a JSON index.
+    fn from_json(data: SimpleJson, package_name: &PackageName, url: &Url) -> Self {
+        let SimpleJson {
+            files,
+            tracks,
+            alternate_locations,
+        } = data;
+
+        SimpleMetadata::from_files(files, package_name, url).with_project_relationships(
+            tracks,
+            alternate_locations,
+            url,
         )
     }
 
     /// Read the [`SimpleMetadata`] from an HTML index.
     fn from_html(text: &str, package_name: &PackageName, url: &Url) -> Result<Self, Error> {
-        let SimpleHtml { base, files } =
-            SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
-
-        Ok(SimpleMetadata::from_files(
+        let SimpleHtml {
+            base,
             files,
-            package_name,
-            base.as_url(),
-        ))
+            tracks,
+            alternate_locations,
+        } = SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
+
+        Ok(
+            SimpleMetadata::from_files(files, package_name, base.as_url())
+                .with_project_relationships(tracks, alternate_locations, base.as_url()),
+        )
+    }
+
+    /// Attach the PEP 708 `tracks` and `alternate-locations` metadata, resolving any relative URLs
+    /// against the given base.
+    fn with_project_relationships(
+        mut self,
+        tracks: Vec<String>,
+        alternate_locations: Vec<String>,
+        base: &Url,
+    ) -> Self {
+        let resolve = |urls: Vec<String>| {
+            urls.into_iter()
+                .filter_map(|url| match base.join(&url) {
+                    Ok(url) => Some(url.to_string()),
+                    Err(err) => {
+                        warn!("Skipping invalid project URL `{url}`: {err}");
+                        None
+                    }
+                })
+                .collect()
+        };
+        self.tracks = resolve(tracks);
+        self.alternate_locations = resolve(alternate_locations);
+        self
     }
 }
 
@@ -881,17 +982,40 @@ impl IntoIterator for SimpleMetadata {
     type IntoIter = std::vec::IntoIter<SimpleMetadatum>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.versions.into_iter()
     }
 }
 
 impl ArchivedSimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &rkyv::Archived<SimpleMetadatum>> {
-        self.0.iter()
+        self.versions.iter()
     }
 
     pub fn datum(&self, i: usize) -> Option<&rkyv::Archived<SimpleMetadatum>> {
-        self.0.get(i)
+        self.versions.get(i)
+    }
+
+    /// Returns `true` if the project declares any PEP 708 relationships to other repositories.
+    fn has_project_relationships(&self) -> bool {
+        !self.tracks.is_empty() || !self.alternate_locations.is_empty()
+    }
+
+    /// Returns `true` if this project (served at `url`) declares that it shares a namespace with
+    /// the `other` project (served at `other_url`), per PEP 708.
+    ///
+    /// This project must either track the other (i.e., be a mirror of it), or both projects must
+    /// list each other as alternate locations. A project that merely claims to track this one is
+    /// not considered related, as any index could make that claim.
+    fn is_related(&self, url: &Url, other: &ArchivedSimpleMetadata, other_url: &Url) -> bool {
+        fn contains(urls: &[rkyv::string::ArchivedString], url: &Url) -> bool {
+            urls.iter().any(|candidate| {
+                candidate.trim_end_matches('/') == url.as_str().trim_end_matches('/')
+            })
+        }
+
+        contains(&self.tracks, other_url)
+            || (contains(&self.alternate_locations, other_url)
+                && contains(&other.alternate_locations, url))
     }
 }
 
@@ -938,6 +1062,7 @@ mod tests {
     use pypi_types::{JoinRelativeError, SimpleJson};
     use uv_normalize::PackageName;
 
+    use crate::rkyvutil::OwnedArchive;
     use crate::{html::SimpleHtml, SimpleMetadata, SimpleMetadatum};
 
     #[test]
@@ -1014,7 +1139,7 @@ mod tests {
         // Note the lack of a trailing `/` here is important for coverage of url-join behavior
         let base = Url::parse("https://account.d.codeartifact.us-west-2.amazonaws.com/pypi/shared-packages-pypi/simple/flask")
             .unwrap();
-        let SimpleHtml { base, files } = SimpleHtml::parse(text, &base).unwrap();
+        let SimpleHtml { base, files, .. } = SimpleHtml::parse(text, &base).unwrap();
 
         // Test parsing of the file urls
         let urls = files
@@ -1032,4 +1157,42 @@ mod tests {
 
         Ok(())
     }
+
+    /// Test that PEP 708 relationships are only honored when declared by the indexes themselves.
+    #[test]
+    fn pep708_relationships() {
+        let package_name = PackageName::from_str("flask").unwrap();
+        let parse = |response: &str, url: &Url| {
+            let data: SimpleJson = serde_json::from_str(response).unwrap();
+            OwnedArchive::from_unarchived(&SimpleMetadata::from_json(data, &package_name, url))
+                .unwrap()
+        };
+
+        let internal_url = Url::parse("https://internal.example.com/simple/flask/").unwrap();
+        let pypi_url = Url::parse("https://pypi.org/simple/flask/").unwrap();
+        let mirror_url = Url::parse("https://mirror.example.com/simple/flask/").unwrap();
+
+        let internal = parse(
+            r#"{ "files": [], "alternate-locations": ["https://pypi.org/simple/flask"] }"#,
+            &internal_url,
+        );
+        let pypi = parse(
+            r#"{ "files": [], "alternate-locations": ["https://internal.example.com/simple/flask/"] }"#,
+            &pypi_url,
+        );
+        let unrelated = parse(r#"{ "files": [] }"#, &pypi_url);
+        let mirror = parse(
+            r#"{ "files": [], "tracks": ["https://pypi.org/simple/flask/"] }"#,
+            &mirror_url,
+        );
+
+        // Alternate locations must be declared by both indexes.
+        assert!(internal.is_related(&internal_url, &pypi, &pypi_url));
+        assert!(!internal.is_related(&internal_url, &unrelated, &pypi_url));
+
+        // A mirror may declare that it tracks the other index.
+        assert!(mirror.is_related(&mirror_url, &unrelated, &pypi_url));
+        assert!(unrelated.is_related(&pypi_url, &mirror, &mirror_url));
+        assert!(!internal.is_related(&internal_url, &mirror, &mirror_url));
+    }
 }
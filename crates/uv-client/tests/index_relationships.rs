@@ -0,0 +1,137 @@
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use distribution_types::{IndexLocations, IndexUrl};
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+use uv_configuration::IndexStrategy;
+use uv_normalize::PackageName;
+
+/// Create a local index at `root` that serves a single `flask` wheel, along with any additional
+/// `<head>` content (e.g., PEP 708 `<meta>` tags).
+fn local_index(root: &Path, version: &str, head: &str) -> Result<IndexUrl> {
+    let project = root.join("flask");
+    fs_err::create_dir_all(&project)?;
+    let filename = format!("flask-{version}-py3-none-any.whl");
+    fs_err::write(
+        project.join("index.html"),
+        format!(
+            "<!DOCTYPE html><html><head>{head}</head><body><a href=\"{filename}\">{filename}</a></body></html>"
+        ),
+    )?;
+    Ok(IndexUrl::from_str(
+        Url::from_directory_path(root).unwrap().as_str(),
+    )?)
+}
+
+/// When using the first match, a subsequent index that the first matching index declares (via
+/// PEP 708) that it tracks should be merged in, while unrelated indexes, and indexes that merely
+/// claim to track the first matching index, should be ignored.
+#[tokio::test]
+async fn first_index_tracks() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let upstream = local_index(&temp_dir.path().join("upstream"), "2.0.0", "")?;
+    let tracks = Url::from_directory_path(temp_dir.path().join("upstream").join("flask")).unwrap();
+    let mirror = local_index(
+        &temp_dir.path().join("mirror"),
+        "1.0.0",
+        &format!("<meta name=\"pypi:tracks\" content=\"{tracks}\">"),
+    )?;
+    let claims = Url::from_directory_path(temp_dir.path().join("mirror").join("flask")).unwrap();
+    let claimant = local_index(
+        &temp_dir.path().join("claimant"),
+        "3.0.0",
+        &format!("<meta name=\"pypi:tracks\" content=\"{claims}\">"),
+    )?;
+    let unrelated = local_index(&temp_dir.path().join("unrelated"), "4.0.0", "")?;
+
+    // Extra indexes are prioritized over the main index.
+    let index_locations = IndexLocations::new(
+        Some(upstream.clone()),
+        vec![mirror.clone(), claimant, unrelated],
+        vec![],
+        false,
+    );
+
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_locations.index_urls())
+        .index_strategy(IndexStrategy::FirstIndex)
+        .build();
+
+    let results = client.simple(&PackageName::from_str("flask")?).await?;
+    let indexes = results
+        .iter()
+        .map(|(index, _)| index.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(indexes, vec![mirror, upstream]);
+
+    Ok(())
+}
+
+/// When using the first match, subsequent indexes should not be queried at all, unless the first
+/// matching index declares (via PEP 708) a relationship with another index.
+#[tokio::test]
+async fn first_index_no_relationships() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let first = local_index(&temp_dir.path().join("first"), "1.0.0", "")?;
+
+    // Serve a second index that counts the requests it receives.
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server_requests = requests.clone();
+    let server_task = tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let socket = TokioIo::new(socket);
+            let requests = server_requests.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                requests.fetch_add(1, Ordering::SeqCst);
+                let mut response = Response::new(Full::new(Bytes::new()));
+                *response.status_mut() = StatusCode::NOT_FOUND;
+                future::ok::<_, hyper::Error>(response)
+            });
+            tokio::task::spawn(async move {
+                let _ = http1::Builder::new().serve_connection(socket, svc).await;
+            });
+        }
+    });
+    let second = IndexUrl::from_str(&format!("http://{addr}/simple"))?;
+
+    // Extra indexes are prioritized over the main index.
+    let index_locations = IndexLocations::new(Some(second), vec![first.clone()], vec![], false);
+
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_locations.index_urls())
+        .index_strategy(IndexStrategy::FirstIndex)
+        .build();
+
+    let results = client.simple(&PackageName::from_str("flask")?).await?;
+    let indexes = results
+        .iter()
+        .map(|(index, _)| index.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(indexes, vec![first]);
+    assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+    server_task.abort();
+
+    Ok(())
+}
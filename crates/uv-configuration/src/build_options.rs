@@ -266,6 +266,13 @@ pub enum IndexStrategy {
     ///
     /// While this differs from pip's behavior, it's the default index strategy as it's the most
     /// secure.
+    ///
+    /// If the matching index declares (via PEP 708 metadata) that it shares the project's namespace
+    /// with a subsequent index (i.e., the matching index `tracks` the other, or both list each other
+    /// as `alternate-locations`), the versions from both indexes are merged. Subsequent indexes are
+    /// only queried if the matching index declares such a relationship.
+    ///
+    /// See: <https://peps.python.org/pep-0708/>
     #[default]
     #[cfg_attr(feature = "clap", clap(alias = "first-match"))]
     FirstIndex,
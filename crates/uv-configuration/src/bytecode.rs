@@ -0,0 +1,78 @@
+use std::fmt::{Display, Formatter};
+
+/// An optimization level to use when compiling Python source files to bytecode.
+///
+/// Mirrors the `-O` and `-OO` flags of the Python interpreter: an interpreter only loads bytecode
+/// that was compiled at its own optimization level.
+#[derive(Debug, Default, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Deserialize)]
+#[serde(try_from = "u8")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum BytecodeOptimization {
+    /// Compile without optimizations, as used by `python` (`*.pyc`).
+    #[default]
+    #[cfg_attr(feature = "clap", value(name = "0"))]
+    None,
+    /// Remove `assert` statements and code conditional on `__debug__`, as used by `python -O`
+    /// (`*.opt-1.pyc`).
+    #[cfg_attr(feature = "clap", value(name = "1"))]
+    Asserts,
+    /// Additionally remove docstrings, as used by `python -OO` (`*.opt-2.pyc`).
+    #[cfg_attr(feature = "clap", value(name = "2"))]
+    Docstrings,
+}
+
+impl BytecodeOptimization {
+    /// Returns the optimization level, as passed to `compileall.compile_file`.
+    pub fn level(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Asserts => 1,
+            Self::Docstrings => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for BytecodeOptimization {
+    type Error = String;
+
+    fn try_from(level: u8) -> Result<Self, Self::Error> {
+        match level {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Asserts),
+            2 => Ok(Self::Docstrings),
+            _ => Err(format!(
+                "`{level}` is not a valid optimization level (expected 0, 1, or 2)"
+            )),
+        }
+    }
+}
+
+impl Display for BytecodeOptimization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.level())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for BytecodeOptimization {
+    fn schema_name() -> String {
+        "BytecodeOptimization".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Integer.into()),
+            number: Some(Box::new(schemars::schema::NumberValidation {
+                minimum: Some(0.0),
+                maximum: Some(2.0),
+                ..schemars::schema::NumberValidation::default()
+            })),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("An optimization level to use when compiling Python source files to bytecode, mirroring the `-O` (`1`) and `-OO` (`2`) flags of the Python interpreter.".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::{Display, Formatter};
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+
+/// The sets of extras and dependency groups that are declared as mutually exclusive, e.g., via
+/// `tool.uv.conflicts`.
+///
+/// The universal resolver forks on each set, such that at most one item in each set is enabled in
+/// any given fork.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Conflicts(Vec<ConflictSet>);
+
+impl Conflicts {
+    /// Returns `true` if no conflicts were declared.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the conflicting sets.
+    pub fn iter(&self) -> impl Iterator<Item = &ConflictSet> {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<ConflictSet> for Conflicts {
+    fn from_iter<T: IntoIterator<Item = ConflictSet>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// A set of extras and dependency groups, no two of which may be enabled together.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConflictSet(Vec<ConflictItem>);
+
+impl ConflictSet {
+    /// Returns an iterator over the items in the set.
+    pub fn iter(&self) -> impl Iterator<Item = &ConflictItem> {
+        self.0.iter()
+    }
+
+    /// Returns `true` if the set contains the given item.
+    pub fn contains(&self, item: &ConflictItem) -> bool {
+        self.0.contains(item)
+    }
+}
+
+impl FromIterator<ConflictItem> for ConflictSet {
+    fn from_iter<T: IntoIterator<Item = ConflictItem>>(iter: T) -> Self {
+        let mut items = Vec::new();
+        for item in iter {
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+        Self(items)
+    }
+}
+
+/// An extra or dependency group of a specific package that participates in a [`ConflictSet`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConflictItem {
+    package: PackageName,
+    kind: ConflictKind,
+}
+
+impl ConflictItem {
+    /// Create a [`ConflictItem`] for an extra of the given package.
+    pub fn extra(package: PackageName, extra: ExtraName) -> Self {
+        Self {
+            package,
+            kind: ConflictKind::Extra(extra),
+        }
+    }
+
+    /// Create a [`ConflictItem`] for a dependency group of the given package.
+    pub fn group(package: PackageName, group: GroupName) -> Self {
+        Self {
+            package,
+            kind: ConflictKind::Group(group),
+        }
+    }
+
+    /// The package that declares the extra or group.
+    pub fn package(&self) -> &PackageName {
+        &self.package
+    }
+
+    /// The extra or group.
+    pub fn kind(&self) -> &ConflictKind {
+        &self.kind
+    }
+}
+
+impl Display for ConflictItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} of `{}`", self.kind, self.package)
+    }
+}
+
+/// Whether a [`ConflictItem`] refers to an extra or a dependency group.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConflictKind {
+    Extra(ExtraName),
+    Group(GroupName),
+}
+
+impl Display for ConflictKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Extra(extra) => write!(f, "extra `{extra}`"),
+            Self::Group(group) => write!(f, "group `{group}`"),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use uv_normalize::GroupName;
+
+/// The development dependency groups to install alongside (or instead of) a project.
+#[derive(Debug, Clone)]
+pub enum DevSpecification {
+    /// Install the project's dependencies, along with the given groups.
+    Include(Vec<GroupName>),
+    /// Install the given groups, but not the project itself or its dependencies.
+    Only(Vec<GroupName>),
+}
+
+impl Default for DevSpecification {
+    fn default() -> Self {
+        Self::Include(Vec::new())
+    }
+}
+
+impl DevSpecification {
+    /// Determine the dependency groups to use based on the command-line arguments.
+    ///
+    /// The `default` groups are included unless `--only-group` is provided; groups passed to
+    /// `--no-group` are always excluded.
+    pub fn from_args(
+        default: Vec<GroupName>,
+        group: Vec<GroupName>,
+        no_group: Vec<GroupName>,
+        only_group: Vec<GroupName>,
+    ) -> Self {
+        if only_group.is_empty() {
+            let mut groups = default;
+            for name in group {
+                if !groups.contains(&name) {
+                    groups.push(name);
+                }
+            }
+            groups.retain(|name| !no_group.contains(name));
+            Self::Include(groups)
+        } else {
+            let mut groups = Vec::with_capacity(only_group.len());
+            for name in only_group {
+                if !groups.contains(&name) && !no_group.contains(&name) {
+                    groups.push(name);
+                }
+            }
+            Self::Only(groups)
+        }
+    }
+
+    /// Returns the dependency groups to install.
+    pub fn groups(&self) -> &[GroupName] {
+        match self {
+            Self::Include(groups) | Self::Only(groups) => groups,
+        }
+    }
+
+    /// Returns `true` if the project itself (and its dependencies) should be installed.
+    pub fn prod(&self) -> bool {
+        matches!(self, Self::Include(_))
+    }
+}
@@ -0,0 +1,26 @@
+/// The strategy to use when an installed package would overwrite files that belong to another
+/// installed package.
+///
+/// For example, `opencv-python` and `opencv-python-headless` both ship the `cv2` module, such that
+/// installing both into the same environment leaves `cv2` in the state of whichever package was
+/// installed last.
+#[derive(Debug, Default, Clone, Copy, Hash, Eq, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum FileConflictStrategy {
+    /// Abort the installation before any file is overwritten.
+    Fail,
+    /// Overwrite the conflicting files, but warn about them.
+    #[default]
+    Warn,
+    /// Silently overwrite the conflicting files.
+    Allow,
+}
+
+impl FileConflictStrategy {
+    /// Returns `true` if file conflicts should be detected at all.
+    pub fn is_checked(self) -> bool {
+        !matches!(self, Self::Allow)
+    }
+}
@@ -1,23 +1,33 @@
 pub use authentication::*;
 pub use build_options::*;
+pub use bytecode::*;
 pub use concurrency::*;
 pub use config_settings::*;
+pub use conflicts::*;
 pub use constraints::*;
+pub use dev::*;
 pub use extras::*;
+pub use file_conflict::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
 pub use preview::*;
+pub use required_environments::*;
 pub use target_triple::*;
 
 mod authentication;
 mod build_options;
+mod bytecode;
 mod concurrency;
 mod config_settings;
+mod conflicts;
 mod constraints;
+mod dev;
 mod extras;
+mod file_conflict;
 mod name_specifiers;
 mod overrides;
 mod package_options;
 mod preview;
+mod required_environments;
 mod target_triple;
@@ -0,0 +1,118 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use pep508_rs::{MarkerEnvironment, MarkerTree, Pep508Error};
+
+use crate::TargetTriple;
+
+/// The targets against which a marker expression in `tool.uv.required-environments` is
+/// evaluated: the default target for each common operating system and architecture.
+const MARKER_TARGETS: &[TargetTriple] = &[
+    TargetTriple::X8664UnknownLinuxGnu,
+    TargetTriple::Aarch64UnknownLinuxGnu,
+    TargetTriple::X8664AppleDarwin,
+    TargetTriple::Aarch64AppleDarwin,
+    TargetTriple::X8664PcWindowsMsvc,
+];
+
+/// An environment for which every locked distribution must provide a compatible wheel, e.g., via
+/// `tool.uv.required-environments`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequiredEnvironment {
+    /// A specific target triple, e.g., `x86_64-unknown-linux-gnu`.
+    Target(TargetTriple),
+    /// A PEP 508 marker expression, e.g., `sys_platform == 'linux'`.
+    Marker(MarkerTree),
+}
+
+impl RequiredEnvironment {
+    /// Returns the [`TargetTriple`]s covered by this environment.
+    ///
+    /// A marker expression covers each of the common targets whose markers (applied on top of
+    /// the given base [`MarkerEnvironment`]) satisfy the expression.
+    pub fn targets(&self, base: &MarkerEnvironment) -> Vec<TargetTriple> {
+        match self {
+            Self::Target(target) => vec![*target],
+            Self::Marker(marker) => MARKER_TARGETS
+                .iter()
+                .copied()
+                .filter(|target| marker.evaluate(&target.markers(base), &[]))
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for RequiredEnvironment {
+    type Err = Pep508Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Prefer a target triple, falling back to a marker expression.
+        if let Ok(target) = TargetTriple::from_str(s) {
+            return Ok(Self::Target(target));
+        }
+        Ok(Self::Marker(MarkerTree::from_str(s)?))
+    }
+}
+
+impl Display for RequiredEnvironment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Target(target) => write!(f, "{target}"),
+            Self::Marker(marker) => write!(f, "{marker}"),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RequiredEnvironment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = RequiredEnvironment;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a target triple or a PEP 508 marker expression")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                RequiredEnvironment::from_str(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl serde::Serialize for RequiredEnvironment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RequiredEnvironment {
+    fn schema_name() -> String {
+        "RequiredEnvironment".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("A target triple (e.g., `x86_64-unknown-linux-gnu`) or a PEP 508 marker expression (e.g., `sys_platform == 'linux'`).".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
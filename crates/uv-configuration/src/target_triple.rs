@@ -1,3 +1,6 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
 use tracing::debug;
 
 use pep508_rs::MarkerEnvironment;
@@ -84,6 +87,26 @@ pub enum TargetTriple {
 }
 
 impl TargetTriple {
+    /// Return the name of the target, e.g., `x86_64-unknown-linux-gnu`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Windows => "windows",
+            Self::Linux => "linux",
+            Self::Macos => "macos",
+            Self::X8664PcWindowsMsvc => "x86_64-pc-windows-msvc",
+            Self::X8664UnknownLinuxGnu => "x86_64-unknown-linux-gnu",
+            Self::Aarch64AppleDarwin => "aarch64-apple-darwin",
+            Self::X8664AppleDarwin => "x86_64-apple-darwin",
+            Self::Aarch64UnknownLinuxGnu => "aarch64-unknown-linux-gnu",
+            Self::Aarch64UnknownLinuxMusl => "aarch64-unknown-linux-musl",
+            Self::X8664UnknownLinuxMusl => "x86_64-unknown-linux-musl",
+            Self::X8664Manylinux217 => "x86_64-manylinux_2_17",
+            Self::X8664Manylinux228 => "x86_64-manylinux_2_28",
+            Self::Aarch64Manylinux217 => "aarch64-manylinux_2_17",
+            Self::Aarch64Manylinux228 => "aarch64-manylinux_2_28",
+        }
+    }
+
     /// Return the [`Platform`] for the target.
     pub fn platform(self) -> Platform {
         match self {
@@ -271,6 +294,38 @@ impl TargetTriple {
     }
 }
 
+impl FromStr for TargetTriple {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Self::Windows,
+            Self::Linux,
+            Self::Macos,
+            Self::X8664PcWindowsMsvc,
+            Self::X8664UnknownLinuxGnu,
+            Self::Aarch64AppleDarwin,
+            Self::X8664AppleDarwin,
+            Self::Aarch64UnknownLinuxGnu,
+            Self::Aarch64UnknownLinuxMusl,
+            Self::X8664UnknownLinuxMusl,
+            Self::X8664Manylinux217,
+            Self::X8664Manylinux228,
+            Self::Aarch64Manylinux217,
+            Self::Aarch64Manylinux228,
+        ]
+        .into_iter()
+        .find(|target| target.as_str() == s)
+        .ok_or_else(|| format!("unknown target triple: `{s}`"))
+    }
+}
+
+impl Display for TargetTriple {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Return the macOS deployment target as parsed from the environment.
 fn macos_deployment_target() -> Option<(u16, u16)> {
     let version = std::env::var("MACOSX_DEPLOYMENT_TARGET").ok()?;
@@ -17,7 +17,7 @@ use uv_build::{SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
 use uv_configuration::{BuildKind, BuildOptions, ConfigSettings, Reinstall, SetupPyStrategy};
-use uv_configuration::{Concurrency, PreviewMode};
+use uv_configuration::{Concurrency, FileConflictStrategy, PreviewMode};
 use uv_distribution::DistributionDatabase;
 use uv_git::GitResolver;
 use uv_installer::{Downloader, Installer, Plan, Planner, SitePackages};
@@ -148,7 +148,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         let tags = self.interpreter.tags()?;
         let resolver = Resolver::new(
             Manifest::simple(requirements.to_vec()),
-            self.options,
+            self.options.clone(),
             &python_requirement,
             Some(markers),
             Some(tags),
@@ -289,8 +289,10 @@ impl<'a> BuildContext for BuildDispatch<'a> {
                 if wheels.len() == 1 { "" } else { "s" },
                 wheels.iter().map(ToString::to_string).join(", ")
             );
+            // Build environments are created from scratch, so skip the file conflict detection.
             Installer::new(venv)
                 .with_link_mode(self.link_mode)
+                .with_on_conflict(FileConflictStrategy::Allow)
                 .install(&wheels)
                 .context("Failed to install build dependencies")?;
         }
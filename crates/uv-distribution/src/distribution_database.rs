@@ -163,6 +163,16 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     FileLocation::AbsoluteUrl(url) => {
                         Url::parse(url).map_err(|err| Error::Url(url.clone(), err))?
                     }
+                    // Wheels discovered in the cache (e.g., in `--offline` mode) point to an
+                    // already-unzipped archive.
+                    FileLocation::Path(path) if path.is_dir() => {
+                        return Ok(LocalWheel {
+                            dist: Dist::Built(dist.clone()),
+                            archive: path.clone(),
+                            hashes: wheel.file.hashes.clone(),
+                            filename: wheel.filename.clone(),
+                        });
+                    }
                     FileLocation::Path(path) => {
                         let cache_entry = self.build_context.cache().entry(
                             CacheBucket::Wheels,
@@ -0,0 +1,130 @@
+use distribution_filename::DistFilename;
+use distribution_types::{File, FileLocation, FlatIndexLocation, IndexLocations, IndexUrl};
+use pep508_rs::VerbatimUrl;
+use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_client::FlatIndexEntries;
+use uv_fs::{directories, files, symlinks};
+
+use crate::index::cached_wheel::CachedWheel;
+use crate::source::{HttpRevisionPointer, LocalRevisionPointer, HTTP_REVISION, LOCAL_REVISION};
+
+/// A flat index of every wheel that's already available in the cache, for use in `--offline`
+/// mode.
+///
+/// Each wheel is surfaced as if it were a `--find-links` entry on the index from which it was
+/// originally downloaded (or built), such that the resolver can select it without consulting the
+/// index itself, and the installer can link it directly from the cache.
+#[derive(Debug)]
+pub struct CachedFlatIndex;
+
+impl CachedFlatIndex {
+    /// Collect the cached wheels for all configured indexes.
+    pub fn entries(cache: &Cache, index_locations: &IndexLocations) -> FlatIndexEntries {
+        let mut entries = Vec::new();
+
+        // Collect into owned `IndexUrl`.
+        let flat_index_urls: Vec<IndexUrl> = index_locations
+            .flat_index()
+            .filter_map(|flat_index| match flat_index {
+                FlatIndexLocation::Path(path) => {
+                    let path = fs_err::canonicalize(path).ok()?;
+                    Some(IndexUrl::Path(VerbatimUrl::from_path(path).ok()?))
+                }
+                FlatIndexLocation::Url(url) => {
+                    Some(IndexUrl::Url(VerbatimUrl::from_url(url.clone())))
+                }
+            })
+            .collect();
+
+        for index_url in index_locations.indexes().chain(flat_index_urls.iter()) {
+            let wheels = Self::wheels(cache, index_url).chain(Self::built_wheels(cache, index_url));
+            for wheel in wheels {
+                let file = File {
+                    dist_info_metadata: false,
+                    filename: wheel.filename.to_string(),
+                    hashes: wheel.hashes,
+                    requires_python: None,
+                    size: None,
+                    upload_time_utc_ms: None,
+                    url: FileLocation::Path(wheel.entry.into_path_buf()),
+                    yanked: None,
+                };
+                entries.push((
+                    DistFilename::WheelFilename(wheel.filename),
+                    file,
+                    index_url.clone(),
+                ));
+            }
+        }
+
+        FlatIndexEntries::from_entries(entries)
+    }
+
+    /// Return the wheels that were downloaded directly from the given index.
+    fn wheels<'a>(
+        cache: &'a Cache,
+        index_url: &'a IndexUrl,
+    ) -> impl Iterator<Item = CachedWheel> + 'a {
+        // For registry wheels, the cache structure is: `<index>/<package-name>/<wheel>.http`
+        // or `<index>/<package-name>/<wheel>.rev`.
+        let root = cache
+            .bucket(CacheBucket::Wheels)
+            .join(WheelCache::Index(index_url).root());
+        directories(root)
+            .flat_map(files)
+            .filter_map(move |file| {
+                let extension = file.extension()?;
+                match index_url {
+                    // Add files from remote registries.
+                    IndexUrl::Pypi(_) | IndexUrl::Url(_) => {
+                        if extension.eq_ignore_ascii_case("http") {
+                            CachedWheel::from_http_pointer(&file, cache)
+                        } else {
+                            None
+                        }
+                    }
+                    // Add files from local registries (e.g., `--find-links`).
+                    IndexUrl::Path(_) => {
+                        if extension.eq_ignore_ascii_case("rev") {
+                            CachedWheel::from_local_pointer(&file, cache)
+                        } else {
+                            None
+                        }
+                    }
+                }
+            })
+            // Ignore pointers to archives that have since been pruned.
+            .filter(|wheel| wheel.entry.path().is_dir())
+    }
+
+    /// Return the wheels that were built from source distributions on the given index.
+    fn built_wheels<'a>(
+        cache: &'a Cache,
+        index_url: &'a IndexUrl,
+    ) -> impl Iterator<Item = CachedWheel> + 'a {
+        // For built wheels, the cache structure is: `<index>/<package-name>/<version>/`.
+        let root = cache
+            .bucket(CacheBucket::BuiltWheels)
+            .join(WheelCache::Index(index_url).root());
+        directories(root)
+            .flat_map(directories)
+            .filter_map(move |shard| {
+                // Read the revision from the cache.
+                let revision = match index_url {
+                    IndexUrl::Pypi(_) | IndexUrl::Url(_) => {
+                        HttpRevisionPointer::read_from(shard.join(HTTP_REVISION))
+                            .ok()??
+                            .into_revision()
+                    }
+                    IndexUrl::Path(_) => {
+                        LocalRevisionPointer::read_from(shard.join(LOCAL_REVISION))
+                            .ok()??
+                            .into_revision()
+                    }
+                };
+                Some(shard.join(revision.id()))
+            })
+            .flat_map(symlinks)
+            .filter_map(CachedWheel::from_built_source)
+    }
+}
@@ -1,6 +1,8 @@
 pub use built_wheel_index::BuiltWheelIndex;
+pub use cached_flat_index::CachedFlatIndex;
 pub use registry_wheel_index::RegistryWheelIndex;
 
 mod built_wheel_index;
+mod cached_flat_index;
 mod cached_wheel;
 mod registry_wheel_index;
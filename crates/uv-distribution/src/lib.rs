@@ -1,7 +1,7 @@
 pub use distribution_database::{DistributionDatabase, HttpArchivePointer, LocalArchivePointer};
 pub use download::LocalWheel;
 pub use error::Error;
-pub use index::{BuiltWheelIndex, RegistryWheelIndex};
+pub use index::{BuiltWheelIndex, CachedFlatIndex, RegistryWheelIndex};
 pub use metadata::{ArchiveMetadata, Metadata, RequiresDist, DEV_DEPENDENCIES};
 pub use reporter::Reporter;
 pub use workspace::{ProjectWorkspace, Workspace, WorkspaceError, WorkspaceMember};
@@ -16,5 +16,6 @@ mod metadata;
 pub mod pyproject;
 pub mod pyproject_mut;
 mod reporter;
+pub mod script;
 mod source;
 mod workspace;
@@ -10,6 +10,7 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 
 use crate::metadata::lowering::LoweringError;
 pub use crate::metadata::requires_dist::{RequiresDist, DEV_DEPENDENCIES};
+use crate::pyproject::DependencyGroupError;
 use crate::WorkspaceError;
 
 mod lowering;
@@ -21,6 +22,8 @@ pub enum MetadataError {
     Workspace(#[from] WorkspaceError),
     #[error("Failed to parse entry for: `{0}`")]
     LoweringError(PackageName, #[source] LoweringError),
+    #[error(transparent)]
+    DependencyGroup(#[from] DependencyGroupError),
 }
 
 #[derive(Debug, Clone)]
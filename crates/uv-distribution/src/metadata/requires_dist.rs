@@ -11,8 +11,8 @@ use crate::{Metadata, ProjectWorkspace};
 
 /// The name of the global `dev-dependencies` group.
 ///
-/// Internally, we model dependency groups as a generic concept; but externally, we only expose the
-/// `dev-dependencies` group.
+/// `tool.uv.dev-dependencies` are exposed as the `dev` group, alongside any PEP 735
+/// `dependency-groups`.
 pub static DEV_DEPENDENCIES: Lazy<GroupName> =
     Lazy::new(|| GroupName::new("dev".to_string()).unwrap());
 
@@ -63,7 +63,7 @@ impl RequiresDist {
         project_workspace: &ProjectWorkspace,
         preview_mode: PreviewMode,
     ) -> Result<Self, MetadataError> {
-        // Collect any `tool.uv.sources` and `tool.uv.dev_dependencies` from `pyproject.toml`.
+        // Collect any `tool.uv.sources` from `pyproject.toml`.
         let empty = BTreeMap::default();
         let sources = project_workspace
             .current_project()
@@ -74,35 +74,49 @@ impl RequiresDist {
             .and_then(|uv| uv.sources.as_ref())
             .unwrap_or(&empty);
 
+        // Collect `tool.uv.dev-dependencies` into the `dev` group, alongside any PEP 735
+        // `dependency-groups`.
         let dev_dependencies = {
-            let dev_dependencies = project_workspace
-                .current_project()
-                .pyproject_toml()
+            let pyproject_toml = project_workspace.current_project().pyproject_toml();
+
+            let mut groups = pyproject_toml.resolve_dependency_groups()?;
+            if let Some(dev_dependencies) = pyproject_toml
                 .tool
                 .as_ref()
                 .and_then(|tool| tool.uv.as_ref())
                 .and_then(|uv| uv.dev_dependencies.as_ref())
+            {
+                if !dev_dependencies.is_empty() {
+                    groups
+                        .entry(DEV_DEPENDENCIES.clone())
+                        .or_default()
+                        .splice(0..0, dev_dependencies.iter().cloned());
+                }
+            }
+
+            groups
                 .into_iter()
-                .flatten()
-                .cloned()
-                .map(|requirement| {
-                    let requirement_name = requirement.name.clone();
-                    lower_requirement(
-                        requirement,
-                        &metadata.name,
-                        project_workspace.project_root(),
-                        sources,
-                        project_workspace.workspace(),
-                        preview_mode,
-                    )
-                    .map_err(|err| MetadataError::LoweringError(requirement_name.clone(), err))
+                .map(|(group, requirements)| {
+                    let requirements = requirements
+                        .into_iter()
+                        .map(|requirement| {
+                            let requirement_name = requirement.name.clone();
+                            lower_requirement(
+                                requirement,
+                                &metadata.name,
+                                project_workspace.project_root(),
+                                sources,
+                                project_workspace.workspace(),
+                                preview_mode,
+                            )
+                            .map_err(|err| {
+                                MetadataError::LoweringError(requirement_name.clone(), err)
+                            })
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok((group, requirements))
                 })
-                .collect::<Result<Vec<_>, _>>()?;
-            if dev_dependencies.is_empty() {
-                BTreeMap::default()
-            } else {
-                BTreeMap::from([(DEV_DEPENDENCIES.clone(), dev_dependencies)])
-            }
+                .collect::<Result<BTreeMap<_, _>, MetadataError>>()?
         };
 
         let requires_dist = metadata
@@ -1,7 +1,11 @@
 //! Reads the following fields from `pyproject.toml`:
 //!
 //! * `project.{dependencies,optional-dependencies}`
+//! * `dependency-groups`
 //! * `tool.uv.sources`
+//! * `tool.uv.conflicts`
+//! * `tool.uv.environments`
+//! * `tool.uv.required-environments`
 //! * `tool.uv.workspace`
 //!
 //! Then lowers them into a dependency specification.
@@ -11,11 +15,13 @@ use std::ops::Deref;
 
 use glob::Pattern;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use url::Url;
 
 use pep440_rs::VersionSpecifiers;
 use pypi_types::VerbatimParsedUrl;
-use uv_normalize::{ExtraName, PackageName};
+use uv_configuration::{ConflictItem, RequiredEnvironment};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 
 /// A `pyproject.toml` as specified in PEP 517.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -25,6 +31,8 @@ pub struct PyProjectToml {
     pub project: Option<Project>,
     /// Tool-specific metadata.
     pub tool: Option<Tool>,
+    /// PEP 735 dependency groups.
+    pub dependency_groups: Option<BTreeMap<GroupName, Vec<DependencyGroupSpecifier>>>,
     /// The raw unserialized document.
     #[serde(skip)]
     pub(crate) raw: String,
@@ -36,12 +44,86 @@ impl PyProjectToml {
         let pyproject = toml::from_str(&raw)?;
         Ok(PyProjectToml { raw, ..pyproject })
     }
+
+    /// Return the requirements for each of the `dependency-groups`, with any `include-group`
+    /// references expanded in place.
+    pub fn resolve_dependency_groups(
+        &self,
+    ) -> Result<
+        BTreeMap<GroupName, Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
+        DependencyGroupError,
+    > {
+        let Some(groups) = self.dependency_groups.as_ref() else {
+            return Ok(BTreeMap::default());
+        };
+
+        let mut resolved = BTreeMap::default();
+        for name in groups.keys() {
+            let mut requirements = Vec::new();
+            resolve_dependency_group(name, groups, &mut Vec::new(), &mut requirements)?;
+            resolved.insert(name.clone(), requirements);
+        }
+        Ok(resolved)
+    }
+}
+
+/// Expand the dependency group `name` into `requirements`, following `include-group` references.
+///
+/// `parents` tracks the chain of groups currently being expanded, to detect cycles.
+fn resolve_dependency_group<'a>(
+    name: &'a GroupName,
+    groups: &'a BTreeMap<GroupName, Vec<DependencyGroupSpecifier>>,
+    parents: &mut Vec<&'a GroupName>,
+    requirements: &mut Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
+) -> Result<(), DependencyGroupError> {
+    if let Some(start) = parents.iter().position(|parent| *parent == name) {
+        let cycle = parents[start..]
+            .iter()
+            .chain(std::iter::once(&name))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        return Err(DependencyGroupError::Cycle(cycle));
+    }
+
+    let Some(specifiers) = groups.get(name) else {
+        let parent = parents.last().copied().unwrap_or(name);
+        return Err(DependencyGroupError::GroupNotFound(
+            name.clone(),
+            parent.clone(),
+        ));
+    };
+
+    parents.push(name);
+    for specifier in specifiers {
+        match specifier {
+            DependencyGroupSpecifier::Requirement(requirement) => {
+                requirements.push(requirement.clone());
+            }
+            DependencyGroupSpecifier::IncludeGroup { include_group } => {
+                resolve_dependency_group(include_group, groups, parents, requirements)?;
+            }
+        }
+    }
+    parents.pop();
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum DependencyGroupError {
+    #[error("Failed to find group `{0}` included by `{1}`")]
+    GroupNotFound(GroupName, GroupName),
+    #[error("Detected a cycle in `dependency-groups`: {0}")]
+    Cycle(String),
 }
 
 // Ignore raw document in comparison.
 impl PartialEq for PyProjectToml {
     fn eq(&self, other: &Self) -> bool {
-        self.project.eq(&other.project) && self.tool.eq(&other.tool)
+        self.project.eq(&other.project)
+            && self.tool.eq(&other.tool)
+            && self.dependency_groups.eq(&other.dependency_groups)
     }
 }
 
@@ -61,6 +143,21 @@ pub struct Project {
     pub optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
 }
 
+/// An entry in a PEP 735 dependency group (`dependency-groups`).
+///
+/// See <https://peps.python.org/pep-0735/>.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum DependencyGroupSpecifier {
+    /// A PEP 508-style requirement, e.g., `pytest>=8`.
+    Requirement(pep508_rs::Requirement<VerbatimParsedUrl>),
+    /// A reference to another dependency group, e.g., `{ include-group = "test" }`.
+    IncludeGroup {
+        #[serde(rename = "include-group")]
+        include_group: GroupName,
+    },
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Tool {
@@ -81,6 +178,55 @@ pub struct ToolUv {
         )
     )]
     pub dev_dependencies: Option<Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
+    /// Sets of extras or dependency groups that can't be enabled together, e.g.,
+    /// `[[{ extra = "cpu" }, { extra = "cu121" }]]`.
+    pub conflicts: Option<Vec<Vec<ConflictItemSpecifier>>>,
+    /// The environments to lock for, as PEP 508 marker expressions, e.g.,
+    /// `["sys_platform == 'linux'"]`. By default, the lock supports all environments.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "PEP 508 marker expressions for the environments to lock, e.g., `sys_platform == 'linux'`."
+        )
+    )]
+    pub environments: Option<Vec<pep508_rs::MarkerTree>>,
+    /// The environments for which every locked distribution must provide a compatible wheel, as
+    /// target triples or PEP 508 marker expressions, e.g., `["x86_64-unknown-linux-gnu"]`.
+    pub required_environments: Option<Vec<RequiredEnvironment>>,
+}
+
+/// An extra or dependency group in a `tool.uv.conflicts` set.
+///
+/// If `package` is omitted, the item refers to the project that declares the conflict.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged, deny_unknown_fields)]
+pub enum ConflictItemSpecifier {
+    Extra {
+        package: Option<PackageName>,
+        extra: ExtraName,
+    },
+    Group {
+        package: Option<PackageName>,
+        group: GroupName,
+    },
+}
+
+impl ConflictItemSpecifier {
+    /// Convert into a [`ConflictItem`], resolving an omitted `package` to the given project.
+    pub fn to_conflict_item(&self, project: &PackageName) -> ConflictItem {
+        match self {
+            Self::Extra { package, extra } => ConflictItem::extra(
+                package.clone().unwrap_or_else(|| project.clone()),
+                extra.clone(),
+            ),
+            Self::Group { package, group } => ConflictItem::group(
+                package.clone().unwrap_or_else(|| project.clone()),
+                group.clone(),
+            ),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
@@ -8,6 +8,7 @@ use pep508_rs::{PackageName, Requirement};
 use pypi_types::VerbatimParsedUrl;
 
 use crate::pyproject::PyProjectToml;
+use crate::script::Pep723Script;
 
 /// Raw and mutable representation of a `pyproject.toml`.
 ///
@@ -15,12 +16,24 @@ use crate::pyproject::PyProjectToml;
 /// preserving comments and other structure, such as `uv add` and `uv remove`.
 pub struct PyProjectTomlMut {
     doc: DocumentMut,
+    target: DependencyTarget,
+}
+
+/// The document that dependencies are added to or removed from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum DependencyTarget {
+    /// The `project.dependencies` table of a `pyproject.toml`.
+    PyProjectToml,
+    /// The top-level `dependencies` of the inline metadata of a PEP 723 script.
+    Script,
 }
 
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("Failed to parse `pyproject.toml`")]
     Parse(#[from] Box<TomlError>),
+    #[error("Failed to parse PEP 723 script metadata")]
+    ParseScript(#[source] Box<TomlError>),
     #[error("Dependencies in `pyproject.toml` are malformed")]
     MalformedDependencies,
 }
@@ -30,12 +43,32 @@ impl PyProjectTomlMut {
     pub fn from_toml(pyproject: &PyProjectToml) -> Result<Self, Error> {
         Ok(Self {
             doc: pyproject.raw.parse().map_err(Box::new)?,
+            target: DependencyTarget::PyProjectToml,
         })
     }
 
+    /// Initialize a `PyProjectTomlMut` from the inline metadata of a PEP 723 script.
+    pub fn from_script(script: &Pep723Script) -> Result<Self, Error> {
+        Ok(Self {
+            doc: script
+                .raw
+                .parse()
+                .map_err(|err| Error::ParseScript(Box::new(err)))?,
+            target: DependencyTarget::Script,
+        })
+    }
+
+    /// Returns the dependencies array of the target document.
+    fn dependencies_mut(&mut self) -> &mut Item {
+        match self.target {
+            DependencyTarget::PyProjectToml => &mut self.doc["project"]["dependencies"],
+            DependencyTarget::Script => &mut self.doc["dependencies"],
+        }
+    }
+
     /// Adds a dependency.
     pub fn add_dependency(&mut self, req: &Requirement) -> Result<(), Error> {
-        let deps = &mut self.doc["project"]["dependencies"];
+        let deps = self.dependencies_mut();
         if deps.is_none() {
             *deps = Item::Value(Value::Array(Array::new()));
         }
@@ -70,7 +103,7 @@ impl PyProjectTomlMut {
 
     /// Removes all occurrences of dependencies with the given name.
     pub fn remove_dependency(&mut self, req: &PackageName) -> Result<Vec<Requirement>, Error> {
-        let deps = &mut self.doc["project"]["dependencies"];
+        let deps = self.dependencies_mut();
         if deps.is_none() {
             return Ok(Vec::new());
         }
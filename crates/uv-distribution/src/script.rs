@@ -0,0 +1,340 @@
+//! Reads and writes the inline script metadata of a PEP 723 script, i.e., the `# /// script`
+//! block declaring `dependencies` and `requires-python`.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use pep440_rs::VersionSpecifiers;
+use pypi_types::VerbatimParsedUrl;
+
+/// A PEP 723 script, including its [`Pep723Metadata`].
+#[derive(Debug, Clone)]
+pub struct Pep723Script {
+    /// The path to the script.
+    pub path: PathBuf,
+    /// The parsed inline script metadata.
+    pub metadata: Pep723Metadata,
+    /// The raw TOML content of the inline script metadata.
+    pub(crate) raw: String,
+    /// The content of the script before the metadata block.
+    prelude: String,
+    /// The content of the script after the metadata block.
+    postlude: String,
+}
+
+impl Pep723Script {
+    /// Read the PEP 723 `script` metadata from a Python file.
+    ///
+    /// Returns `None` if the file is missing a PEP 723 metadata block.
+    pub async fn read(path: impl AsRef<Path>) -> Result<Option<Self>, Pep723Error> {
+        let contents = match fs_err::tokio::read_to_string(path.as_ref()).await {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let Some(ScriptTag {
+            prelude,
+            metadata,
+            postlude,
+        }) = ScriptTag::parse(&contents)?
+        else {
+            return Ok(None);
+        };
+
+        let metadata_toml = toml::from_str(&metadata)?;
+
+        Ok(Some(Self {
+            path: path.as_ref().to_path_buf(),
+            metadata: metadata_toml,
+            raw: metadata,
+            prelude,
+            postlude,
+        }))
+    }
+
+    /// Read the PEP 723 `script` metadata from a Python file, initializing an empty metadata
+    /// block if the file doesn't contain one.
+    ///
+    /// The metadata block is inserted at the top of the file, after any shebang line.
+    pub async fn read_or_init(path: impl AsRef<Path>) -> Result<Self, Pep723Error> {
+        if let Some(script) = Self::read(path.as_ref()).await? {
+            return Ok(script);
+        }
+
+        let contents = fs_err::tokio::read_to_string(path.as_ref()).await?;
+        let (prelude, postlude) = if contents.starts_with("#!") {
+            let end = contents
+                .find('\n')
+                .map_or(contents.len(), |index| index + 1);
+            let (shebang, rest) = contents.split_at(end);
+            let shebang = if shebang.ends_with('\n') {
+                shebang.to_string()
+            } else {
+                format!("{shebang}\n")
+            };
+            (shebang, rest.to_string())
+        } else {
+            (String::new(), contents)
+        };
+
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            metadata: Pep723Metadata::default(),
+            raw: String::new(),
+            prelude,
+            postlude,
+        })
+    }
+
+    /// Replace the inline script metadata with the given TOML, and write the script back to
+    /// disk.
+    pub async fn write(&self, metadata: &str) -> Result<(), Pep723Error> {
+        let mut contents = self.prelude.clone();
+        contents.push_str("# /// script\n");
+        for line in metadata.lines() {
+            if line.is_empty() {
+                contents.push_str("#\n");
+            } else {
+                contents.push_str("# ");
+                contents.push_str(line);
+                contents.push('\n');
+            }
+        }
+        contents.push_str("# ///\n");
+        contents.push_str(&self.postlude);
+
+        fs_err::tokio::write(&self.path, contents).await?;
+        Ok(())
+    }
+}
+
+/// PEP 723 metadata as parsed from a `script` comment block.
+///
+/// See: <https://peps.python.org/pep-0723/>
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Pep723Metadata {
+    pub dependencies: Option<Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
+    pub requires_python: Option<VersionSpecifiers>,
+}
+
+#[derive(Debug, Error)]
+pub enum Pep723Error {
+    #[error("An opening tag (`# /// script`) was found without a closing tag (`# ///`). Ensure that every line between the opening and closing tags (including empty lines) starts with a leading `#`.")]
+    UnclosedBlock,
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to parse PEP 723 script metadata")]
+    Toml(#[from] toml::de::Error),
+}
+
+/// The `script` metadata block of a Python file, along with the surrounding content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ScriptTag {
+    /// The content of the file before the metadata block.
+    prelude: String,
+    /// The metadata block, with the leading `# ` stripped from each line.
+    metadata: String,
+    /// The content of the file after the metadata block.
+    postlude: String,
+}
+
+impl ScriptTag {
+    /// Given the contents of a Python file, extract the `script` metadata block.
+    ///
+    /// Per PEP 723, the block opens with a `# /// script` line, and closes with the last `# ///`
+    /// line before the first line that isn't a comment (i.e., `#` or `# ...`). Every line in
+    /// between is part of the metadata, with the leading `#` (and a single space, if present)
+    /// removed.
+    ///
+    /// See: <https://peps.python.org/pep-0723/#reference-implementation>
+    fn parse(contents: &str) -> Result<Option<Self>, Pep723Error> {
+        let mut lines = contents.split_inclusive('\n');
+
+        // Find the opening tag.
+        let mut prelude = String::new();
+        loop {
+            let Some(line) = lines.next() else {
+                return Ok(None);
+            };
+            if line.trim_end_matches(['\r', '\n']) == "# /// script" {
+                break;
+            }
+            prelude.push_str(line);
+        }
+
+        // Collect the comment lines that follow, tracking the last closing tag.
+        let mut block = Vec::new();
+        let mut closing = None;
+        let mut postlude = String::new();
+        for line in lines.by_ref() {
+            let content = line.trim_end_matches(['\r', '\n']);
+            let content = if content == "#" {
+                ""
+            } else if let Some(content) = content.strip_prefix("# ") {
+                content
+            } else {
+                postlude.push_str(line);
+                break;
+            };
+            if content == "///" {
+                closing = Some(block.len());
+            }
+            block.push((line, content));
+        }
+
+        let Some(closing) = closing else {
+            return Err(Pep723Error::UnclosedBlock);
+        };
+
+        // Any comment lines after the closing tag are part of the postlude.
+        let mut metadata = String::new();
+        for (_, content) in &block[..closing] {
+            metadata.push_str(content);
+            metadata.push('\n');
+        }
+        let mut rest = String::new();
+        for (line, _) in &block[closing + 1..] {
+            rest.push_str(line);
+        }
+        rest.push_str(&postlude);
+        rest.extend(lines);
+
+        Ok(Some(Self {
+            prelude,
+            metadata,
+            postlude: rest,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::{Pep723Error, ScriptTag};
+
+    #[test]
+    fn missing_space() {
+        let contents = indoc! {r"
+        # /// script
+        #requires-python = '>=3.11'
+        # ///
+        "};
+
+        assert!(matches!(
+            ScriptTag::parse(contents),
+            Err(Pep723Error::UnclosedBlock)
+        ));
+    }
+
+    #[test]
+    fn no_closing_pragma() {
+        let contents = indoc! {r"
+        # /// script
+        # requires-python = '>=3.11'
+        # dependencies = [
+        #     'requests<3',
+        #     'rich',
+        # ]
+        "};
+
+        assert!(matches!(
+            ScriptTag::parse(contents),
+            Err(Pep723Error::UnclosedBlock)
+        ));
+    }
+
+    #[test]
+    fn no_script() {
+        let contents = indoc! {r"
+        import requests
+
+        print(requests.get('https://example.com'))
+        "};
+
+        assert!(ScriptTag::parse(contents).unwrap().is_none());
+    }
+
+    #[test]
+    fn simple() {
+        let contents = indoc! {r"
+        #!/usr/bin/env python3
+        # /// script
+        # requires-python = '>=3.11'
+        # dependencies = [
+        #     'requests<3',
+        #     'rich',
+        # ]
+        # ///
+
+        import requests
+        "};
+
+        let expected_metadata = indoc! {r"
+        requires-python = '>=3.11'
+        dependencies = [
+            'requests<3',
+            'rich',
+        ]
+        "};
+
+        let actual = ScriptTag::parse(contents).unwrap().unwrap();
+
+        assert_eq!(actual.prelude, "#!/usr/bin/env python3\n");
+        assert_eq!(actual.metadata, expected_metadata);
+        assert_eq!(actual.postlude, "\nimport requests\n");
+    }
+
+    #[test]
+    fn embedded_comment() {
+        let contents = indoc! {r"
+        # /// script
+        # embedded-csharp = '''
+        # /// <summary>
+        # /// text
+        # ///
+        # /// </summary>
+        # public class MyClass { }
+        # '''
+        # ///
+        "};
+
+        let expected_metadata = indoc! {r"
+        embedded-csharp = '''
+        /// <summary>
+        /// text
+        ///
+        /// </summary>
+        public class MyClass { }
+        '''
+        "};
+
+        let actual = ScriptTag::parse(contents).unwrap().unwrap();
+
+        assert_eq!(actual.metadata, expected_metadata);
+        assert_eq!(actual.postlude, "");
+    }
+
+    #[test]
+    fn trailing_comment() {
+        let contents = indoc! {r"
+        # /// script
+        # dependencies = ['rich']
+        # ///
+        #
+        # A trailing comment.
+
+        import rich
+        "};
+
+        let actual = ScriptTag::parse(contents).unwrap().unwrap();
+
+        assert_eq!(actual.metadata, "dependencies = ['rich']\n");
+        assert_eq!(actual.postlude, "#\n# A trailing comment.\n\nimport rich\n");
+    }
+}
@@ -1,16 +1,17 @@
 //! Resolve the current [`ProjectWorkspace`] or [`Workspace`].
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 
 use glob::{glob, GlobError, PatternError};
 use rustc_hash::FxHashSet;
 use tracing::{debug, trace};
 
-use pep508_rs::VerbatimUrl;
+use pep508_rs::{MarkerTree, VerbatimUrl};
 use pypi_types::{Requirement, RequirementSource};
+use uv_configuration::{ConflictSet, Conflicts, RequiredEnvironment};
 use uv_fs::{absolutize_path, Simplified};
-use uv_normalize::PackageName;
+use uv_normalize::{GroupName, PackageName};
 use uv_warnings::warn_user;
 
 use crate::pyproject::{Project, PyProjectToml, Source, ToolUvWorkspace};
@@ -51,6 +52,13 @@ pub struct Workspace {
     /// The sources table from the workspace `pyproject.toml`. It is overridden by the project
     /// sources.
     sources: BTreeMap<PackageName, Source>,
+    /// The environments to lock for, from the workspace `pyproject.toml`.
+    #[cfg_attr(test, serde(skip_serializing_if = "Option::is_none"))]
+    environments: Option<Vec<MarkerTree>>,
+    /// The environments that require a compatible wheel for every locked distribution, from the
+    /// workspace `pyproject.toml`.
+    #[cfg_attr(test, serde(skip_serializing_if = "Option::is_none"))]
+    required_environments: Option<Vec<RequiredEnvironment>>,
 }
 
 impl Workspace {
@@ -192,6 +200,40 @@ impl Workspace {
             .collect()
     }
 
+    /// Returns the names of the PEP 735 `dependency-groups` declared by any workspace member.
+    pub fn dependency_groups(&self) -> BTreeSet<GroupName> {
+        self.packages
+            .values()
+            .filter_map(|member| member.pyproject_toml.dependency_groups.as_ref())
+            .flat_map(|groups| groups.keys().cloned())
+            .collect()
+    }
+
+    /// Returns the conflicting extras and dependency groups declared via `tool.uv.conflicts` by any
+    /// workspace member.
+    pub fn conflicts(&self) -> Conflicts {
+        self.packages
+            .values()
+            .filter_map(|member| {
+                let project = member.pyproject_toml.project.as_ref()?;
+                let conflicts = member
+                    .pyproject_toml
+                    .tool
+                    .as_ref()
+                    .and_then(|tool| tool.uv.as_ref())
+                    .and_then(|uv| uv.conflicts.as_ref())?;
+                Some((project, conflicts))
+            })
+            .flat_map(|(project, conflicts)| {
+                conflicts.iter().map(|set| {
+                    set.iter()
+                        .map(|item| item.to_conflict_item(&project.name))
+                        .collect::<ConflictSet>()
+                })
+            })
+            .collect()
+    }
+
     /// If there is a package at the workspace root, return it.
     pub fn root_member(&self) -> Option<&WorkspaceMember> {
         self.packages
@@ -220,6 +262,18 @@ impl Workspace {
         &self.sources
     }
 
+    /// The environments to lock for, from `tool.uv.environments` in the workspace
+    /// `pyproject.toml`, if any.
+    pub fn environments(&self) -> Option<&[MarkerTree]> {
+        self.environments.as_deref()
+    }
+
+    /// The environments that require a compatible wheel for every locked distribution, from
+    /// `tool.uv.required-environments` in the workspace `pyproject.toml`, if any.
+    pub fn required_environments(&self) -> Option<&[RequiredEnvironment]> {
+        self.required_environments.as_deref()
+    }
+
     /// Collect the workspace member projects from the `members` and `excludes` entries.
     async fn collect_members(
         workspace_root: PathBuf,
@@ -319,11 +373,12 @@ impl Workspace {
                 );
             }
         }
-        let workspace_sources = workspace_pyproject_toml
-            .tool
-            .and_then(|tool| tool.uv)
-            .and_then(|uv| uv.sources)
-            .unwrap_or_default();
+        let workspace_uv = workspace_pyproject_toml.tool.and_then(|tool| tool.uv);
+        let workspace_environments = workspace_uv.as_ref().and_then(|uv| uv.environments.clone());
+        let workspace_required_environments = workspace_uv
+            .as_ref()
+            .and_then(|uv| uv.required_environments.clone());
+        let workspace_sources = workspace_uv.and_then(|uv| uv.sources).unwrap_or_default();
 
         check_nested_workspaces(&workspace_root, stop_discovery_at);
 
@@ -331,6 +386,8 @@ impl Workspace {
             root: workspace_root,
             packages: workspace_members,
             sources: workspace_sources,
+            environments: workspace_environments,
+            required_environments: workspace_required_environments,
         })
     }
 }
@@ -592,6 +649,16 @@ impl ProjectWorkspace {
             // above it, so the project is an implicit workspace root identical to the project root.
             debug!("No workspace root found, using project root");
 
+            let project_environments = project_pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.environments.clone());
+            let project_required_environments = project_pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.required_environments.clone());
             let current_project_as_members =
                 BTreeMap::from_iter([(project.name.clone(), current_project)]);
             return Ok(Self {
@@ -603,6 +670,8 @@ impl ProjectWorkspace {
                     // There may be package sources, but we don't need to duplicate them into the
                     // workspace sources.
                     sources: BTreeMap::default(),
+                    environments: project_environments,
+                    required_environments: project_required_environments,
                 },
             });
         };
@@ -1,19 +1,18 @@
+use std::collections::BTreeMap;
+use std::io;
 use std::num::NonZeroUsize;
-use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::time::Duration;
-use std::{io, panic};
 
-use async_channel::{Receiver, SendError};
 use tempfile::tempdir_in;
 use thiserror::Error;
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command};
-use tokio::sync::oneshot;
 use tracing::{debug, instrument};
 use walkdir::WalkDir;
 
+use uv_configuration::BytecodeOptimization;
 use uv_fs::Simplified;
 use uv_warnings::warn_user;
 
@@ -25,8 +24,8 @@ const COMPILE_TIMEOUT: Duration = Duration::from_secs(60);
 pub enum CompileError {
     #[error("Failed to list files in `site-packages`")]
     Walkdir(#[from] walkdir::Error),
-    #[error("Failed to send task to worker")]
-    WorkerDisappeared(SendError<PathBuf>),
+    #[error("Failed to read or update the `RECORD` of: {}", _0.user_display())]
+    Record(PathBuf, #[source] install_wheel_rs::Error),
     #[error("The task executor is broken, did some other task panic?")]
     Join,
     #[error("Failed to start Python interpreter to run compile script")]
@@ -51,8 +50,8 @@ pub enum CompileError {
     Timeout(Duration),
 }
 
-/// Bytecode compile all file in `dir` using a pool of Python interpreters running a Python script
-/// that calls `compileall.compile_file`.
+/// Bytecode compile all file in `dir` using a Python interpreter running a Python script that
+/// calls `compileall.compile_file` on a pool of worker processes.
 ///
 /// All compilation errors are muted (like pip). There is a 60s timeout for each file to handle
 /// a broken `python`.
@@ -71,110 +70,164 @@ pub async fn compile_tree(
         dir.is_absolute(),
         "compileall doesn't work with relative paths"
     );
-    let worker_count = std::thread::available_parallelism().unwrap_or_else(|err| {
-        warn_user!("Couldn't determine number of cores, compiling with a single thread: {err}");
-        NonZeroUsize::MIN
-    });
-
-    // A larger buffer is significantly faster than just 1 or the worker count.
-    let (sender, receiver) = async_channel::bounded::<PathBuf>(worker_count.get() * 10);
-
-    // Running Python with an actual file will produce better error messages.
-    let tempdir = tempdir_in(cache).map_err(CompileError::TempFile)?;
-    let pip_compileall_py = tempdir.path().join("pip_compileall.py");
 
-    debug!("Starting {} bytecode compilation workers", worker_count);
-    let mut worker_handles = Vec::new();
-    for _ in 0..worker_count.get() {
-        let (tx, rx) = oneshot::channel();
-
-        let worker = worker(
-            dir.to_path_buf(),
-            python_executable.to_path_buf(),
-            pip_compileall_py.clone(),
-            receiver.clone(),
-        );
-
-        // Spawn each worker on a dedicated thread.
-        std::thread::Builder::new()
-            .name("uv-compile".to_owned())
-            .spawn(move || {
-                // Report panics back to the main thread.
-                let result = panic::catch_unwind(AssertUnwindSafe(|| {
-                    tokio::runtime::Builder::new_current_thread()
-                        .enable_all()
-                        .build()
-                        .expect("Failed to build runtime")
-                        .block_on(worker)
-                }));
-
-                // This may fail if the main thread returned early due to an error.
-                let _ = tx.send(result);
-            })
-            .expect("Failed to start compilation worker");
-
-        worker_handles.push(async { rx.await.unwrap() });
-    }
-    // Make sure the channel gets closed when all workers exit.
-    drop(receiver);
-
-    // Start the producer, sending all `.py` files to workers.
-    let mut source_files = 0;
-    let mut send_error = None;
     let walker = WalkDir::new(dir)
         .into_iter()
         // Otherwise we stumble over temporary files from `compileall`.
         .filter_entry(|dir| dir.file_name() != "__pycache__");
-    for entry in walker {
-        let entry = entry?;
+    let source_files = walker.filter_map(|entry| {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => return Some(Err(CompileError::from(err))),
+        };
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(err) => return Some(Err(CompileError::from(err))),
+        };
         // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
-        if entry.metadata()?.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
-            source_files += 1;
-            if let Err(err) = sender.send(entry.path().to_owned()).await {
-                // The workers exited.
-                // If e.g. something with the Python interpreter is wrong, the workers have exited
-                // with an error. We try to report this informative error and only if that fails,
-                // report the send error.
-                send_error = Some(err);
-                break;
+        if metadata.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
+            Some(Ok((0, entry.into_path())))
+        } else {
+            None
+        }
+    });
+
+    let (source_files, _) = compile_files(
+        source_files,
+        available_parallelism(),
+        dir,
+        python_executable,
+        cache,
+        &[BytecodeOptimization::None],
+    )
+    .await?;
+
+    Ok(source_files)
+}
+
+/// Bytecode compile the Python files of the installed distributions at the given `.dist-info`
+/// directories, at each of the given optimization levels.
+///
+/// Unlike [`compile_tree`], only the files listed in the `RECORD` of each distribution are
+/// compiled, and the generated `.pyc` files are added to that `RECORD`, such that any installer
+/// can remove them on uninstall. Like [`compile_tree`], all compilation errors are muted.
+#[instrument(skip_all, fields(num_dists = %dist_infos.len()))]
+pub async fn compile_dists(
+    dist_infos: &[PathBuf],
+    python_executable: &Path,
+    cache: &Path,
+    optimization: &[BytecodeOptimization],
+) -> Result<usize, CompileError> {
+    // Collect the Python source files from the `RECORD` of each distribution.
+    let mut source_files = Vec::new();
+    for (index, dist_info) in dist_infos.iter().enumerate() {
+        debug_assert!(
+            dist_info.is_absolute(),
+            "compileall doesn't work with relative paths"
+        );
+        let Some(site_packages) = dist_info.parent() else {
+            continue;
+        };
+        let record = install_wheel_rs::read_record(dist_info)
+            .map_err(|err| CompileError::Record(dist_info.clone(), err))?;
+        for entry in record {
+            // Scripts and data files outside of `site-packages` aren't importable.
+            if !entry.is_in_site_packages() {
+                continue;
+            }
+            if Path::new(&entry.path)
+                .extension()
+                .is_some_and(|ext| ext == "py")
+            {
+                let path = site_packages.join(&entry.path);
+                if path.is_file() {
+                    source_files.push((index, path));
+                }
             }
         }
     }
 
-    // All workers will receive an error after the last item. Note that there are still
-    // up to worker_count * 10 items in the queue.
-    drop(sender);
+    let Some(file_count) = NonZeroUsize::new(source_files.len()) else {
+        return Ok(0);
+    };
 
-    // Make sure all workers exit regularly, avoid hiding errors.
-    for result in futures::future::join_all(worker_handles).await {
-        match result {
-            // There spawning earlier errored due to a panic in a task.
-            Err(_) => return Err(CompileError::Join),
-            // The worker reports an error.
-            Ok(Err(compile_error)) => return Err(compile_error),
-            Ok(Ok(())) => {}
+    // Avoid launching more worker processes than there are files to compile.
+    let worker_count = available_parallelism().min(file_count);
+
+    // Run the compiler from `site-packages`, as with `compile_tree`.
+    let site_packages = dist_infos
+        .iter()
+        .find_map(|dist_info| dist_info.parent())
+        .unwrap_or(cache);
+
+    let (source_files, compiled) = compile_files(
+        source_files.into_iter().map(Ok),
+        worker_count,
+        site_packages,
+        python_executable,
+        cache,
+        optimization,
+    )
+    .await?;
+
+    // Record the generated bytecode in the `RECORD` of the distribution that owns the source.
+    let mut compiled_by_dist: BTreeMap<usize, Vec<PathBuf>> = BTreeMap::new();
+    for (index, pyc) in compiled {
+        // With `PYTHONPYCACHEPREFIX`, the bytecode is written outside of `site-packages`.
+        if dist_infos[index]
+            .parent()
+            .is_some_and(|site_packages| pyc.starts_with(site_packages))
+        {
+            compiled_by_dist.entry(index).or_default().push(pyc);
         }
     }
-
-    if let Some(send_error) = send_error {
-        // This is suspicious: Why did the channel stop working, but all workers exited
-        // successfully?
-        return Err(CompileError::WorkerDisappeared(send_error));
+    for (index, pycs) in compiled_by_dist {
+        let dist_info = &dist_infos[index];
+        install_wheel_rs::extend_record(dist_info, &pycs)
+            .map_err(|err| CompileError::Record(dist_info.clone(), err))?;
     }
 
     Ok(source_files)
 }
 
-async fn worker(
-    dir: PathBuf,
-    interpreter: PathBuf,
-    pip_compileall_py: PathBuf,
-    receiver: Receiver<PathBuf>,
-) -> Result<(), CompileError> {
+/// Returns the number of bytecode compilation workers to launch.
+fn available_parallelism() -> NonZeroUsize {
+    std::thread::available_parallelism().unwrap_or_else(|err| {
+        warn_user!("Couldn't determine number of cores, compiling with a single thread: {err}");
+        NonZeroUsize::MIN
+    })
+}
+
+/// Compile the given source files, each tagged with the index of its owner, in a single Python
+/// interpreter that distributes them over a pool of `worker_count` worker processes.
+///
+/// Returns the number of source files and the generated `.pyc` files, tagged with the index of
+/// the corresponding source file's owner.
+async fn compile_files(
+    source_files: impl Iterator<Item = Result<(usize, PathBuf), CompileError>>,
+    worker_count: NonZeroUsize,
+    dir: &Path,
+    python_executable: &Path,
+    cache: &Path,
+    optimization: &[BytecodeOptimization],
+) -> Result<(usize, Vec<(usize, PathBuf)>), CompileError> {
+    let source_files = source_files.collect::<Result<Vec<_>, _>>()?;
+    if source_files.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+
+    // Running Python with an actual file will produce better error messages.
+    let tempdir = tempdir_in(cache).map_err(CompileError::TempFile)?;
+    let pip_compileall_py = tempdir.path().join("pip_compileall.py");
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
         .await
         .map_err(CompileError::TempFile)?;
 
+    debug!(
+        "Starting bytecode compilation with {} workers",
+        worker_count
+    );
+
     // Sometimes, the first time we read from stdout, we get an empty string back (no newline). If
     // we try to write to stdin, it will often be a broken pipe. In this case, we have to restart
     // the child process
@@ -182,8 +235,14 @@ async fn worker(
     let wait_until_ready = async {
         loop {
             // If the interpreter started successful, return it, else retry.
-            if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+            if let Some(child) = launch_bytecode_compiler(
+                dir,
+                python_executable,
+                &pip_compileall_py,
+                worker_count,
+                optimization,
+            )
+            .await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -204,7 +263,7 @@ async fn worker(
         Ok(child_stderr_collected)
     });
 
-    let result = worker_main_loop(receiver, child_stdin, &mut child_stdout).await;
+    let result = compile_main_loop(&source_files, child_stdin, &mut child_stdout).await;
     // Reap the process to avoid zombies.
     let _ = bytecode_compiler.kill().await;
 
@@ -222,13 +281,13 @@ async fn worker(
     } else {
         let stderr = String::from_utf8_lossy(&child_stderr_collected);
         match result {
-            Ok(()) => {
+            Ok(compiled) => {
                 debug!(
                     "Bytecode compilation `python` at {} stderr:\n{}\n---",
-                    interpreter.user_display(),
+                    python_executable.user_display(),
                     stderr
                 );
-                Ok(())
+                Ok(compiled)
             }
             Err(err) => Err(CompileError::ErrorWithStderr {
                 stderr: stderr.trim().to_string(),
@@ -237,9 +296,12 @@ async fn worker(
         }
     };
 
-    debug!("Bytecode compilation worker exiting: {:?}", result);
+    debug!(
+        "Bytecode compilation exiting: {:?}",
+        result.as_ref().map(Vec::len)
+    );
 
-    result
+    Ok((source_files.len(), result?))
 }
 
 /// Returns the child and stdin/stdout/stderr on a successful launch or `None` for a broken interpreter state.
@@ -247,6 +309,8 @@ async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
     pip_compileall_py: &Path,
+    worker_count: NonZeroUsize,
+    optimization: &[BytecodeOptimization],
 ) -> Result<
     Option<(
         Child,
@@ -257,8 +321,11 @@ async fn launch_bytecode_compiler(
     CompileError,
 > {
     // We input the paths through stdin and get the successful paths returned through stdout.
+    // The number of worker processes and the optimization levels are passed as arguments.
     let mut bytecode_compiler = Command::new(interpreter)
         .arg(pip_compileall_py)
+        .arg(worker_count.to_string())
+        .args(optimization.iter().map(ToString::to_string))
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -314,55 +381,84 @@ async fn launch_bytecode_compiler(
     }
 }
 
-/// We use stdin/stdout as a sort of bounded channel. We write one path to stdin, then wait until
-/// we get the same path back from stdout. This way we ensure one worker is only working on one
-/// piece of work at the same time.
-async fn worker_main_loop(
-    receiver: Receiver<PathBuf>,
+/// We write all paths to stdin, one per line, and close it to signal that there are no more files.
+/// The script then reports each file in the order it received them, such that we can check that we
+/// get the same path back from stdout.
+///
+/// Before echoing the path, the script writes the path of each `.pyc` file it generated for
+/// it, one per line, which we return alongside the index of the source file's owner.
+async fn compile_main_loop(
+    source_files: &[(usize, PathBuf)],
     mut child_stdin: ChildStdin,
     child_stdout: &mut BufReader<ChildStdout>,
-) -> Result<(), CompileError> {
-    let mut out_line = String::new();
-    while let Ok(source_file) = receiver.recv().await {
+) -> Result<Vec<(usize, PathBuf)>, CompileError> {
+    let mut pending = Vec::with_capacity(source_files.len());
+    let mut bytes = Vec::new();
+    for (index, source_file) in source_files {
         let source_file = source_file.display().to_string();
         if source_file.contains(['\r', '\n']) {
             warn_user!("Path contains newline, skipping: {source_file:?}");
             continue;
         }
         // Luckily, LF alone works on windows too
-        let bytes = format!("{source_file}\n").into_bytes();
+        bytes.extend_from_slice(source_file.as_bytes());
+        bytes.push(b'\n');
+        pending.push((*index, source_file));
+    }
+
+    let write_handle = async {
+        child_stdin
+            .write_all(&bytes)
+            .await
+            .map_err(|err| CompileError::ChildStdio {
+                device: "stdin",
+                err,
+            })?;
+        // Closing stdin tells the script to start compiling.
+        drop(child_stdin);
+        Ok::<(), CompileError>(())
+    };
+    tokio::time::timeout(COMPILE_TIMEOUT, write_handle)
+        .await
+        .map_err(|_| CompileError::Timeout(COMPILE_TIMEOUT))??;
 
+    let mut compiled = Vec::new();
+    let mut out_line = String::new();
+    for (index, source_file) in pending {
         let python_handle = async {
-            child_stdin
-                .write_all(&bytes)
-                .await
-                .map_err(|err| CompileError::ChildStdio {
-                    device: "stdin",
-                    err,
+            let mut pycs = Vec::new();
+            loop {
+                out_line.clear();
+                let read = child_stdout.read_line(&mut out_line).await.map_err(|err| {
+                    CompileError::ChildStdio {
+                        device: "stdout",
+                        err,
+                    }
                 })?;
-
-            out_line.clear();
-            child_stdout.read_line(&mut out_line).await.map_err(|err| {
-                CompileError::ChildStdio {
-                    device: "stdout",
-                    err,
+                let actual = out_line.trim_end_matches(['\n', '\r']);
+                // This is a sanity check, if we don't get the path back something has gone
+                // wrong, e.g. we're not actually running a python interpreter.
+                if read == 0 || !actual.ends_with(".pyc") {
+                    if actual != source_file {
+                        return Err(CompileError::WrongPath(
+                            source_file.clone(),
+                            actual.to_string(),
+                        ));
+                    }
+                    break;
                 }
-            })?;
-            Ok::<(), CompileError>(())
+                pycs.push(PathBuf::from(actual));
+            }
+            Ok::<Vec<PathBuf>, CompileError>(pycs)
         };
 
         // Handle a broken `python` by using a timeout, one that's higher than any compilation
         // should ever take.
-        tokio::time::timeout(COMPILE_TIMEOUT, python_handle)
+        let pycs = tokio::time::timeout(COMPILE_TIMEOUT, python_handle)
             .await
             .map_err(|_| CompileError::Timeout(COMPILE_TIMEOUT))??;
 
-        // This is a sanity check, if we don't get the path back something has gone wrong, e.g.
-        // we're not actually running a python interpreter.
-        let actual = out_line.trim_end_matches(['\n', '\r']);
-        if actual != source_file {
-            return Err(CompileError::WrongPath(source_file, actual.to_string()));
-        }
+        compiled.extend(pycs.into_iter().map(|pyc| (index, pyc)));
     }
-    Ok(())
+    Ok(compiled)
 }
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use distribution_types::{CachedDist, InstalledDist, Name};
+use install_wheel_rs::Layout;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_toolchain::PythonEnvironment;
+
+use crate::SitePackages;
+
+/// The maximum number of conflicting files to display per pair of distributions.
+const MAX_DISPLAYED_FILES: usize = 10;
+
+/// A file that would be written by a distribution, but is already owned by another distribution.
+#[derive(Debug, Clone)]
+pub struct FileConflict {
+    /// The absolute path to the conflicting file.
+    pub path: PathBuf,
+    /// The distribution that is being installed.
+    pub package: PackageName,
+    /// The distribution that previously owned the file.
+    pub previous: PackageName,
+}
+
+/// The [`FileConflict`]s detected ahead of an installation.
+#[derive(Debug, Clone)]
+pub struct FileConflicts {
+    conflicts: Vec<FileConflict>,
+    site_packages: PathBuf,
+}
+
+impl FileConflicts {
+    /// Returns `true` if there are no conflicts.
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Returns an iterator over the conflicting files.
+    pub fn iter(&self) -> impl Iterator<Item = &FileConflict> {
+        self.conflicts.iter()
+    }
+
+    /// Returns the conflicting files, grouped by the distribution being installed and the
+    /// distribution that previously owned the files.
+    pub fn grouped(&self) -> BTreeMap<(&PackageName, &PackageName), Vec<&Path>> {
+        let mut grouped: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for conflict in &self.conflicts {
+            grouped
+                .entry((&conflict.package, &conflict.previous))
+                .or_default()
+                .push(self.relative(&conflict.path));
+        }
+        grouped
+    }
+
+    /// Returns the path relative to `site-packages`, for display.
+    fn relative<'a>(&self, path: &'a Path) -> &'a Path {
+        path.strip_prefix(&self.site_packages).unwrap_or(path)
+    }
+}
+
+impl Display for FileConflicts {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, ((package, previous), paths)) in self.grouped().into_iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            let s = if paths.len() == 1 { "" } else { "s" };
+            write!(
+                f,
+                "`{package}` overwrites {} file{s} previously installed by `{previous}`:",
+                paths.len()
+            )?;
+            for path in paths.iter().take(MAX_DISPLAYED_FILES) {
+                write!(f, "\n  {}", path.simplified_display())?;
+            }
+            if paths.len() > MAX_DISPLAYED_FILES {
+                write!(f, "\n  ...and {} more", paths.len() - MAX_DISPLAYED_FILES)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Detect files that installing the given wheels would overwrite, where those files are owned
+/// by another distribution, either already installed in the environment or earlier in `wheels`.
+///
+/// The `RECORD` files of the installed distributions are only read if one of the wheels would
+/// write to a path that already exists in the environment.
+///
+/// Distributions without a `RECORD` (e.g., `.egg-info` distributions) are ignored.
+pub(crate) fn find_conflicts(
+    venv: &PythonEnvironment,
+    layout: &Layout,
+    wheels: &[CachedDist],
+) -> Result<FileConflicts> {
+    // Collect the files that each wheel would write.
+    let mut wheel_files = Vec::with_capacity(wheels.len());
+    for wheel in wheels {
+        let files = install_wheel_rs::linker::site_packages_files(layout, wheel.path())
+            .with_context(|| format!("Failed to read `RECORD` for: {wheel}"))?;
+        wheel_files.push(
+            files
+                .into_iter()
+                .map(|path| normalize(&path))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    // Index the files of the installed distributions by path, but only if any of the wheels
+    // would overwrite an existing file.
+    let mut owners: FxHashMap<PathBuf, PackageName> = FxHashMap::default();
+    let overlaps = wheel_files
+        .iter()
+        .flatten()
+        .any(|path| path.symlink_metadata().is_ok());
+    if overlaps {
+        let site_packages = SitePackages::from_executable(venv)?;
+        for dist in site_packages.iter() {
+            if !matches!(dist, InstalledDist::Registry(_) | InstalledDist::Url(_)) {
+                continue;
+            }
+            // Distributions that are about to be replaced don't own their files anymore.
+            if wheels.iter().any(|wheel| wheel.name() == dist.name()) {
+                continue;
+            }
+            let Some(root) = dist.path().parent() else {
+                continue;
+            };
+            let record = match install_wheel_rs::read_record(dist.path()) {
+                Ok(record) => record,
+                Err(install_wheel_rs::Error::MissingRecord(path)) => {
+                    debug!(
+                        "Skipping file conflict detection for `{}`; missing `RECORD` at: {}",
+                        dist.name(),
+                        path.user_display()
+                    );
+                    continue;
+                }
+                Err(err) => {
+                    return Err(err).with_context(|| format!("Failed to read `RECORD` for: {dist}"))
+                }
+            };
+            for entry in record {
+                if entry.is_in_site_packages() {
+                    owners.insert(normalize(&root.join(entry.path)), dist.name().clone());
+                }
+            }
+        }
+    }
+
+    // Check the files of each wheel against the files owned by other distributions.
+    let mut conflicts = Vec::new();
+    for (wheel, files) in wheels.iter().zip(wheel_files) {
+        for path in files {
+            match owners.get(&path) {
+                Some(previous) if previous != wheel.name() => {
+                    debug!(
+                        "`{}` overwrites a file previously installed by `{previous}`: {}",
+                        wheel.name(),
+                        path.user_display()
+                    );
+                    conflicts.push(FileConflict {
+                        path: path.clone(),
+                        package: wheel.name().clone(),
+                        previous: previous.clone(),
+                    });
+                    owners.insert(path, wheel.name().clone());
+                }
+                Some(_) => {}
+                None => {
+                    owners.insert(path, wheel.name().clone());
+                }
+            }
+        }
+    }
+
+    Ok(FileConflicts {
+        conflicts,
+        site_packages: normalize(&layout.scheme.purelib),
+    })
+}
+
+/// Normalize a path for comparison, resolving any `.` and `..` components (e.g., in `RECORD`
+/// entries like `foo/../foo/__init__.py`).
+fn normalize(path: &Path) -> PathBuf {
+    uv_fs::normalize_path(path).unwrap_or_else(|_| path.to_path_buf())
+}
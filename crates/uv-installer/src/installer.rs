@@ -1,13 +1,20 @@
-use anyhow::{Context, Error, Result};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Error, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use tracing::instrument;
 
 use distribution_types::CachedDist;
+use uv_configuration::FileConflictStrategy;
 use uv_toolchain::PythonEnvironment;
+use uv_warnings::warn_user;
+
+use crate::conflicts::find_conflicts;
 
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
     link_mode: install_wheel_rs::linker::LinkMode,
+    on_conflict: FileConflictStrategy,
     reporter: Option<Box<dyn Reporter>>,
     installer_name: Option<String>,
 }
@@ -18,6 +25,7 @@ impl<'a> Installer<'a> {
         Self {
             venv,
             link_mode: install_wheel_rs::linker::LinkMode::default(),
+            on_conflict: FileConflictStrategy::default(),
             reporter: None,
             installer_name: Some("uv".to_string()),
         }
@@ -29,6 +37,16 @@ impl<'a> Installer<'a> {
         Self { link_mode, ..self }
     }
 
+    /// Set the [`FileConflictStrategy`] to use when a wheel would overwrite files that belong to
+    /// another distribution.
+    #[must_use]
+    pub fn with_on_conflict(self, on_conflict: FileConflictStrategy) -> Self {
+        Self {
+            on_conflict,
+            ..self
+        }
+    }
+
     /// Set the [`Reporter`] to use for this installer.
     #[must_use]
     pub fn with_reporter(self, reporter: impl Reporter + 'static) -> Self {
@@ -48,32 +66,59 @@ impl<'a> Installer<'a> {
     }
 
     /// Install a set of wheels into a Python virtual environment.
+    ///
+    /// Returns the paths to the `.dist-info` directories of the installed distributions, in the
+    /// same order as the given wheels.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
-    pub fn install(self, wheels: &[CachedDist]) -> Result<()> {
+    pub fn install(self, wheels: &[CachedDist]) -> Result<Vec<PathBuf>> {
         let layout = self.venv.interpreter().layout();
-        tokio::task::block_in_place(|| {
-            wheels.par_iter().try_for_each(|wheel| {
-                install_wheel_rs::linker::install_wheel(
-                    &layout,
-                    wheel.path(),
-                    wheel.filename(),
-                    wheel
-                        .parsed_url()?
-                        .as_ref()
-                        .map(pypi_types::DirectUrl::try_from)
-                        .transpose()?
-                        .as_ref(),
-                    self.installer_name.as_deref(),
-                    self.link_mode,
-                )
-                .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
-
-                if let Some(reporter) = self.reporter.as_ref() {
-                    reporter.on_install_progress(wheel);
+
+        // Detect any files that would be overwritten, prior to modifying the environment.
+        if self.on_conflict.is_checked() {
+            let conflicts = find_conflicts(self.venv, &layout, wheels)?;
+            if !conflicts.is_empty() {
+                match self.on_conflict {
+                    FileConflictStrategy::Fail => {
+                        bail!(
+                            "Installation would overwrite files that belong to other packages:\n{conflicts}"
+                        );
+                    }
+                    FileConflictStrategy::Warn => {
+                        warn_user!("{conflicts}");
+                    }
+                    FileConflictStrategy::Allow => {}
                 }
+            }
+        }
+
+        tokio::task::block_in_place(|| {
+            wheels
+                .par_iter()
+                .map(|wheel| {
+                    let dist_info = install_wheel_rs::linker::install_wheel(
+                        &layout,
+                        wheel.path(),
+                        wheel.filename(),
+                        wheel
+                            .parsed_url()?
+                            .as_ref()
+                            .map(pypi_types::DirectUrl::try_from)
+                            .transpose()?
+                            .as_ref(),
+                        self.installer_name.as_deref(),
+                        self.link_mode,
+                    )
+                    .with_context(|| {
+                        format!("Failed to install: {} ({wheel})", wheel.filename())
+                    })?;
+
+                    if let Some(reporter) = self.reporter.as_ref() {
+                        reporter.on_install_progress(wheel);
+                    }
 
-                Ok::<(), Error>(())
-            })
+                    Ok::<PathBuf, Error>(dist_info)
+                })
+                .collect()
         })
     }
 }
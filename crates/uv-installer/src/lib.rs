@@ -1,4 +1,5 @@
-pub use compile::{compile_tree, CompileError};
+pub use compile::{compile_dists, compile_tree, CompileError};
+pub use conflicts::{FileConflict, FileConflicts};
 pub use downloader::{Downloader, Reporter as DownloadReporter};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
@@ -6,6 +7,7 @@ pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
 pub use uninstall::{uninstall, UninstallError};
 
 mod compile;
+mod conflicts;
 mod downloader;
 
 mod installer;
@@ -6,17 +6,51 @@ pip silently just swallows all pyc compilation errors, but `python -m compileall
 not have such a flag, so we adapt the pip code. This is relevant, e.g., for
 `debugpy-1.5.1-cp38-cp38-manylinux_2_5_x86_64.manylinux1_x86_64.manylinux_2_12_x86_64.manylinux2010_x86_64`,
 which contains some vendored Python 2 code which fails to compile.
+
+Rather than launching one interpreter per worker, a single interpreter reads all paths from
+stdin and compiles them on a pool of worker processes, like `python -m compileall -j`.
 """
 
 import compileall
+import importlib.util
 import os
 import py_compile
 import sys
 import warnings
 
-with warnings.catch_warnings():
-    warnings.filterwarnings("ignore")
 
+def compile_path(path, invalidation_mode, force, optimization_levels):
+    """Compile a single file at each optimization level, returning the generated bytecode."""
+    pycs = []
+    with warnings.catch_warnings():
+        warnings.filterwarnings("ignore")
+        for optimize in optimization_levels:
+            # Unlike pip, we set quiet=2, so we don't have to capture stdout.
+            # We'd like to show those errors, but given that pip thinks that's totally fine,
+            # we can't really change that.
+            success = compileall.compile_file(
+                path,
+                invalidation_mode=invalidation_mode,
+                force=force,
+                quiet=2,
+                optimize=optimize,
+            )
+            if not success:
+                continue
+            # Report the generated bytecode, such that it can be added to the `RECORD`.
+            try:
+                pyc = importlib.util.cache_from_source(
+                    path, optimization="" if optimize == 0 else optimize
+                )
+            except NotImplementedError:
+                # `sys.implementation.cache_tag` is `None`, bytecode caching is disabled.
+                continue
+            if os.path.isfile(pyc):
+                pycs.append(pyc)
+    return pycs
+
+
+def main():
     # Successful launch check
     print("Ready")
 
@@ -51,17 +85,51 @@ with warnings.catch_warnings():
         # based and has a matching mtime (unless force=True).
         force = True
 
-    # In rust, we provide one line per file to compile.
-    for path in sys.stdin:
-        # Remove trailing newlines.
-        path = path.strip()
-        if not path:
-            continue
-        # Unlike pip, we set quiet=2, so we don't have to capture stdout.
-        # We'd like to show those errors, but given that pip thinks that's totally fine,
-        # we can't really change that.
-        success = compileall.compile_file(
-            path, invalidation_mode=invalidation_mode, force=force, quiet=2
+    # The number of worker processes, followed by the optimization levels to compile at
+    # (`0` by default).
+    workers = int(sys.argv[1]) if len(sys.argv) > 1 else 1
+    optimization_levels = [int(level) for level in sys.argv[2:]] or [0]
+
+    # In rust, we provide one line per file to compile, and close stdin after the last one.
+    paths = [path.strip() for path in sys.stdin]
+    paths = [path for path in paths if path]
+
+    def compile_all(map_fn, chunksize=1):
+        results = map_fn(
+            compile_path,
+            paths,
+            [invalidation_mode] * len(paths),
+            [force] * len(paths),
+            [optimization_levels] * len(paths),
+            chunksize=chunksize,
         )
-        # We're ready for the next file.
-        print(path)
+        for path, pycs in zip(paths, results):
+            for pyc in pycs:
+                print(pyc)
+            # The file is done, report it after its bytecode.
+            print(path)
+
+    executor = None
+    if workers > 1 and len(paths) > 1:
+        # Like `compileall.compile_dir`, fall back to compiling serially if the platform
+        # doesn't support process pools.
+        try:
+            from concurrent.futures import ProcessPoolExecutor
+
+            executor = ProcessPoolExecutor(max_workers=workers)
+        except (ImportError, NotImplementedError, OSError):
+            executor = None
+
+    if executor is None:
+        compile_all(lambda fn, *iterables, chunksize: map(fn, *iterables))
+    else:
+        with executor:
+            # Batch the files to reduce the overhead of sending them to the workers.
+            chunksize = max(1, min(64, len(paths) // (workers * 4)))
+            compile_all(executor.map, chunksize=chunksize)
+
+
+# Guard the entrypoint, as the worker processes re-import this script on platforms that spawn
+# rather than fork them.
+if __name__ == "__main__":
+    main()
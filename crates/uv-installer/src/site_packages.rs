@@ -11,6 +11,7 @@ use distribution_types::{
     Diagnostic, InstalledDist, Name, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use pep440_rs::{Version, VersionSpecifiers};
+use platform_tags::Tags;
 use pypi_types::{Requirement, VerbatimParsedUrl};
 use uv_normalize::PackageName;
 use uv_toolchain::PythonEnvironment;
@@ -154,6 +155,29 @@ impl SitePackages {
         self.distributions.iter().any(Option::is_some)
     }
 
+    /// Validate that the installed packages were built for a platform and interpreter that are
+    /// compatible with the virtual environment's interpreter (e.g., after the environment was
+    /// repointed at a different interpreter).
+    ///
+    /// Unlike [`SitePackages::diagnostics`], this reads the `WHEEL` file of every installed
+    /// distribution, and so is only performed on request.
+    pub fn platform_diagnostics(&self) -> Result<Vec<SitePackagesDiagnostic>> {
+        let tags = self.venv.interpreter().tags()?;
+        let mut diagnostics = Vec::new();
+        for distribution in self.iter() {
+            if let Ok(Some(wheel_tags)) = distribution.tags() {
+                if !wheel_tags.is_empty() && !wheel_tags.iter().any(|tag| is_compatible(tag, tags))
+                {
+                    diagnostics.push(SitePackagesDiagnostic::IncompatiblePlatform {
+                        package: distribution.name().clone(),
+                        tags: wheel_tags,
+                    });
+                }
+            }
+        }
+        Ok(diagnostics)
+    }
+
     /// Validate the installed packages in the virtual environment.
     pub fn diagnostics(&self) -> Result<Vec<SitePackagesDiagnostic>> {
         let mut diagnostics = Vec::new();
@@ -405,6 +429,12 @@ pub enum SitePackagesDiagnostic {
         /// The installed versions of the package.
         paths: Vec<PathBuf>,
     },
+    IncompatiblePlatform {
+        /// The package that was built for a different platform or interpreter.
+        package: PackageName,
+        /// The wheel tags of the installed distribution.
+        tags: Vec<String>,
+    },
 }
 
 impl Diagnostic for SitePackagesDiagnostic {
@@ -442,6 +472,10 @@ impl Diagnostic for SitePackagesDiagnostic {
                     paths.iter().fold(String::new(), |acc, path| acc + &format!("\n  - {}", path.display()))
                 )
             }
+            Self::IncompatiblePlatform { package, tags } => format!(
+                "The package `{package}` was built for an incompatible platform or interpreter (`{}`). Consider reinstalling it.",
+                tags.join("`, `")
+            ),
         }
     }
 
@@ -457,6 +491,7 @@ impl Diagnostic for SitePackagesDiagnostic {
                 ..
             } => name == package || &requirement.name == name,
             Self::DuplicatePackage { package, .. } => name == package,
+            Self::IncompatiblePlatform { package, .. } => name == package,
         }
     }
 }
@@ -470,3 +505,18 @@ impl InstalledPackagesProvider for SitePackages {
         self.get_packages(name)
     }
 }
+
+/// Returns `true` if the given wheel tag (e.g., `cp312-cp312-manylinux_2_17_x86_64`) is
+/// compatible with the given [`Tags`].
+///
+/// Tags that can't be parsed are assumed to be compatible.
+fn is_compatible(tag: &str, tags: &Tags) -> bool {
+    let mut parts = tag.split('-');
+    let (Some(python), Some(abi), Some(platform), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return true;
+    };
+    let split = |part: &str| part.split('.').map(ToString::to_string).collect::<Vec<_>>();
+    tags.is_compatible(&split(python), &split(abi), &split(platform))
+}
@@ -13,7 +13,7 @@ use pypi_types::Requirement;
 use uv_configuration::ExtrasSpecification;
 use uv_distribution::{DistributionDatabase, Reporter, RequiresDist};
 use uv_fs::Simplified;
-use uv_normalize::{ExtraName, PackageName};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_resolver::{InMemoryIndex, MetadataResponse};
 use uv_types::{BuildContext, HashStrategy};
 
@@ -36,6 +36,8 @@ pub struct SourceTreeResolver<'a, Context: BuildContext> {
     source_trees: Vec<PathBuf>,
     /// The extras to include when resolving requirements.
     extras: &'a ExtrasSpecification,
+    /// The dependency groups to include when resolving requirements.
+    groups: &'a [GroupName],
     /// The hash policy to enforce.
     hasher: &'a HashStrategy,
     /// The in-memory index for resolving dependencies.
@@ -56,12 +58,20 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         Self {
             source_trees,
             extras,
+            groups: &[],
             hasher,
             index,
             database,
         }
     }
 
+    /// Include the given dependency groups (e.g., from `dependency-groups`) in the resolved
+    /// requirements.
+    #[must_use]
+    pub fn with_groups(self, groups: &'a [GroupName]) -> Self {
+        Self { groups, ..self }
+    }
+
     /// Set the [`Reporter`] to use for this resolver.
     #[must_use]
     pub fn with_reporter(self, reporter: impl Reporter + 'static) -> Self {
@@ -133,6 +143,25 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
             }
         }
 
+        // Add the requirements from any requested dependency groups.
+        let mut dev_dependencies = metadata.dev_dependencies;
+        for group in self.groups {
+            let Some(group_requirements) = dev_dependencies.remove(group) else {
+                return Err(anyhow::anyhow!(
+                    "Group `{group}` is not defined in: `{}`",
+                    path.user_display()
+                ));
+            };
+            requirements.extend(
+                group_requirements
+                    .into_iter()
+                    .map(|requirement| Requirement {
+                        origin: Some(origin.clone()),
+                        ..requirement
+                    }),
+            );
+        }
+
         let project = metadata.name;
         let extras = metadata.provides_extras;
 
@@ -1,14 +1,21 @@
+use std::ffi::OsStr;
 use std::path::Path;
+use std::str::FromStr;
 
-use anstream::eprint;
-use anyhow::Result;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::debug;
 
+use pep440_rs::Version;
+use pep508_rs::MarkerTree;
 use requirements_txt::RequirementsTxt;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::Upgrade;
-use uv_distribution::Workspace;
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
+use uv_normalize::PackageName;
 use uv_resolver::{Lock, Preference, PreferenceError};
+use uv_warnings::warn_user;
 
 #[derive(Debug, Default)]
 pub struct LockedRequirements {
@@ -64,28 +71,12 @@ pub async fn read_requirements_txt(
 }
 
 /// Load the preferred requirements from an existing lockfile, applying the upgrade strategy.
-pub async fn read_lockfile(workspace: &Workspace, upgrade: &Upgrade) -> Result<LockedRequirements> {
+pub fn read_lockfile(lock: &Lock, upgrade: &Upgrade) -> LockedRequirements {
     // As an optimization, skip reading the lockfile is we're upgrading all packages anyway.
     if upgrade.is_all() {
-        return Ok(LockedRequirements::default());
+        return LockedRequirements::default();
     }
 
-    // If an existing lockfile exists, build up a set of preferences.
-    let lockfile = workspace.root().join("uv.lock");
-    let lock = match fs_err::tokio::read_to_string(&lockfile).await {
-        Ok(encoded) => match toml::from_str::<Lock>(&encoded) {
-            Ok(lock) => lock,
-            Err(err) => {
-                eprint!("Failed to parse lockfile; ignoring locked requirements: {err}");
-                return Ok(LockedRequirements::default());
-            }
-        },
-        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-            return Ok(LockedRequirements::default());
-        }
-        Err(err) => return Err(err.into()),
-    };
-
     let mut preferences = Vec::new();
     let mut git = Vec::new();
 
@@ -108,5 +99,147 @@ pub async fn read_lockfile(workspace: &Workspace, upgrade: &Upgrade) -> Result<L
         }
     }
 
-    Ok(LockedRequirements { preferences, git })
+    LockedRequirements { preferences, git }
+}
+
+/// A `poetry.lock` or `pdm.lock` file, of which only the pinned package versions are read.
+#[derive(Debug, Deserialize)]
+struct ForeignLock {
+    #[serde(default)]
+    package: Vec<ForeignLockPackage>,
+}
+
+/// A pinned package in a `poetry.lock` or `pdm.lock` file.
+#[derive(Debug, Deserialize)]
+struct ForeignLockPackage {
+    name: PackageName,
+    version: Option<String>,
+    /// The markers under which the package is included (`markers` in `poetry.lock`, `marker` in
+    /// `pdm.lock`).
+    #[serde(alias = "marker")]
+    markers: Option<String>,
+    /// The source of the package in a `poetry.lock` file, if not the default index.
+    source: Option<PoetrySource>,
+    /// The Git repository of the package in a `pdm.lock` file.
+    git: Option<String>,
+    /// The direct URL of the package in a `pdm.lock` file.
+    url: Option<String>,
+    /// The local path of the package in a `pdm.lock` file.
+    path: Option<String>,
+}
+
+/// The `[package.source]` table of a package in a `poetry.lock` file.
+#[derive(Debug, Deserialize)]
+struct PoetrySource {
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+impl ForeignLockPackage {
+    /// Returns the kind of non-registry source (e.g., `git`) from which the package is installed,
+    /// if any.
+    ///
+    /// Packages installed from an alternative index (`legacy` in `poetry.lock`) are treated as
+    /// registry packages, since only their version is imported.
+    fn direct_source(&self) -> Option<&str> {
+        if let Some(source) = self.source.as_ref() {
+            return (source.kind != "legacy").then_some(source.kind.as_str());
+        }
+        if self.git.is_some() {
+            Some("git")
+        } else if self.url.is_some() {
+            Some("url")
+        } else if self.path.is_some() {
+            Some("path")
+        } else {
+            None
+        }
+    }
+}
+
+/// Load the preferred requirements from a lockfile produced by another tool (i.e., a
+/// `requirements.txt`, `poetry.lock`, or `pdm.lock` file), applying the upgrade strategy.
+///
+/// The format is inferred from the file name, falling back to `requirements.txt`.
+pub async fn read_import(path: &Path, upgrade: &Upgrade) -> Result<Vec<Preference>> {
+    // As an optimization, skip reading the file if we're upgrading all packages anyway.
+    if upgrade.is_all() {
+        return Ok(Vec::new());
+    }
+
+    let preferences = match path.file_name().and_then(OsStr::to_str) {
+        Some("poetry.lock" | "pdm.lock") => {
+            let contents = fs_err::tokio::read_to_string(path).await?;
+            let lock: ForeignLock = toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+            let mut preferences = Vec::with_capacity(lock.package.len());
+            for package in lock.package {
+                // Only the pinned version is imported, so packages from other sources (e.g., Git
+                // repositories) can't be represented.
+                if let Some(source) = package.direct_source() {
+                    warn_user!(
+                        "Skipping `{}` in `{}`, which is installed from a `{source}` source; only packages from an index can be imported",
+                        package.name,
+                        path.user_display()
+                    );
+                    continue;
+                }
+                let marker = package
+                    .markers
+                    .as_deref()
+                    .map(MarkerTree::from_str)
+                    .transpose()
+                    .with_context(|| {
+                        format!(
+                            "Invalid markers for `{}` in `{}`",
+                            package.name,
+                            path.user_display()
+                        )
+                    })?;
+                let Some(version) = package.version else {
+                    continue;
+                };
+                match Version::from_str(&version) {
+                    Ok(version) => {
+                        preferences.push(Preference::from_import(package.name, version, marker));
+                    }
+                    Err(err) => {
+                        debug!(
+                            "Ignoring invalid version for `{}` in `{}`: {err}",
+                            package.name,
+                            path.user_display()
+                        );
+                    }
+                }
+            }
+            preferences
+        }
+        _ => {
+            let requirements_txt = RequirementsTxt::parse(
+                path,
+                std::env::current_dir()?,
+                &BaseClientBuilder::new().connectivity(Connectivity::Offline),
+            )
+            .await?;
+            requirements_txt
+                .requirements
+                .into_iter()
+                .map(Preference::from_entry)
+                .filter_map(Result::transpose)
+                .collect::<Result<Vec<_>, PreferenceError>>()?
+        }
+    };
+
+    // Apply the upgrade strategy to the requirements.
+    Ok(match upgrade {
+        // Respect all imported versions.
+        Upgrade::None => preferences,
+        // Ignore all imported versions.
+        Upgrade::All => vec![],
+        // Ignore imported versions for the specified packages.
+        Upgrade::Packages(packages) => preferences
+            .into_iter()
+            .filter(|preference| !packages.contains(preference.name()))
+            .collect(),
+    })
 }
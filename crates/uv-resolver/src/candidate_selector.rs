@@ -1,5 +1,6 @@
 use itertools::Itertools;
 use pubgrub::range::Range;
+use rustc_hash::FxHashMap;
 use tracing::debug;
 
 use distribution_types::{CompatibleDist, IncompatibleDist, IncompatibleSource};
@@ -20,14 +21,16 @@ use crate::{Exclusions, Manifest, Options};
 #[allow(clippy::struct_field_names)]
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
+    resolution_package: FxHashMap<PackageName, ResolutionStrategy>,
     prerelease_strategy: PreReleaseStrategy,
+    prerelease_package: FxHashMap<PackageName, PreReleaseStrategy>,
     index_strategy: IndexStrategy,
 }
 
 impl CandidateSelector {
     /// Return a [`CandidateSelector`] for the given [`Manifest`].
     pub(crate) fn for_resolution(
-        options: Options,
+        options: &Options,
         manifest: &Manifest,
         markers: Option<&MarkerEnvironment>,
     ) -> Self {
@@ -38,26 +41,58 @@ impl CandidateSelector {
                 markers,
                 options.dependency_mode,
             ),
+            resolution_package: options
+                .resolution_package
+                .iter()
+                .map(|(package_name, mode)| {
+                    let strategy = ResolutionStrategy::from_mode(
+                        *mode,
+                        manifest,
+                        markers,
+                        options.dependency_mode,
+                    );
+                    (package_name.clone(), strategy)
+                })
+                .collect(),
             prerelease_strategy: PreReleaseStrategy::from_mode(
                 options.prerelease_mode,
                 manifest,
                 markers,
                 options.dependency_mode,
             ),
+            prerelease_package: options
+                .prerelease_package
+                .iter()
+                .map(|(package_name, mode)| {
+                    let strategy = PreReleaseStrategy::from_mode(
+                        *mode,
+                        manifest,
+                        markers,
+                        options.dependency_mode,
+                    );
+                    (package_name.clone(), strategy)
+                })
+                .collect(),
             index_strategy: options.index_strategy,
         }
     }
 
+    /// Return the [`ResolutionStrategy`] for the given package, respecting any per-package
+    /// overrides.
     #[inline]
-    #[allow(dead_code)]
-    pub(crate) fn resolution_strategy(&self) -> &ResolutionStrategy {
-        &self.resolution_strategy
+    pub(crate) fn resolution_strategy(&self, package_name: &PackageName) -> &ResolutionStrategy {
+        self.resolution_package
+            .get(package_name)
+            .unwrap_or(&self.resolution_strategy)
     }
 
+    /// Return the [`PreReleaseStrategy`] for the given package, respecting any per-package
+    /// overrides.
     #[inline]
-    #[allow(dead_code)]
-    pub(crate) fn prerelease_strategy(&self) -> &PreReleaseStrategy {
-        &self.prerelease_strategy
+    pub(crate) fn prerelease_strategy(&self, package_name: &PackageName) -> &PreReleaseStrategy {
+        self.prerelease_package
+            .get(package_name)
+            .unwrap_or(&self.prerelease_strategy)
     }
 
     #[inline]
@@ -183,7 +218,7 @@ impl CandidateSelector {
 
     /// Determine the appropriate prerelease strategy for the current package.
     fn allow_prereleases(&self, package_name: &PackageName) -> AllowPreRelease {
-        match &self.prerelease_strategy {
+        match self.prerelease_strategy(package_name) {
             PreReleaseStrategy::Disallow => AllowPreRelease::No,
             PreReleaseStrategy::Allow => AllowPreRelease::Yes,
             PreReleaseStrategy::IfNecessary => AllowPreRelease::IfNecessary,
@@ -219,7 +254,12 @@ impl CandidateSelector {
         let highest = self.use_highest_version(package_name);
         let allow_prerelease = self.allow_prereleases(package_name);
 
-        if self.index_strategy == IndexStrategy::UnsafeBestMatch {
+        // Under `first-index`, multiple version maps are only returned for indexes that declare
+        // (via PEP 708) that they share the project's namespace, so it's safe to merge them.
+        if matches!(
+            self.index_strategy,
+            IndexStrategy::UnsafeBestMatch | IndexStrategy::FirstIndex
+        ) {
             if highest {
                 Self::select_candidate(
                     version_maps
@@ -267,7 +307,7 @@ impl CandidateSelector {
     /// By default, we select the latest version, but we also allow using the lowest version instead
     /// to check the lower bounds.
     pub(crate) fn use_highest_version(&self, package_name: &PackageName) -> bool {
-        match &self.resolution_strategy {
+        match self.resolution_strategy(package_name) {
             ResolutionStrategy::Highest => true,
             ResolutionStrategy::Lowest => false,
             ResolutionStrategy::LowestDirect(direct_dependencies) => {
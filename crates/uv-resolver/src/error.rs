@@ -3,23 +3,26 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use dashmap::DashMap;
+use indexmap::IndexSet;
 use pubgrub::range::Range;
 use pubgrub::report::{DefaultStringReporter, DerivationTree, External, Reporter};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use distribution_types::{BuiltDist, IndexLocations, InstalledDist, SourceDist};
 use pep440_rs::Version;
-use pep508_rs::Requirement;
+use pep508_rs::{MarkerTree, Requirement};
 use uv_normalize::PackageName;
 
 use crate::candidate_selector::CandidateSelector;
 use crate::dependency_provider::UvDependencyProvider;
+use crate::failure_report::NoSolutionReport;
 use crate::pubgrub::{
-    PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter, PubGrubSpecifierError,
+    PubGrubHint, PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter, PubGrubSpecifierError,
 };
 use crate::python_requirement::PythonRequirement;
 use crate::resolver::{
-    FxOnceMap, IncompletePackage, UnavailablePackage, UnavailableReason, VersionsResponse,
+    FxOnceMap, IncompletePackage, Relaxation, UnavailablePackage, UnavailableReason,
+    VersionsResponse,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -178,6 +181,8 @@ impl From<pubgrub::error::PubGrubError<UvDependencyProvider>> for ResolveError {
                     index_locations: None,
                     unavailable_packages: FxHashMap::default(),
                     incomplete_packages: FxHashMap::default(),
+                    fork_markers: Vec::new(),
+                    relaxations: Vec::new(),
                 })
             }
             pubgrub::error::PubGrubError::SelfDependency { package, version } => {
@@ -200,6 +205,8 @@ pub struct NoSolutionError {
     index_locations: Option<IndexLocations>,
     unavailable_packages: FxHashMap<PackageName, UnavailablePackage>,
     incomplete_packages: FxHashMap<PackageName, BTreeMap<Version, IncompletePackage>>,
+    fork_markers: Vec<MarkerTree>,
+    relaxations: Vec<Relaxation>,
 }
 
 impl std::error::Error for NoSolutionError {}
@@ -216,13 +223,7 @@ impl std::fmt::Display for NoSolutionError {
         write!(f, "{report}")?;
 
         // Include any additional hints.
-        for hint in formatter.hints(
-            &self.derivation_tree,
-            &self.selector,
-            &self.index_locations,
-            &self.unavailable_packages,
-            &self.incomplete_packages,
-        ) {
+        for hint in self.hints(&formatter) {
             write!(f, "\n\n{hint}")?;
         }
 
@@ -231,6 +232,44 @@ impl std::fmt::Display for NoSolutionError {
 }
 
 impl NoSolutionError {
+    /// Generate a machine-readable report of the resolution failure.
+    pub fn report(&self) -> NoSolutionReport {
+        let formatter = PubGrubReportFormatter {
+            available_versions: &self.available_versions,
+            python_requirement: self.python_requirement.as_ref(),
+        };
+        let hints = self.hints(&formatter);
+        NoSolutionReport::new(
+            &self.to_string(),
+            &self.derivation_tree,
+            hints,
+            &self.fork_markers,
+        )
+    }
+
+    /// Return the hints for the resolution failure, including any suggested relaxations.
+    fn hints(&self, formatter: &PubGrubReportFormatter) -> IndexSet<PubGrubHint> {
+        let mut hints = formatter.hints(
+            &self.derivation_tree,
+            &self.selector,
+            &self.index_locations,
+            &self.unavailable_packages,
+            &self.incomplete_packages,
+        );
+        hints.extend(
+            self.relaxations
+                .iter()
+                .cloned()
+                .map(|relaxation| PubGrubHint::Relax { relaxation }),
+        );
+        hints
+    }
+
+    /// Return the packages that appear in the derivation tree.
+    pub(crate) fn packages(&self) -> impl Iterator<Item = &PubGrubPackage> {
+        self.derivation_tree.packages().into_iter()
+    }
+
     /// Update the available versions attached to the error using the given package version index.
     ///
     /// Only packages used in the error's derivation tree will be retrieved.
@@ -338,4 +377,18 @@ impl NoSolutionError {
         self.python_requirement = Some(python_requirement.clone());
         self
     }
+
+    /// Update the markers of the resolver forks in which the failure occurred.
+    #[must_use]
+    pub(crate) fn with_fork_markers(mut self, fork_markers: &[MarkerTree]) -> Self {
+        self.fork_markers = fork_markers.to_vec();
+        self
+    }
+
+    /// Update the relaxations of the direct requirements that would avoid the failure.
+    #[must_use]
+    pub(crate) fn with_relaxations(mut self, relaxations: Vec<Relaxation>) -> Self {
+        self.relaxations = relaxations;
+        self
+    }
 }
@@ -1,28 +1,39 @@
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
-use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
+use chrono::{DateTime, Days, Duration, NaiveDate, NaiveTime, SecondsFormat, Utc};
+
+use uv_configuration::Upgrade;
+use uv_normalize::PackageName;
 
 /// A timestamp that excludes files newer than it.
-#[derive(Debug, Copy, Clone, serde::Deserialize, serde::Serialize)]
-pub struct ExcludeNewer(DateTime<Utc>);
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize,
+)]
+pub struct ExcludeNewerTimestamp(DateTime<Utc>);
 
-impl ExcludeNewer {
+impl ExcludeNewerTimestamp {
     /// Returns the timestamp in milliseconds.
     pub fn timestamp_millis(&self) -> i64 {
         self.0.timestamp_millis()
     }
+
+    /// Returns the timestamp as an RFC 3339 string (e.g., `2006-12-02T02:07:43Z`).
+    pub fn to_rfc3339(&self) -> String {
+        self.0.to_rfc3339_opts(SecondsFormat::Secs, true)
+    }
 }
 
-impl From<DateTime<Utc>> for ExcludeNewer {
+impl From<DateTime<Utc>> for ExcludeNewerTimestamp {
     fn from(datetime: DateTime<Utc>) -> Self {
         Self(datetime)
     }
 }
 
-impl FromStr for ExcludeNewer {
+impl FromStr for ExcludeNewerTimestamp {
     type Err = String;
 
-    /// Parse an [`ExcludeNewer`] from a string.
+    /// Parse an [`ExcludeNewerTimestamp`] from a string.
     ///
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
     /// format (e.g., `2006-12-02`).
@@ -46,16 +57,16 @@ impl FromStr for ExcludeNewer {
     }
 }
 
-impl std::fmt::Display for ExcludeNewer {
+impl std::fmt::Display for ExcludeNewerTimestamp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
     }
 }
 
 #[cfg(feature = "schemars")]
-impl schemars::JsonSchema for ExcludeNewer {
+impl schemars::JsonSchema for ExcludeNewerTimestamp {
     fn schema_name() -> String {
-        "ExcludeNewer".to_string()
+        "ExcludeNewerTimestamp".to_string()
     }
 
     fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
@@ -76,3 +87,590 @@ impl schemars::JsonSchema for ExcludeNewer {
         .into()
     }
 }
+
+/// A span of time, relative to the current time, within which files are excluded (e.g., `7 days`).
+///
+/// Used to enforce a minimum release age (or "cooldown") on candidate distributions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExcludeNewerSpan(Duration);
+
+impl ExcludeNewerSpan {
+    /// Returns the cutoff for this span, relative to the given time.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> ExcludeNewerTimestamp {
+        ExcludeNewerTimestamp(now - self.0)
+    }
+}
+
+impl FromStr for ExcludeNewerSpan {
+    type Err = String;
+
+    /// Parse an [`ExcludeNewerSpan`] from a string.
+    ///
+    /// Accepts an integer followed by a unit of weeks, days, hours, or minutes (e.g., `7 days`,
+    /// `7d`, `2 weeks`, or `12h`).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let split = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len());
+        let (amount, unit) = trimmed.split_at(split);
+        let amount = amount.parse::<i64>().map_err(|_| {
+            format!("`{input}` is not a valid span (expected, e.g., `7 days` or `12h`)")
+        })?;
+        let duration = match unit.trim() {
+            "w" | "week" | "weeks" => Duration::try_weeks(amount),
+            "d" | "day" | "days" => Duration::try_days(amount),
+            "h" | "hour" | "hours" => Duration::try_hours(amount),
+            "m" | "minute" | "minutes" => Duration::try_minutes(amount),
+            unit => {
+                return Err(format!(
+                    "`{input}` has an unknown unit `{unit}` (expected one of: `weeks`, `days`, `hours`, `minutes`)"
+                ))
+            }
+        };
+        let duration = duration.ok_or_else(|| format!("`{input}` is out of range"))?;
+        Ok(Self(duration))
+    }
+}
+
+impl std::fmt::Display for ExcludeNewerSpan {
+    /// Display the span in the largest unit that represents it exactly.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let minutes = self.0.num_minutes();
+        let (amount, unit) = if minutes % (60 * 24 * 7) == 0 && minutes != 0 {
+            (minutes / (60 * 24 * 7), "week")
+        } else if minutes % (60 * 24) == 0 && minutes != 0 {
+            (minutes / (60 * 24), "day")
+        } else if minutes % 60 == 0 && minutes != 0 {
+            (minutes / 60, "hour")
+        } else {
+            (minutes, "minute")
+        };
+        if amount == 1 {
+            write!(f, "{amount} {unit}")
+        } else {
+            write!(f, "{amount} {unit}s")
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ExcludeNewerSpan {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for ExcludeNewerSpan {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ExcludeNewerSpan {
+    fn schema_name() -> String {
+        "ExcludeNewerSpan".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            string: Some(Box::new(schemars::schema::StringValidation {
+                pattern: Some(r"^\s*\d+\s*(w|weeks?|d|days?|h|hours?|m|minutes?)\s*$".to_string()),
+                ..schemars::schema::StringValidation::default()
+            })),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("Exclude distributions uploaded within the given span of the current time.\n\nAccepts an integer followed by a unit of weeks, days, hours, or minutes (e.g., `7 days` or `12h`).".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+/// A per-package override of the `exclude-newer` policy.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExcludeNewerOverride {
+    /// Exclude files for the package that were uploaded after the given timestamp.
+    Timestamp(ExcludeNewerTimestamp),
+    /// Exempt the package from the `exclude-newer` policy entirely.
+    Disabled,
+}
+
+impl ExcludeNewerOverride {
+    /// Returns the cutoff for the package, if any.
+    pub fn timestamp(&self) -> Option<ExcludeNewerTimestamp> {
+        match self {
+            Self::Timestamp(timestamp) => Some(*timestamp),
+            Self::Disabled => None,
+        }
+    }
+}
+
+impl FromStr for ExcludeNewerOverride {
+    type Err = String;
+
+    /// Parse an [`ExcludeNewerOverride`] from a string: either a timestamp, or `false` to exempt
+    /// the package.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input == "false" {
+            Ok(Self::Disabled)
+        } else {
+            ExcludeNewerTimestamp::from_str(input).map(Self::Timestamp)
+        }
+    }
+}
+
+impl std::fmt::Display for ExcludeNewerOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Timestamp(timestamp) => write!(f, "{}", timestamp.to_rfc3339()),
+            Self::Disabled => write!(f, "false"),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ExcludeNewerOverride {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Bool(bool),
+            String(String),
+        }
+
+        match Value::deserialize(deserializer)? {
+            Value::Bool(false) => Ok(Self::Disabled),
+            Value::Bool(true) => Err(serde::de::Error::custom(
+                "expected a timestamp or `false`, but found `true`",
+            )),
+            Value::String(s) => ExcludeNewerTimestamp::from_str(&s)
+                .map(Self::Timestamp)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+impl serde::Serialize for ExcludeNewerOverride {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Timestamp(timestamp) => serializer.serialize_str(&timestamp.to_rfc3339()),
+            Self::Disabled => serializer.serialize_bool(false),
+        }
+    }
+}
+
+/// A `PACKAGE=VALUE` entry for `--exclude-newer-package`.
+#[derive(Debug, Clone)]
+pub struct ExcludeNewerPackageEntry {
+    /// The package to which the override applies.
+    package: PackageName,
+    /// The override for the package.
+    value: ExcludeNewerOverride,
+}
+
+impl FromStr for ExcludeNewerPackageEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, value)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid `exclude-newer-package` entry: {s} (expected `PACKAGE=DATE` or `PACKAGE=false`)"
+            ));
+        };
+        let package = PackageName::from_str(package.trim()).map_err(|err| err.to_string())?;
+        let value = ExcludeNewerOverride::from_str(value.trim())?;
+        Ok(Self { package, value })
+    }
+}
+
+/// A set of per-package overrides of the `exclude-newer` policy.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(transparent)]
+pub struct ExcludeNewerPackage(BTreeMap<PackageName, ExcludeNewerOverride>);
+
+impl ExcludeNewerPackage {
+    /// Returns the override for the given package, if any.
+    pub fn get(&self, package_name: &PackageName) -> Option<&ExcludeNewerOverride> {
+        self.0.get(package_name)
+    }
+
+    /// Returns `true` if there are no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the overrides.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &ExcludeNewerOverride)> {
+        self.0.iter()
+    }
+
+    /// Merge two sets of overrides, preferring the values in `self`.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for (package, value) in other.0 {
+            self.0.entry(package).or_insert(value);
+        }
+        self
+    }
+}
+
+impl FromIterator<ExcludeNewerPackageEntry> for ExcludeNewerPackage {
+    fn from_iter<T: IntoIterator<Item = ExcludeNewerPackageEntry>>(iter: T) -> Self {
+        let mut map = BTreeMap::default();
+        for entry in iter {
+            // Prefer the first entry for a given package, matching the precedence of the CLI.
+            map.entry(entry.package).or_insert(entry.value);
+        }
+        Self(map)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ExcludeNewerPackage {
+    fn schema_name() -> String {
+        "ExcludeNewerPackage".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let value = schemars::schema::SchemaObject {
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                any_of: Some(vec![
+                    gen.subschema_for::<ExcludeNewerTimestamp>(),
+                    schemars::schema::SchemaObject {
+                        instance_type: Some(schemars::schema::InstanceType::Boolean.into()),
+                        ..schemars::schema::SchemaObject::default()
+                    }
+                    .into(),
+                ]),
+                ..schemars::schema::SubschemaValidation::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        };
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                additional_properties: Some(Box::new(value.into())),
+                ..schemars::schema::ObjectValidation::default()
+            })),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("Per-package overrides of the `exclude-newer` policy, as a map from package name to a timestamp, or `false` to exempt the package entirely.".to_string()),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+/// A minimum release age, along with the cutoff it resolved to at the start of the resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExcludeNewerCooldown {
+    /// The span within which files are excluded.
+    span: ExcludeNewerSpan,
+    /// The cutoff that the span resolved to.
+    cutoff: ExcludeNewerTimestamp,
+    /// The cutoffs for packages whose cooldown was resolved more recently than `cutoff` (e.g.,
+    /// because they were upgraded with `--upgrade-package`).
+    package: BTreeMap<PackageName, ExcludeNewerTimestamp>,
+}
+
+impl ExcludeNewerCooldown {
+    /// Create an [`ExcludeNewerCooldown`] from a span and a previously-resolved cutoff.
+    pub fn new(span: ExcludeNewerSpan, cutoff: ExcludeNewerTimestamp) -> Self {
+        Self {
+            span,
+            cutoff,
+            package: BTreeMap::default(),
+        }
+    }
+
+    /// Set the per-package cutoffs, which take precedence over the global cutoff.
+    #[must_use]
+    pub fn with_package(mut self, package: BTreeMap<PackageName, ExcludeNewerTimestamp>) -> Self {
+        self.package = package;
+        self
+    }
+
+    /// Returns the span within which files are excluded.
+    pub fn span(&self) -> ExcludeNewerSpan {
+        self.span
+    }
+
+    /// Returns the cutoff that the span resolved to.
+    pub fn cutoff(&self) -> ExcludeNewerTimestamp {
+        self.cutoff
+    }
+
+    /// Returns the cutoff that applies to the given package.
+    pub fn cutoff_for(&self, package_name: &PackageName) -> ExcludeNewerTimestamp {
+        self.package
+            .get(package_name)
+            .copied()
+            .unwrap_or(self.cutoff)
+    }
+
+    /// Returns the per-package cutoffs.
+    pub fn package(&self) -> &BTreeMap<PackageName, ExcludeNewerTimestamp> {
+        &self.package
+    }
+}
+
+/// The policy for excluding files based on their upload time.
+///
+/// Combines an absolute cutoff, a relative cooldown (e.g., "ignore any release younger than 7
+/// days"), and per-package overrides that either pin a different cutoff or exempt the package
+/// entirely.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExcludeNewer {
+    /// The absolute cutoff that applies to all packages.
+    timestamp: Option<ExcludeNewerTimestamp>,
+    /// The relative cutoff that applies to all packages.
+    cooldown: Option<ExcludeNewerCooldown>,
+    /// The per-package overrides, which take precedence over the global cutoffs.
+    package: ExcludeNewerPackage,
+}
+
+impl ExcludeNewer {
+    /// Create an [`ExcludeNewer`] policy from its components.
+    pub fn new(
+        timestamp: Option<ExcludeNewerTimestamp>,
+        cooldown: Option<ExcludeNewerCooldown>,
+        package: ExcludeNewerPackage,
+    ) -> Self {
+        Self {
+            timestamp,
+            cooldown,
+            package,
+        }
+    }
+
+    /// Determine the [`ExcludeNewer`] policy from the user-provided settings, resolving any
+    /// cooldown relative to the current time.
+    ///
+    /// Returns `None` if no policy was provided.
+    pub fn from_args(
+        timestamp: Option<ExcludeNewerTimestamp>,
+        cooldown: Option<ExcludeNewerSpan>,
+        package: Option<ExcludeNewerPackage>,
+    ) -> Option<Self> {
+        let package = package.unwrap_or_default();
+        if timestamp.is_none() && cooldown.is_none() && package.is_empty() {
+            return None;
+        }
+        let now = Utc::now();
+        let cooldown = cooldown.map(|span| ExcludeNewerCooldown::new(span, span.cutoff(now)));
+        Some(Self {
+            timestamp,
+            cooldown,
+            package,
+        })
+    }
+
+    /// Reuse the cooldown cutoff from a previous resolution (e.g., as recorded in a lockfile), if
+    /// it was resolved for the same span.
+    ///
+    /// This ensures that re-resolving is reproducible, rather than admitting any files that have
+    /// aged past the cooldown in the interim. Packages that are being upgraded retain the cutoff
+    /// resolved relative to the current time.
+    #[must_use]
+    pub fn with_previous(mut self, previous: Option<&ExcludeNewer>, upgrade: &Upgrade) -> Self {
+        if upgrade.is_all() {
+            return self;
+        }
+        if let (Some(cooldown), Some(previous)) = (
+            self.cooldown.as_mut(),
+            previous.and_then(|previous| previous.cooldown.as_ref()),
+        ) {
+            if cooldown.span == previous.span {
+                let current = cooldown.cutoff;
+                cooldown.cutoff = previous.cutoff;
+                cooldown.package.clone_from(&previous.package);
+                if let Upgrade::Packages(packages) = upgrade {
+                    for package in packages {
+                        cooldown.package.insert(package.clone(), current);
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns the cutoff that applies to the given package, if any.
+    ///
+    /// Per-package overrides take precedence; otherwise, the stricter (i.e., earlier) of the
+    /// absolute cutoff and the cooldown cutoff applies.
+    pub fn for_package(&self, package_name: &PackageName) -> Option<ExcludeNewerTimestamp> {
+        if let Some(value) = self.package.get(package_name) {
+            return value.timestamp();
+        }
+        match (
+            self.timestamp,
+            self.cooldown
+                .as_ref()
+                .map(|cooldown| cooldown.cutoff_for(package_name)),
+        ) {
+            (Some(timestamp), Some(cutoff)) => Some(timestamp.min(cutoff)),
+            (timestamp, cutoff) => timestamp.or(cutoff),
+        }
+    }
+
+    /// Returns the absolute cutoff that applies to all packages.
+    pub fn timestamp(&self) -> Option<ExcludeNewerTimestamp> {
+        self.timestamp
+    }
+
+    /// Returns the cooldown that applies to all packages.
+    pub fn cooldown(&self) -> Option<&ExcludeNewerCooldown> {
+        self.cooldown.as_ref()
+    }
+
+    /// Returns the per-package overrides.
+    pub fn package(&self) -> &ExcludeNewerPackage {
+        &self.package
+    }
+}
+
+impl From<ExcludeNewerTimestamp> for ExcludeNewer {
+    fn from(timestamp: ExcludeNewerTimestamp) -> Self {
+        Self {
+            timestamp: Some(timestamp),
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::{DateTime, Utc};
+
+    use uv_configuration::Upgrade;
+    use uv_normalize::PackageName;
+
+    use super::{
+        ExcludeNewer, ExcludeNewerCooldown, ExcludeNewerPackage, ExcludeNewerPackageEntry,
+        ExcludeNewerSpan, ExcludeNewerTimestamp,
+    };
+
+    #[test]
+    fn span() {
+        for (input, expected) in [
+            ("7 days", "1 week"),
+            ("7d", "1 week"),
+            ("3d", "3 days"),
+            ("1 day", "1 day"),
+            ("36h", "36 hours"),
+            ("90 minutes", "90 minutes"),
+            ("2 weeks", "2 weeks"),
+        ] {
+            let span = ExcludeNewerSpan::from_str(input).unwrap();
+            assert_eq!(span.to_string(), expected, "{input}");
+        }
+
+        assert!(ExcludeNewerSpan::from_str("7").is_err());
+        assert!(ExcludeNewerSpan::from_str("days").is_err());
+        assert!(ExcludeNewerSpan::from_str("7 fortnights").is_err());
+    }
+
+    #[test]
+    fn for_package() {
+        let now = DateTime::parse_from_rfc3339("2024-06-15T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let span = ExcludeNewerSpan::from_str("7 days").unwrap();
+        let package = ["internal=false", "pinned=2024-01-01"]
+            .into_iter()
+            .map(|entry| ExcludeNewerPackageEntry::from_str(entry).unwrap())
+            .collect::<ExcludeNewerPackage>();
+        let exclude_newer = ExcludeNewer::new(
+            Some(ExcludeNewerTimestamp::from_str("2024-06-10T00:00:00Z").unwrap()),
+            Some(ExcludeNewerCooldown::new(span, span.cutoff(now))),
+            package,
+        );
+
+        // The stricter of the absolute cutoff and the cooldown applies by default.
+        assert_eq!(
+            exclude_newer
+                .for_package(&PackageName::from_str("anyio").unwrap())
+                .unwrap()
+                .to_rfc3339(),
+            "2024-06-08T00:00:00Z"
+        );
+
+        // Overrides take precedence.
+        assert_eq!(
+            exclude_newer
+                .for_package(&PackageName::from_str("pinned").unwrap())
+                .unwrap()
+                .to_rfc3339(),
+            "2024-01-02T00:00:00Z"
+        );
+        assert!(exclude_newer
+            .for_package(&PackageName::from_str("internal").unwrap())
+            .is_none());
+    }
+
+    #[test]
+    fn with_previous() {
+        let span = ExcludeNewerSpan::from_str("7 days").unwrap();
+        let before = DateTime::parse_from_rfc3339("2024-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let now = DateTime::parse_from_rfc3339("2024-06-15T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let previous = ExcludeNewer::new(
+            None,
+            Some(ExcludeNewerCooldown::new(span, span.cutoff(before))),
+            ExcludeNewerPackage::default(),
+        );
+        let current = ExcludeNewer::new(
+            None,
+            Some(ExcludeNewerCooldown::new(span, span.cutoff(now))),
+            ExcludeNewerPackage::default(),
+        );
+        let anyio = PackageName::from_str("anyio").unwrap();
+        let idna = PackageName::from_str("idna").unwrap();
+
+        // The previous cutoff is reused.
+        let exclude_newer = current
+            .clone()
+            .with_previous(Some(&previous), &Upgrade::None);
+        assert_eq!(
+            exclude_newer.for_package(&anyio).unwrap().to_rfc3339(),
+            "2024-05-25T00:00:00Z"
+        );
+
+        // Upgraded packages use the current cutoff, and retain it on subsequent resolutions.
+        let exclude_newer = current.clone().with_previous(
+            Some(&previous),
+            &Upgrade::from_args(None, vec![anyio.clone()]),
+        );
+        assert_eq!(
+            exclude_newer.for_package(&anyio).unwrap().to_rfc3339(),
+            "2024-06-08T00:00:00Z"
+        );
+        assert_eq!(
+            exclude_newer.for_package(&idna).unwrap().to_rfc3339(),
+            "2024-05-25T00:00:00Z"
+        );
+        let exclude_newer = current
+            .clone()
+            .with_previous(Some(&exclude_newer), &Upgrade::None);
+        assert_eq!(
+            exclude_newer.for_package(&anyio).unwrap().to_rfc3339(),
+            "2024-06-08T00:00:00Z"
+        );
+
+        // Upgrading all packages uses the current cutoff.
+        let exclude_newer = current.with_previous(Some(&previous), &Upgrade::All);
+        assert_eq!(
+            exclude_newer.for_package(&idna).unwrap().to_rfc3339(),
+            "2024-06-08T00:00:00Z"
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use pubgrub::range::Range;
+use pubgrub::report::{DerivationTree, External};
+use pubgrub::term::Term;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use distribution_types::{IncompatibleDist, IncompatibleSource, IncompatibleWheel};
+use pep440_rs::Version;
+use pep508_rs::MarkerTree;
+
+use crate::pubgrub::{PubGrubHint, PubGrubPackage};
+use crate::resolver::{UnavailablePackage, UnavailableReason, UnavailableVersion};
+
+/// A machine-readable report of a resolution failure, as emitted by `--error-format json`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct NoSolutionReport {
+    /// The human-readable report, as displayed in the default error format.
+    pub message: String,
+    /// The incompatibilities that make up the derivation tree.
+    ///
+    /// Derived incompatibilities refer to their causes by index into this list. The last
+    /// incompatibility is the root of the tree.
+    pub incompatibilities: Vec<ReportIncompatibility>,
+    /// Hints that may help to resolve the failure.
+    pub hints: Vec<ReportHint>,
+    /// The marker expressions of the resolver forks in which the failure occurred, from the
+    /// outermost fork to the innermost. Empty if the resolver did not fork.
+    pub forks: Vec<String>,
+}
+
+impl NoSolutionReport {
+    pub(crate) fn new(
+        message: &str,
+        derivation_tree: &DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>,
+        hints: impl IntoIterator<Item = PubGrubHint>,
+        fork_markers: &[MarkerTree],
+    ) -> Self {
+        let mut incompatibilities = Vec::new();
+        collect_incompatibilities(
+            derivation_tree,
+            &mut incompatibilities,
+            &mut FxHashMap::default(),
+        );
+        Self {
+            message: strip_ansi(message),
+            incompatibilities,
+            hints: hints
+                .into_iter()
+                .map(|hint| ReportHint {
+                    kind: hint.kind(),
+                    package: hint.package().map(ToString::to_string),
+                    message: strip_ansi(&hint.to_string()),
+                })
+                .collect(),
+            forks: fork_markers.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// A single incompatibility in the derivation tree of a [`NoSolutionReport`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum ReportIncompatibility {
+    /// The root of the resolution, i.e., the user's requirements.
+    Root { package: String, version: String },
+    /// No versions of the package within the range exist.
+    NoVersions { package: String, range: String },
+    /// The versions of the package within the range exist, but can't be used.
+    Unavailable {
+        package: String,
+        range: String,
+        reason: ReportReason,
+        message: String,
+    },
+    /// The versions of the package within the range depend on the dependency within its range.
+    Dependency {
+        package: String,
+        range: String,
+        dependency: String,
+        #[serde(rename = "dependency-range")]
+        dependency_range: String,
+    },
+    /// An incompatibility derived from two prior incompatibilities, identified by their index.
+    Derived {
+        terms: Vec<ReportTerm>,
+        causes: [usize; 2],
+    },
+}
+
+/// A term in a derived incompatibility, i.e., a package constrained to (or away from) a range.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ReportTerm {
+    pub package: String,
+    pub range: String,
+    /// Whether the term requires the range (`true`), or excludes it (`false`).
+    pub positive: bool,
+}
+
+/// The reason that a package (or a range of its versions) can't be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportReason {
+    /// The package could not be found in any of the configured package locations.
+    NotFound,
+    /// The package could not be found in the cache, and network access was disabled.
+    Offline,
+    /// The versions were yanked.
+    Yanked,
+    /// The versions require a Python version that isn't supported.
+    RequiresPython,
+    /// The versions have no wheels compatible with the target platform.
+    NoCompatibleWheel,
+    /// The versions were uploaded after the `--exclude-newer` cutoff.
+    ExcludeNewer,
+    /// The versions only provide source distributions, but building was disabled.
+    NoBuild,
+    /// The versions only provide wheels, but using wheels was disabled.
+    NoBinary,
+    /// The package metadata was missing, invalid, or inconsistent.
+    InvalidMetadata,
+    /// The package metadata could not be determined, e.g., because building the source
+    /// distribution failed.
+    BuildFailure,
+    /// The versions are unavailable for some other reason.
+    Unavailable,
+}
+
+impl From<&UnavailableReason> for ReportReason {
+    fn from(reason: &UnavailableReason) -> Self {
+        match reason {
+            UnavailableReason::Package(package) => match package {
+                UnavailablePackage::NoIndex | UnavailablePackage::NotFound => Self::NotFound,
+                UnavailablePackage::Offline => Self::Offline,
+                UnavailablePackage::MissingMetadata
+                | UnavailablePackage::InvalidMetadata(_)
+                | UnavailablePackage::InvalidStructure(_) => Self::InvalidMetadata,
+            },
+            UnavailableReason::Version(version) => match version {
+                UnavailableVersion::IncompatibleDist(dist) => match dist {
+                    IncompatibleDist::Wheel(IncompatibleWheel::Tag(_)) => Self::NoCompatibleWheel,
+                    IncompatibleDist::Wheel(IncompatibleWheel::Yanked(_))
+                    | IncompatibleDist::Source(IncompatibleSource::Yanked(_)) => Self::Yanked,
+                    IncompatibleDist::Wheel(IncompatibleWheel::RequiresPython(..))
+                    | IncompatibleDist::Source(IncompatibleSource::RequiresPython(..)) => {
+                        Self::RequiresPython
+                    }
+                    IncompatibleDist::Wheel(IncompatibleWheel::ExcludeNewer(_))
+                    | IncompatibleDist::Source(IncompatibleSource::ExcludeNewer(_)) => {
+                        Self::ExcludeNewer
+                    }
+                    IncompatibleDist::Wheel(IncompatibleWheel::NoBinary) => Self::NoBinary,
+                    IncompatibleDist::Source(IncompatibleSource::NoBuild) => Self::NoBuild,
+                    IncompatibleDist::Unavailable => Self::Unavailable,
+                },
+                UnavailableVersion::MissingMetadata
+                | UnavailableVersion::InvalidMetadata
+                | UnavailableVersion::InconsistentMetadata
+                | UnavailableVersion::InvalidStructure => Self::InvalidMetadata,
+                UnavailableVersion::Offline => Self::Offline,
+                UnavailableVersion::ResolverError(_) => Self::BuildFailure,
+            },
+        }
+    }
+}
+
+/// A hint attached to a [`NoSolutionReport`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ReportHint {
+    /// A stable identifier for the kind of hint (e.g., `prerelease-available`).
+    pub kind: &'static str,
+    /// The package to which the hint applies, if any.
+    pub package: Option<String>,
+    /// The human-readable hint.
+    pub message: String,
+}
+
+/// Flatten the [`DerivationTree`] into a list of incompatibilities in post-order, such that every
+/// cause precedes the incompatibility derived from it. Returns the index of the given node.
+///
+/// Shared subtrees (as identified by their `shared_id`) are only emitted once.
+fn collect_incompatibilities(
+    tree: &DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>,
+    incompatibilities: &mut Vec<ReportIncompatibility>,
+    shared: &mut FxHashMap<usize, usize>,
+) -> usize {
+    let incompatibility = match tree {
+        DerivationTree::External(external) => match external {
+            External::NotRoot(package, version) => ReportIncompatibility::Root {
+                package: package.to_string(),
+                version: version.to_string(),
+            },
+            External::NoVersions(package, range) => ReportIncompatibility::NoVersions {
+                package: package.to_string(),
+                range: range.to_string(),
+            },
+            External::Custom(package, range, reason) => ReportIncompatibility::Unavailable {
+                package: package.to_string(),
+                range: range.to_string(),
+                reason: ReportReason::from(reason),
+                message: reason.to_string(),
+            },
+            External::FromDependencyOf(package, range, dependency, dependency_range) => {
+                ReportIncompatibility::Dependency {
+                    package: package.to_string(),
+                    range: range.to_string(),
+                    dependency: dependency.to_string(),
+                    dependency_range: dependency_range.to_string(),
+                }
+            }
+        },
+        DerivationTree::Derived(derived) => {
+            if let Some(index) = derived.shared_id.and_then(|id| shared.get(&id)) {
+                return *index;
+            }
+            let cause1 = collect_incompatibilities(&derived.cause1, incompatibilities, shared);
+            let cause2 = collect_incompatibilities(&derived.cause2, incompatibilities, shared);
+            let mut terms = derived
+                .terms
+                .iter()
+                .map(|(package, term)| match term {
+                    Term::Positive(range) => ReportTerm {
+                        package: package.to_string(),
+                        range: range.to_string(),
+                        positive: true,
+                    },
+                    Term::Negative(range) => ReportTerm {
+                        package: package.to_string(),
+                        range: range.to_string(),
+                        positive: false,
+                    },
+                })
+                .collect::<Vec<_>>();
+            terms.sort_by(|a, b| a.package.cmp(&b.package));
+            let index = incompatibilities.len();
+            incompatibilities.push(ReportIncompatibility::Derived {
+                terms,
+                causes: [cause1, cause2],
+            });
+            if let Some(id) = derived.shared_id {
+                shared.insert(id, index);
+            }
+            return index;
+        }
+    };
+    incompatibilities.push(incompatibility);
+    incompatibilities.len() - 1
+}
+
+/// Remove any ANSI styling from the given text.
+fn strip_ansi(text: &str) -> String {
+    anstream::adapter::strip_str(text).to_string()
+}
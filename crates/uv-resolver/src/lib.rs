@@ -1,17 +1,24 @@
 pub use dependency_mode::DependencyMode;
-pub use error::ResolveError;
-pub use exclude_newer::ExcludeNewer;
+pub use error::{NoSolutionError, ResolveError};
+pub use exclude_newer::{
+    ExcludeNewer, ExcludeNewerCooldown, ExcludeNewerOverride, ExcludeNewerPackage,
+    ExcludeNewerPackageEntry, ExcludeNewerSpan, ExcludeNewerTimestamp,
+};
 pub use exclusions::Exclusions;
+pub use failure_report::{
+    NoSolutionReport, ReportHint, ReportIncompatibility, ReportReason, ReportTerm,
+};
 pub use flat_index::FlatIndex;
-pub use lock::{Lock, LockError};
+pub use lock::{Lock, LockError, LockOptions, LOCK_VERSION};
 pub use manifest::Manifest;
 pub use options::{Options, OptionsBuilder};
-pub use preferences::{Preference, PreferenceError};
+pub use package_overrides::PackageOverrides;
+pub use preferences::{Preference, PreferenceError, PreferenceSource};
 pub use prerelease_mode::PreReleaseMode;
 pub use pubgrub::{PubGrubSpecifier, PubGrubSpecifierError};
 pub use python_requirement::PythonRequirement;
 pub use requires_python::{RequiresPython, RequiresPythonError};
-pub use resolution::{AnnotationStyle, DisplayResolutionGraph, ResolutionGraph};
+pub use resolution::{AnnotationStyle, DisplayResolutionGraph, DisplayWhy, ResolutionGraph};
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
     BuildId, DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
@@ -29,11 +36,13 @@ mod dependency_provider;
 mod error;
 mod exclude_newer;
 mod exclusions;
+mod failure_report;
 mod flat_index;
 mod lock;
 mod manifest;
 mod marker;
 mod options;
+mod package_overrides;
 mod pins;
 mod preferences;
 mod prerelease_mode;
@@ -9,38 +9,61 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anyhow::Result;
+use chrono::{DateTime, SecondsFormat};
 use either::Either;
 use petgraph::visit::EdgeRef;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Deserializer};
 use toml_edit::{value, Array, ArrayOfTables, InlineTable, Item, Table, Value};
+use tracing::debug;
 use url::Url;
 
 use cache_key::RepositoryUrl;
 use distribution_filename::WheelFilename;
 use distribution_types::{
     BuiltDist, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist, Dist, FileLocation,
-    GitSourceDist, IndexUrl, PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel,
-    RegistrySourceDist, RemoteSource, Resolution, ResolvedDist, ToUrlError,
+    GitSourceDist, IndexLocations, IndexUrl, PathBuiltDist, PathSourceDist, RegistryBuiltDist,
+    RegistryBuiltWheel, RegistrySourceDist, RemoteSource, Resolution, ResolvedDist, ToUrlError,
 };
 use pep440_rs::Version;
 use pep508_rs::{MarkerEnvironment, MarkerTree, VerbatimUrl, VerbatimUrlError};
 use platform_tags::{TagCompatibility, TagPriority, Tags};
-use pypi_types::{HashDigest, ParsedArchiveUrl, ParsedGitUrl};
-use uv_configuration::ExtrasSpecification;
+use pypi_types::{
+    HashDigest, ParsedArchiveUrl, ParsedGitUrl, Requirement, RequirementSource, VerbatimParsedUrl,
+};
+use uv_configuration::{ConflictItem, Conflicts, DevSpecification, ExtrasSpecification};
+use uv_distribution::Metadata;
 use uv_git::{GitReference, GitSha, RepositoryReference, ResolvedRepositoryReference};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 
 use crate::resolution::AnnotatedDist;
-use crate::{RequiresPython, ResolutionGraph};
+use crate::{
+    ExcludeNewer, ExcludeNewerCooldown, ExcludeNewerPackage, ExcludeNewerSpan,
+    ExcludeNewerTimestamp, Options, PackageOverrides, PreReleaseMode, RequiresPython,
+    ResolutionGraph, ResolutionMode,
+};
+
+/// The current version of the lockfile format.
+///
+/// Lockfiles written by older versions of uv are upgraded to this version when the lockfile is
+/// next written. Lockfiles with a newer version are rejected, since they may include fields that
+/// this version of uv doesn't understand.
+pub const LOCK_VERSION: u32 = 2;
 
 #[derive(Clone, Debug, serde::Deserialize)]
 #[serde(try_from = "LockWire")]
 pub struct Lock {
+    /// The version of the lockfile format, as read from the lockfile.
     version: u32,
     distributions: Vec<Distribution>,
     /// The range of supported Python versions.
     requires_python: Option<RequiresPython>,
+    /// The environments the lock was restricted to, if any.
+    environments: Vec<MarkerTree>,
+    /// The resolver settings used to generate the lock, if recorded.
+    options: Option<LockOptions>,
+    /// The `exclude-newer` policy used to generate the lock.
+    exclude_newer: Option<ExcludeNewer>,
     /// A map from distribution ID to index in `distributions`.
     ///
     /// This can be used to quickly lookup the full distribution for any ID
@@ -57,7 +80,14 @@ pub struct Lock {
 
 impl Lock {
     /// Initialize a [`Lock`] from a [`ResolutionGraph`].
-    pub fn from_resolution_graph(graph: &ResolutionGraph) -> Result<Self, LockError> {
+    ///
+    /// The [`LockOptions`] are recorded in the lock, such that a change in resolver settings can be
+    /// detected when the lock is next read.
+    pub fn from_resolution_graph(
+        graph: &ResolutionGraph,
+        options: &Options,
+        lock_options: LockOptions,
+    ) -> Result<Self, LockError> {
         let mut locked_dists = BTreeMap::new();
 
         // Lock all base packages.
@@ -117,7 +147,13 @@ impl Lock {
 
         let distributions = locked_dists.into_values().collect();
         let requires_python = graph.requires_python.clone();
-        let lock = Self::new(distributions, requires_python)?;
+        let lock = Self::new(
+            distributions,
+            requires_python,
+            options.environments.clone(),
+            lock_options,
+            options.exclude_newer.clone(),
+        )?;
         Ok(lock)
     }
 
@@ -125,15 +161,56 @@ impl Lock {
     fn new(
         distributions: Vec<Distribution>,
         requires_python: Option<RequiresPython>,
+        environments: Vec<MarkerTree>,
+        options: LockOptions,
+        exclude_newer: Option<ExcludeNewer>,
     ) -> Result<Self, LockError> {
         let wire = LockWire {
-            version: 1,
+            version: LOCK_VERSION,
             distributions,
             requires_python,
+            environments,
+            options: Some(ResolverOptionsWire::new(options, exclude_newer.as_ref())),
         };
         Self::try_from(wire)
     }
 
+    /// Parse a [`Lock`] from the contents of a `uv.lock` file.
+    ///
+    /// The version of the lockfile format is validated before the rest of the lockfile, such that
+    /// lockfiles written by a newer version of uv produce a clear error, even if their contents
+    /// can't otherwise be parsed.
+    pub fn from_toml(contents: &str) -> Result<Self, LockError> {
+        let table: toml::Table =
+            toml::from_str(contents).map_err(LockErrorKind::InvalidLockfile)?;
+
+        // If the version is missing or malformed, defer to the error from the full deserialization.
+        if let Some(version) = table
+            .get("version")
+            .and_then(toml::Value::as_integer)
+            .and_then(|version| u32::try_from(version).ok())
+        {
+            if version > LOCK_VERSION {
+                return Err(LockErrorKind::UnsupportedVersion { version }.into());
+            }
+            if version < LOCK_VERSION {
+                debug!("Upgrading lockfile from version {version} to version {LOCK_VERSION}");
+            }
+        }
+
+        let lock = toml::Value::Table(table)
+            .try_into()
+            .map_err(LockErrorKind::InvalidLockfile)?;
+        Ok(lock)
+    }
+
+    /// Returns the version of the lockfile format, as read from the lockfile.
+    ///
+    /// The lockfile is always written using the current version ([`LOCK_VERSION`]).
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
     /// Returns the [`Distribution`] entries in this lock.
     pub fn distributions(&self) -> &[Distribution] {
         &self.distributions
@@ -144,39 +221,124 @@ impl Lock {
         self.requires_python.as_ref()
     }
 
-    /// Convert the [`Lock`] to a [`Resolution`] using the given marker environment, tags, and root.
+    /// Returns the environments the lock was restricted to. If empty, the lock supports all
+    /// environments.
+    pub fn environments(&self) -> &[MarkerTree] {
+        &self.environments
+    }
+
+    /// Returns the resolver settings used to generate the lockfile, or `None` if the lockfile
+    /// predates the recording of resolver settings (i.e., version 1 of the lockfile format).
+    pub fn options(&self) -> Option<&LockOptions> {
+        self.options.as_ref()
+    }
+
+    /// Returns the `exclude-newer` policy used to generate this lock.
+    pub fn exclude_newer(&self) -> Option<&ExcludeNewer> {
+        self.exclude_newer.as_ref()
+    }
+
+    /// Convert the [`Lock`] to a [`Resolution`] using the given marker environment, tags, and
+    /// roots.
+    ///
+    /// Each root is installed along with the requested extras and dependency groups, such that
+    /// (e.g.) several workspace members can be installed into a single environment.
     pub fn to_resolution(
         &self,
         workspace_root: &Path,
         marker_env: &MarkerEnvironment,
         tags: &Tags,
-        root_name: &PackageName,
+        root_names: &[PackageName],
         extras: &ExtrasSpecification,
-        dev: &[GroupName],
+        dev: &DevSpecification,
+        conflicts: &Conflicts,
     ) -> Result<Resolution, LockError> {
         let mut queue: VecDeque<(&Distribution, Option<&ExtraName>)> = VecDeque::new();
+        let mut enabled = Vec::new();
+
+        for root_name in root_names {
+            // Add the root distribution to the queue.
+            let root = self
+                .find_by_name(root_name)
+                .map_err(|_| LockErrorKind::DuplicateRoot {
+                    name: root_name.clone(),
+                })?
+                .ok_or_else(|| LockErrorKind::MissingRoot {
+                    name: root_name.clone(),
+                })?;
 
-        // Add the root distribution to the queue.
-        let root = self
-            .find_by_name(root_name)
-            .expect("found too many distributions matching root")
-            .expect("could not find root");
-
-        // Add the base package.
-        queue.push_back((root, None));
-
-        // Add any extras.
-        match extras {
-            ExtrasSpecification::None => {}
-            ExtrasSpecification::All => {
-                for extra in root.optional_dependencies.keys() {
-                    queue.push_back((root, Some(extra)));
+            // Track the enabled extras and groups, to reject any conflicting combination.
+            if !conflicts.is_empty() {
+                enabled.extend(
+                    dev.groups()
+                        .iter()
+                        .map(|group| ConflictItem::group(root_name.clone(), group.clone())),
+                );
+                if dev.prod() {
+                    match extras {
+                        ExtrasSpecification::None => {}
+                        ExtrasSpecification::All => {
+                            enabled.extend(root.optional_dependencies.keys().map(|extra| {
+                                ConflictItem::extra(root_name.clone(), extra.clone())
+                            }));
+                        }
+                        ExtrasSpecification::Some(extras) => enabled.extend(
+                            extras
+                                .iter()
+                                .map(|extra| ConflictItem::extra(root_name.clone(), extra.clone())),
+                        ),
+                    }
                 }
             }
-            ExtrasSpecification::Some(extras) => {
-                for extra in extras {
-                    queue.push_back((root, Some(extra)));
+
+            if dev.prod() {
+                // Add the base package.
+                queue.push_back((root, None));
+
+                // Add any extras.
+                match extras {
+                    ExtrasSpecification::None => {}
+                    ExtrasSpecification::All => {
+                        for extra in root.optional_dependencies.keys() {
+                            queue.push_back((root, Some(extra)));
+                        }
+                    }
+                    ExtrasSpecification::Some(extras) => {
+                        for extra in extras {
+                            queue.push_back((root, Some(extra)));
+                        }
+                    }
+                }
+            } else {
+                // Add the requested dependency groups, but not the base package itself.
+                for dep in dev
+                    .groups()
+                    .iter()
+                    .flat_map(|group| root.dev_dependencies.get(group).into_iter().flatten())
+                {
+                    if dep
+                        .marker
+                        .as_ref()
+                        .map_or(true, |marker| marker.evaluate(marker_env, &[]))
+                    {
+                        let dep_dist = self.find_by_id(&dep.distribution_id);
+                        let dep_extra = dep.extra.as_ref();
+                        queue.push_back((dep_dist, dep_extra));
+                    }
+                }
+            }
+        }
+
+        // Reject any combination of extras and groups that was declared as conflicting, including
+        // across roots.
+        for set in conflicts.iter() {
+            let mut items = enabled.iter().filter(|item| set.contains(item));
+            if let (Some(first), Some(second)) = (items.next(), items.next()) {
+                return Err(LockErrorKind::ConflictingItems {
+                    first: first.clone(),
+                    second: second.clone(),
                 }
+                .into());
             }
         }
 
@@ -187,7 +349,7 @@ impl Lock {
                     Either::Left(dist.optional_dependencies.get(extra).into_iter().flatten())
                 } else {
                     Either::Right(dist.dependencies.iter().chain(
-                        dev.iter().flat_map(|group| {
+                        dev.groups().iter().flat_map(|group| {
                             dist.dev_dependencies.get(group).into_iter().flatten()
                         }),
                     ))
@@ -212,6 +374,89 @@ impl Lock {
         Ok(Resolution::new(map, diagnostics))
     }
 
+    /// Returns the first distribution that would be installed in the given marker environment,
+    /// across all workspace members, extras, and dependency groups, but lacks a wheel compatible
+    /// with the given tags (i.e., would require building from source).
+    ///
+    /// Workspace members themselves are always built from source, and so are not considered.
+    pub fn find_missing_wheel(
+        &self,
+        marker_env: &MarkerEnvironment,
+        tags: &Tags,
+    ) -> Option<&Distribution> {
+        let mut seen = FxHashSet::default();
+        let mut queue: VecDeque<&Distribution> = self
+            .distributions
+            .iter()
+            .filter(|dist| matches!(dist.id.source, Source::Editable(_)))
+            .collect();
+
+        while let Some(dist) = queue.pop_front() {
+            if !seen.insert(&dist.id) {
+                continue;
+            }
+
+            if !matches!(dist.id.source, Source::Editable(_))
+                && dist.find_best_wheel(tags).is_none()
+            {
+                return Some(dist);
+            }
+
+            let deps = dist
+                .dependencies
+                .iter()
+                .chain(dist.optional_dependencies.values().flatten())
+                .chain(dist.dev_dependencies.values().flatten());
+            for dep in deps {
+                if dep
+                    .marker
+                    .as_ref()
+                    .map_or(true, |marker| marker.evaluate(marker_env, &[]))
+                {
+                    queue.push_back(self.find_by_id(&dep.distribution_id));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns `true` if the lock was generated from the given workspace members, i.e., if the
+    /// editable distributions in the lock correspond exactly to the members, and the requirements
+    /// recorded for each member match its current (lowered) requirements.
+    ///
+    /// If so, the lock remains valid without performing a resolution.
+    pub fn satisfies(&self, members: &[Metadata]) -> bool {
+        let editables = self
+            .distributions
+            .iter()
+            .filter(|dist| matches!(dist.id.source, Source::Editable(_)))
+            .collect::<Vec<_>>();
+        if editables.len() != members.len() {
+            return false;
+        }
+
+        members.iter().all(|member| {
+            editables.iter().any(|dist| {
+                dist.id.name == member.name
+                    && dist.id.version == member.version
+                    && dist.metadata == DistributionMetadata::from_metadata(member)
+            })
+        })
+    }
+
+    /// Returns `true` if every registry distribution in the lock was resolved from one of the
+    /// configured indexes (including `--find-links` URLs).
+    ///
+    /// If not, the indexes have changed since the lock was generated, and the lock must be
+    /// re-resolved.
+    pub fn satisfies_indexes(&self, index_locations: &IndexLocations) -> bool {
+        self.distributions.iter().all(|dist| match dist.id.source {
+            Source::Registry(ref url) => index_locations.urls().any(|index| index == url),
+            _ => true,
+        })
+    }
+
     /// Returns the distribution with the given name. If there are multiple
     /// matching distributions, then an error is returned. If there are no
     /// matching distributions, then `Ok(None)` is returned.
@@ -245,6 +490,27 @@ struct LockWire {
     distributions: Vec<Distribution>,
     #[serde(rename = "requires-python")]
     requires_python: Option<RequiresPython>,
+    #[serde(default)]
+    environments: Vec<MarkerTree>,
+    #[serde(default = "ResolverOptionsWire::absent")]
+    options: Option<ResolverOptionsWire>,
+}
+
+impl LockWire {
+    /// Migrate a lockfile written with an older version of the lockfile format to the current
+    /// version ([`LOCK_VERSION`]), such that the rest of the conversion only needs to handle the
+    /// current format.
+    ///
+    /// The version itself is left unchanged, so that outdated lockfiles can be detected and
+    /// rewritten.
+    fn migrate(mut self) -> Self {
+        // Version 1 predates the `[options]` table, so the resolver settings used to generate the
+        // lock are unknown, rather than the defaults.
+        if self.version < 2 {
+            self.options = None;
+        }
+        self
+    }
 }
 
 impl From<Lock> for LockWire {
@@ -253,22 +519,241 @@ impl From<Lock> for LockWire {
             version: lock.version,
             distributions: lock.distributions,
             requires_python: lock.requires_python,
+            environments: lock.environments,
+            options: lock
+                .options
+                .map(|options| ResolverOptionsWire::new(options, lock.exclude_newer.as_ref())),
         }
     }
 }
 
+/// The resolver settings used to generate a [`Lock`], such that `uv lock` can detect when the
+/// settings have changed since the lock was generated.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LockOptions {
+    pub resolution_mode: ResolutionMode,
+    pub resolution_package: PackageOverrides<ResolutionMode>,
+    pub prerelease_mode: PreReleaseMode,
+    pub prerelease_package: PackageOverrides<PreReleaseMode>,
+    /// The constraints applied to the resolution, e.g., from `tool.uv.constraint-dependencies`.
+    pub constraints: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
+    /// The overrides applied to the resolution, e.g., from `tool.uv.override-dependencies`.
+    pub overrides: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
+}
+
+/// The resolver options used to generate the lock, as recorded in the `[options]` table.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ResolverOptionsWire {
+    #[serde(default)]
+    resolution_mode: ResolutionMode,
+    #[serde(default)]
+    resolution_package: PackageOverrides<ResolutionMode>,
+    #[serde(default)]
+    prerelease_mode: PreReleaseMode,
+    #[serde(default)]
+    prerelease_package: PackageOverrides<PreReleaseMode>,
+    #[serde(default)]
+    constraints: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
+    #[serde(default)]
+    overrides: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
+    exclude_newer: Option<ExcludeNewerTimestamp>,
+    exclude_newer_cooldown: Option<ExcludeNewerSpan>,
+    exclude_newer_cooldown_cutoff: Option<ExcludeNewerTimestamp>,
+    #[serde(default)]
+    exclude_newer_cooldown_package: BTreeMap<PackageName, ExcludeNewerTimestamp>,
+    #[serde(default)]
+    exclude_newer_package: ExcludeNewerPackage,
+}
+
+impl ResolverOptionsWire {
+    fn new(options: LockOptions, exclude_newer: Option<&ExcludeNewer>) -> Self {
+        let LockOptions {
+            resolution_mode,
+            resolution_package,
+            prerelease_mode,
+            prerelease_package,
+            constraints,
+            overrides,
+        } = options;
+        let Some(exclude_newer) = exclude_newer else {
+            return Self {
+                resolution_mode,
+                resolution_package,
+                prerelease_mode,
+                prerelease_package,
+                constraints,
+                overrides,
+                ..Self::default()
+            };
+        };
+        Self {
+            resolution_mode,
+            resolution_package,
+            prerelease_mode,
+            prerelease_package,
+            constraints,
+            overrides,
+            exclude_newer: exclude_newer.timestamp(),
+            exclude_newer_cooldown: exclude_newer.cooldown().map(ExcludeNewerCooldown::span),
+            exclude_newer_cooldown_cutoff: exclude_newer
+                .cooldown()
+                .map(ExcludeNewerCooldown::cutoff),
+            exclude_newer_cooldown_package: exclude_newer
+                .cooldown()
+                .map(|cooldown| cooldown.package().clone())
+                .unwrap_or_default(),
+            exclude_newer_package: exclude_newer.package().clone(),
+        }
+    }
+}
+
+impl ResolverOptionsWire {
+    /// Returns the settings assumed for a lockfile without an `[options]` table, i.e., the defaults.
+    #[allow(clippy::unnecessary_wraps)]
+    fn absent() -> Option<Self> {
+        Some(Self::default())
+    }
+
+    /// Returns the resolver settings recorded in the lock.
+    fn lock_options(&self) -> LockOptions {
+        LockOptions {
+            resolution_mode: self.resolution_mode,
+            resolution_package: self.resolution_package.clone(),
+            prerelease_mode: self.prerelease_mode,
+            prerelease_package: self.prerelease_package.clone(),
+            constraints: self.constraints.clone(),
+            overrides: self.overrides.clone(),
+        }
+    }
+
+    /// Returns the `exclude-newer` policy recorded in the lock, if any.
+    fn exclude_newer(self) -> Result<Option<ExcludeNewer>, LockError> {
+        let cooldown = match (
+            self.exclude_newer_cooldown,
+            self.exclude_newer_cooldown_cutoff,
+        ) {
+            (Some(span), Some(cutoff)) => Some(
+                ExcludeNewerCooldown::new(span, cutoff)
+                    .with_package(self.exclude_newer_cooldown_package),
+            ),
+            (None, None) if self.exclude_newer_cooldown_package.is_empty() => None,
+            _ => return Err(LockErrorKind::IncompleteCooldown.into()),
+        };
+        if self.exclude_newer.is_none()
+            && cooldown.is_none()
+            && self.exclude_newer_package.is_empty()
+        {
+            return Ok(None);
+        }
+        Ok(Some(ExcludeNewer::new(
+            self.exclude_newer,
+            cooldown,
+            self.exclude_newer_package,
+        )))
+    }
+}
+
 impl Lock {
     /// Returns the TOML representation of this lock file.
     pub fn to_toml(&self) -> Result<String> {
         // We construct a TOML document manually instead of going through Serde to enable
         // the use of inline tables.
         let mut doc = toml_edit::DocumentMut::new();
-        doc.insert("version", value(i64::from(self.version)));
+        doc.insert("version", value(i64::from(LOCK_VERSION)));
 
         if let Some(ref requires_python) = self.requires_python {
             doc.insert("requires-python", value(requires_python.to_string()));
         }
 
+        if !self.environments.is_empty() {
+            let environments = self
+                .environments
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Array>();
+            doc.insert("environments", value(environments));
+        }
+
+        let mut options = Table::new();
+        let default_options = LockOptions::default();
+        let lock_options = self.options.as_ref().unwrap_or(&default_options);
+        if lock_options.resolution_mode != ResolutionMode::default() {
+            options.insert(
+                "resolution-mode",
+                value(lock_options.resolution_mode.to_string()),
+            );
+        }
+        if lock_options.prerelease_mode != PreReleaseMode::default() {
+            options.insert(
+                "prerelease-mode",
+                value(lock_options.prerelease_mode.to_string()),
+            );
+        }
+        if let Some(ref exclude_newer) = self.exclude_newer {
+            if let Some(timestamp) = exclude_newer.timestamp() {
+                options.insert("exclude-newer", value(timestamp.to_rfc3339()));
+            }
+            if let Some(cooldown) = exclude_newer.cooldown() {
+                options.insert("exclude-newer-cooldown", value(cooldown.span().to_string()));
+                options.insert(
+                    "exclude-newer-cooldown-cutoff",
+                    value(cooldown.cutoff().to_rfc3339()),
+                );
+                if !cooldown.package().is_empty() {
+                    let mut package = Table::new();
+                    for (name, cutoff) in cooldown.package() {
+                        package.insert(name.as_ref(), value(cutoff.to_rfc3339()));
+                    }
+                    options.insert("exclude-newer-cooldown-package", Item::Table(package));
+                }
+            }
+            if !exclude_newer.package().is_empty() {
+                let mut package = Table::new();
+                for (name, value) in exclude_newer.package().iter() {
+                    let value = match value.timestamp() {
+                        Some(timestamp) => Value::from(timestamp.to_rfc3339()),
+                        None => Value::from(false),
+                    };
+                    package.insert(name.as_ref(), Item::Value(value));
+                }
+                options.insert("exclude-newer-package", Item::Table(package));
+            }
+        }
+        if !lock_options.resolution_package.is_empty() {
+            let mut package = Table::new();
+            for (name, mode) in lock_options.resolution_package.iter() {
+                package.insert(name.as_ref(), value(mode.to_string()));
+            }
+            options.insert("resolution-package", Item::Table(package));
+        }
+        if !lock_options.prerelease_package.is_empty() {
+            let mut package = Table::new();
+            for (name, mode) in lock_options.prerelease_package.iter() {
+                package.insert(name.as_ref(), value(mode.to_string()));
+            }
+            options.insert("prerelease-package", Item::Table(package));
+        }
+        if !lock_options.constraints.is_empty() {
+            let constraints = lock_options
+                .constraints
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Array>();
+            options.insert("constraints", value(constraints));
+        }
+        if !lock_options.overrides.is_empty() {
+            let overrides = lock_options
+                .overrides
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Array>();
+            options.insert("overrides", value(overrides));
+        }
+        if !options.is_empty() {
+            doc.insert("options", Item::Table(options));
+        }
+
         let mut distributions = ArrayOfTables::new();
         for dist in &self.distributions {
             let mut table = Table::new();
@@ -314,6 +799,10 @@ impl Lock {
                 table.insert("dev-dependencies", Item::Table(dev_dependencies));
             }
 
+            if !dist.metadata.is_empty() {
+                table.insert("metadata", Item::Table(dist.metadata.to_toml()));
+            }
+
             if !dist.wheels.is_empty() {
                 let wheels = dist
                     .wheels
@@ -347,7 +836,9 @@ impl Lock {
 impl TryFrom<LockWire> for Lock {
     type Error = LockError;
 
-    fn try_from(mut wire: LockWire) -> Result<Lock, LockError> {
+    fn try_from(wire: LockWire) -> Result<Lock, LockError> {
+        let mut wire = wire.migrate();
+
         // Put all dependencies for each distribution in a canonical order and
         // check for duplicates.
         for dist in &mut wire.distributions {
@@ -395,6 +886,9 @@ impl TryFrom<LockWire> for Lock {
                 }
             }
         }
+        for dist in &mut wire.distributions {
+            dist.metadata.sort();
+        }
         wire.distributions
             .sort_by(|dist1, dist2| dist1.id.cmp(&dist2.id));
 
@@ -510,10 +1004,17 @@ impl TryFrom<LockWire> for Lock {
                 }
             }
         }
+        let (options, exclude_newer) = match wire.options {
+            Some(options) => (Some(options.lock_options()), options.exclude_newer()?),
+            None => (None, None),
+        };
         Ok(Lock {
             version: wire.version,
             distributions: wire.distributions,
             requires_python: wire.requires_python,
+            environments: wire.environments,
+            options,
+            exclude_newer,
             by_id,
         })
     }
@@ -534,6 +1035,9 @@ pub struct Distribution {
     optional_dependencies: BTreeMap<ExtraName, Vec<Dependency>>,
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     dev_dependencies: BTreeMap<GroupName, Vec<Dependency>>,
+    /// The requirements declared by the distribution, recorded for workspace members only.
+    #[serde(default)]
+    metadata: DistributionMetadata,
 }
 
 impl Distribution {
@@ -541,6 +1045,13 @@ impl Distribution {
         let id = DistributionId::from_annotated_dist(annotated_dist);
         let sdist = SourceDist::from_annotated_dist(annotated_dist)?;
         let wheels = Wheel::from_annotated_dist(annotated_dist)?;
+        // Record the requirements of editable distributions (i.e., workspace members), such that
+        // we can detect changes to their `pyproject.toml` without re-resolving.
+        let metadata = if matches!(id.source, Source::Editable(_)) {
+            DistributionMetadata::from_metadata(&annotated_dist.metadata)
+        } else {
+            DistributionMetadata::default()
+        };
         Ok(Distribution {
             id,
             sdist,
@@ -548,6 +1059,7 @@ impl Distribution {
             dependencies: vec![],
             optional_dependencies: BTreeMap::default(),
             dev_dependencies: BTreeMap::default(),
+            metadata,
         })
     }
 
@@ -747,11 +1259,11 @@ impl Distribution {
                         hashes: vec![],
                         requires_python: None,
                         size: sdist.size(),
-                        upload_time_utc_ms: None,
+                        upload_time_utc_ms: sdist.upload_time(),
                         url: FileLocation::AbsoluteUrl(file_url.to_string()),
                         yanked: None,
                     });
-                    let index = IndexUrl::Url(VerbatimUrl::from_url(url.clone()));
+                    let index = IndexUrl::from(VerbatimUrl::from_url(url.clone()));
                     let reg_dist = RegistrySourceDist {
                         name: self.id.name.clone(),
                         version: self.id.version.clone(),
@@ -796,6 +1308,23 @@ impl Distribution {
         &self.id.name
     }
 
+    /// Returns the [`Version`] of the distribution.
+    pub fn version(&self) -> &Version {
+        &self.id.version
+    }
+
+    /// Returns the earliest time at which any of the distribution's files was uploaded, in
+    /// milliseconds since the Unix epoch.
+    ///
+    /// Returns `None` if the distribution didn't come from a registry that reports upload times.
+    pub fn upload_time(&self) -> Option<i64> {
+        self.sdist
+            .iter()
+            .filter_map(SourceDist::upload_time)
+            .chain(self.wheels.iter().filter_map(|wheel| wheel.upload_time))
+            .min()
+    }
+
     /// Returns the [`ResolvedRepositoryReference`] for the distribution, if it is a Git source.
     pub fn as_git_ref(&self) -> Option<ResolvedRepositoryReference> {
         match &self.id.source {
@@ -1169,6 +1698,17 @@ struct SourceDistMetadata {
     ///
     /// This is only present for source distributions that come from registries.
     size: Option<u64>,
+    /// The time at which the source distribution was uploaded, in milliseconds since the Unix
+    /// epoch.
+    ///
+    /// This is only present for source distributions that come from registries that report
+    /// upload times.
+    #[serde(
+        default,
+        rename = "upload-time",
+        deserialize_with = "deserialize_upload_time"
+    )]
+    upload_time: Option<i64>,
 }
 
 /// A URL or file path where the source dist that was
@@ -1191,6 +1731,25 @@ enum SourceDist {
     },
 }
 
+/// Deserialize an RFC 3339 upload time into milliseconds since the Unix epoch.
+fn deserialize_upload_time<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(upload_time) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    DateTime::parse_from_rfc3339(&upload_time)
+        .map(|datetime| Some(datetime.timestamp_millis()))
+        .map_err(serde::de::Error::custom)
+}
+
+/// Serialize an upload time in milliseconds since the Unix epoch as an RFC 3339 timestamp.
+fn serialize_upload_time(upload_time: i64) -> Option<String> {
+    DateTime::from_timestamp_millis(upload_time)
+        .map(|datetime| datetime.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
 /// A [`PathBuf`], but we show `.` instead of an empty path.
 fn deserialize_path_with_dot<'de, D>(deserializer: D) -> Result<PathBuf, D::Error>
 where
@@ -1233,6 +1792,13 @@ impl SourceDist {
             SourceDist::Path { metadata, .. } => metadata.size,
         }
     }
+
+    fn upload_time(&self) -> Option<i64> {
+        match &self {
+            SourceDist::Url { metadata, .. } => metadata.upload_time,
+            SourceDist::Path { metadata, .. } => metadata.upload_time,
+        }
+    }
 }
 
 impl SourceDist {
@@ -1253,6 +1819,9 @@ impl SourceDist {
         if let Some(size) = self.size() {
             table.insert("size", Value::from(i64::try_from(size)?));
         }
+        if let Some(upload_time) = self.upload_time().and_then(serialize_upload_time) {
+            table.insert("upload-time", Value::from(upload_time));
+        }
         Ok(table)
     }
 
@@ -1316,9 +1885,14 @@ impl SourceDist {
             .map_err(LockError::from)?;
         let hash = reg_dist.file.hashes.first().cloned().map(Hash::from);
         let size = reg_dist.file.size;
+        let upload_time = reg_dist.file.upload_time_utc_ms;
         Ok(SourceDist::Url {
             url,
-            metadata: SourceDistMetadata { hash, size },
+            metadata: SourceDistMetadata {
+                hash,
+                size,
+                upload_time,
+            },
         })
     }
 
@@ -1328,6 +1902,7 @@ impl SourceDist {
             metadata: SourceDistMetadata {
                 hash: hashes.first().cloned().map(Hash::from),
                 size: None,
+                upload_time: None,
             },
         }
     }
@@ -1338,6 +1913,7 @@ impl SourceDist {
             metadata: SourceDistMetadata {
                 hash: hashes.first().cloned().map(Hash::from),
                 size: None,
+                upload_time: None,
             },
         }
     }
@@ -1348,6 +1924,7 @@ impl SourceDist {
             metadata: SourceDistMetadata {
                 hash: hashes.first().cloned().map(Hash::from),
                 size: None,
+                upload_time: None,
             },
         }
     }
@@ -1361,6 +1938,7 @@ impl SourceDist {
             metadata: SourceDistMetadata {
                 hash: hashes.first().cloned().map(Hash::from),
                 size: None,
+                upload_time: None,
             },
         }
     }
@@ -1459,6 +2037,10 @@ struct Wheel {
     ///
     /// This is only present for wheels that come from registries.
     size: Option<u64>,
+    /// The time at which the wheel was uploaded, in milliseconds since the Unix epoch.
+    ///
+    /// This is only present for wheels that come from registries that report upload times.
+    upload_time: Option<i64>,
     /// The filename of the wheel.
     ///
     /// This isn't part of the wire format since it's redundant with the
@@ -1521,10 +2103,12 @@ impl Wheel {
             .map_err(LockError::from)?;
         let hash = wheel.file.hashes.first().cloned().map(Hash::from);
         let size = wheel.file.size;
+        let upload_time = wheel.file.upload_time_utc_ms;
         Ok(Wheel {
             url,
             hash,
             size,
+            upload_time,
             filename,
         })
     }
@@ -1534,6 +2118,7 @@ impl Wheel {
             url: direct_dist.url.to_url(),
             hash: hashes.first().cloned().map(Hash::from),
             size: None,
+            upload_time: None,
             filename: direct_dist.filename.clone(),
         }
     }
@@ -1543,6 +2128,7 @@ impl Wheel {
             url: path_dist.url.to_url(),
             hash: hashes.first().cloned().map(Hash::from),
             size: None,
+            upload_time: None,
             filename: path_dist.filename.clone(),
         }
     }
@@ -1555,11 +2141,11 @@ impl Wheel {
             hashes: vec![],
             requires_python: None,
             size: self.size,
-            upload_time_utc_ms: None,
+            upload_time_utc_ms: self.upload_time,
             url: FileLocation::AbsoluteUrl(self.url.to_string()),
             yanked: None,
         });
-        let index = IndexUrl::Url(VerbatimUrl::from_url(url.clone()));
+        let index = IndexUrl::from(VerbatimUrl::from_url(url.clone()));
         RegistryBuiltWheel {
             filename,
             file,
@@ -1585,6 +2171,15 @@ struct WheelWire {
     ///
     /// This is only present for wheels that come from registries.
     size: Option<u64>,
+    /// The time at which the wheel was uploaded, in milliseconds since the Unix epoch.
+    ///
+    /// This is only present for wheels that come from registries that report upload times.
+    #[serde(
+        default,
+        rename = "upload-time",
+        deserialize_with = "deserialize_upload_time"
+    )]
+    upload_time: Option<i64>,
 }
 
 impl Wheel {
@@ -1598,6 +2193,9 @@ impl Wheel {
         if let Some(size) = self.size {
             table.insert("size", Value::from(i64::try_from(size)?));
         }
+        if let Some(upload_time) = self.upload_time.and_then(serialize_upload_time) {
+            table.insert("upload-time", Value::from(upload_time));
+        }
         Ok(table)
     }
 }
@@ -1618,11 +2216,196 @@ impl TryFrom<WheelWire> for Wheel {
             url: wire.url,
             hash: wire.hash,
             size: wire.size,
+            upload_time: wire.upload_time,
             filename,
         })
     }
 }
 
+/// The requirements declared by a workspace member, as recorded in the lock file.
+///
+/// Unlike the locked dependencies, these reflect the member's `pyproject.toml` (after applying
+/// `tool.uv.sources`), and so can be compared against the workspace to determine whether the lock
+/// is still up-to-date.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct DistributionMetadata {
+    #[serde(default)]
+    requires_dist: Vec<LockedRequirement>,
+    #[serde(default)]
+    requires_dev: BTreeMap<GroupName, Vec<LockedRequirement>>,
+}
+
+impl DistributionMetadata {
+    fn from_metadata(metadata: &Metadata) -> Self {
+        let mut distribution_metadata = Self {
+            requires_dist: metadata
+                .requires_dist
+                .iter()
+                .map(LockedRequirement::from_requirement)
+                .collect(),
+            requires_dev: metadata
+                .dev_dependencies
+                .iter()
+                .map(|(group, requirements)| {
+                    let requirements = requirements
+                        .iter()
+                        .map(LockedRequirement::from_requirement)
+                        .collect();
+                    (group.clone(), requirements)
+                })
+                .collect(),
+        };
+        distribution_metadata.sort();
+        distribution_metadata
+    }
+
+    /// Put the requirements in a canonical order.
+    fn sort(&mut self) {
+        self.requires_dist.sort();
+        for requirements in self.requires_dev.values_mut() {
+            requirements.sort();
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.requires_dist.is_empty() && self.requires_dev.is_empty()
+    }
+
+    /// Returns the TOML representation of this metadata.
+    fn to_toml(&self) -> Table {
+        let mut table = Table::new();
+        if !self.requires_dist.is_empty() {
+            table.insert(
+                "requires-dist",
+                value(LockedRequirement::to_toml_array(&self.requires_dist)),
+            );
+        }
+        if !self.requires_dev.is_empty() {
+            let mut requires_dev = Table::new();
+            for (group, requirements) in &self.requires_dev {
+                requires_dev.insert(
+                    group.as_ref(),
+                    value(LockedRequirement::to_toml_array(requirements)),
+                );
+            }
+            table.insert("requires-dev", Item::Table(requires_dev));
+        }
+        table
+    }
+}
+
+/// A single requirement of a workspace member, as recorded in the lock file.
+///
+/// Paths are stored relative to the workspace root, so that the recorded requirements are
+/// portable across machines.
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct LockedRequirement {
+    name: PackageName,
+    #[serde(default)]
+    extras: Vec<ExtraName>,
+    specifier: Option<String>,
+    index: Option<String>,
+    url: Option<String>,
+    path: Option<String>,
+    #[serde(default)]
+    editable: bool,
+    marker: Option<MarkerTree>,
+}
+
+impl LockedRequirement {
+    fn from_requirement(requirement: &Requirement) -> Self {
+        let mut locked = Self {
+            name: requirement.name.clone(),
+            extras: requirement.extras.clone(),
+            specifier: None,
+            index: None,
+            url: None,
+            path: None,
+            editable: false,
+            marker: requirement.marker.clone(),
+        };
+        locked.extras.sort();
+        match &requirement.source {
+            RequirementSource::Registry { specifier, index } => {
+                if !specifier.is_empty() {
+                    locked.specifier = Some(specifier.to_string());
+                }
+                locked.index.clone_from(index);
+            }
+            RequirementSource::Url { url, .. } | RequirementSource::Git { url, .. } => {
+                locked.url = Some(url.raw().to_string());
+            }
+            RequirementSource::Path { lock_path, .. } => {
+                locked.path = Some(serialize_path_with_dot(lock_path).into_owned());
+            }
+            RequirementSource::Directory {
+                lock_path,
+                editable,
+                ..
+            } => {
+                locked.path = Some(serialize_path_with_dot(lock_path).into_owned());
+                locked.editable = *editable;
+            }
+        }
+        locked
+    }
+
+    /// Returns the TOML representation of this requirement.
+    fn to_toml(&self) -> InlineTable {
+        let mut table = InlineTable::new();
+        table.insert("name", Value::from(self.name.to_string()));
+        if !self.extras.is_empty() {
+            let extras = self
+                .extras
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Array>();
+            table.insert("extras", Value::from(extras));
+        }
+        if let Some(ref specifier) = self.specifier {
+            table.insert("specifier", Value::from(specifier.as_str()));
+        }
+        if let Some(ref index) = self.index {
+            table.insert("index", Value::from(index.as_str()));
+        }
+        if let Some(ref url) = self.url {
+            table.insert("url", Value::from(url.as_str()));
+        }
+        if let Some(ref path) = self.path {
+            table.insert("path", Value::from(path.as_str()));
+        }
+        if self.editable {
+            table.insert("editable", Value::from(true));
+        }
+        if let Some(ref marker) = self.marker {
+            table.insert("marker", Value::from(marker.to_string()));
+        }
+        table
+    }
+
+    /// Returns the TOML representation of a list of requirements, with each requirement on its
+    /// own line if there are multiple.
+    fn to_toml_array(requirements: &[LockedRequirement]) -> Array {
+        requirements
+            .iter()
+            .enumerate()
+            .map(|(i, requirement)| {
+                let mut table = requirement.to_toml();
+                if requirements.len() > 1 {
+                    // Indent each requirement on a new line.
+                    table.decor_mut().set_prefix("\n\t");
+                    if i == requirements.len() - 1 {
+                        table.decor_mut().set_suffix("\n");
+                    }
+                }
+                table
+            })
+            .collect::<Array>()
+    }
+}
+
 /// A single dependency of a distribution in a lock file.
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, serde::Deserialize)]
 struct Dependency {
@@ -1739,6 +2522,13 @@ impl<'de> serde::Deserialize<'de> for Hash {
 #[error(transparent)]
 pub struct LockError(Box<LockErrorKind>);
 
+impl LockError {
+    /// Returns `true` if the lockfile was written by a newer version of uv, and so can't be read.
+    pub fn is_unsupported_version(&self) -> bool {
+        matches!(*self.0, LockErrorKind::UnsupportedVersion { .. })
+    }
+}
+
 impl<E> From<E> for LockError
 where
     LockErrorKind: From<E>,
@@ -1799,6 +2589,15 @@ enum LockErrorKind {
         /// The ID of the conflicting dependency.
         dependency: Dependency,
     },
+    /// An error that occurs when the caller enables extras or dependency
+    /// groups that were declared as conflicting.
+    #[error("{first} and {second} are declared as conflicting, and can't be enabled together")]
+    ConflictingItems {
+        /// The first of the conflicting items.
+        first: ConflictItem,
+        /// The second of the conflicting items.
+        second: ConflictItem,
+    },
     /// An error that occurs when the URL to a file for a wheel or
     /// source dist could not be converted to a structured `url::Url`.
     #[error("failed to parse wheel or source distribution URL")]
@@ -1894,6 +2693,40 @@ enum LockErrorKind {
         /// The ID of the distribution that has a missing base.
         id: DistributionId,
     },
+    /// An error that occurs when a root package (e.g., a workspace member) to be installed is not
+    /// included in the lock.
+    #[error("could not find root package `{name}` in the lockfile")]
+    MissingRoot {
+        /// The name of the missing root package.
+        name: PackageName,
+    },
+    /// An error that occurs when a root package to be installed matches multiple distributions in
+    /// the lock.
+    #[error("found multiple distributions matching root package `{name}`")]
+    DuplicateRoot {
+        /// The name of the ambiguous root package.
+        name: PackageName,
+    },
+    /// An error that occurs when the lock records an `exclude-newer-cooldown` without the
+    /// corresponding cutoff (or per-package cutoffs without a cooldown), or vice versa.
+    #[error(
+        "`exclude-newer-cooldown` and `exclude-newer-cooldown-cutoff` must be provided together"
+    )]
+    IncompleteCooldown,
+    /// An error that occurs when the lockfile uses a version of the lockfile format that is newer
+    /// than the version supported by this version of uv.
+    #[error("lockfile uses version {version} of the lockfile format, but this version of uv only supports version {supported}; the lockfile was likely written by a newer version of uv", supported = LOCK_VERSION)]
+    UnsupportedVersion {
+        /// The version of the lockfile format.
+        version: u32,
+    },
+    /// An error that occurs when the lockfile doesn't match the schema for its version.
+    #[error("failed to parse lockfile")]
+    InvalidLockfile(
+        /// The underlying error that occurred.
+        #[source]
+        toml::de::Error,
+    ),
     /// An error that occurs when converting between URLs and paths.
     #[error("found dependency `{id}` with no locked distribution")]
     VerbatimUrl {
@@ -1964,7 +2797,7 @@ mod tests {
     #[test]
     fn hash_required_present() {
         let data = r#"
-version = 1
+version = 2
 
 [[distribution]]
 name = "anyio"
@@ -1979,7 +2812,7 @@ wheels = [{ url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4
     #[test]
     fn hash_optional_missing() {
         let data = r#"
-version = 1
+version = 2
 
 [[distribution]]
 name = "anyio"
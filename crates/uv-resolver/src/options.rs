@@ -1,25 +1,34 @@
-use uv_configuration::IndexStrategy;
+use pep508_rs::MarkerTree;
+use uv_configuration::{Conflicts, IndexStrategy};
 
-use crate::{DependencyMode, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use crate::{DependencyMode, ExcludeNewer, PackageOverrides, PreReleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
+    pub resolution_package: PackageOverrides<ResolutionMode>,
     pub prerelease_mode: PreReleaseMode,
+    pub prerelease_package: PackageOverrides<PreReleaseMode>,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
     pub index_strategy: IndexStrategy,
+    pub conflicts: Conflicts,
+    pub environments: Vec<MarkerTree>,
 }
 
 /// Builder for [`Options`].
 #[derive(Debug, Default, Clone)]
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
+    resolution_package: PackageOverrides<ResolutionMode>,
     prerelease_mode: PreReleaseMode,
+    prerelease_package: PackageOverrides<PreReleaseMode>,
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
     index_strategy: IndexStrategy,
+    conflicts: Conflicts,
+    environments: Vec<MarkerTree>,
 }
 
 impl OptionsBuilder {
@@ -35,6 +44,16 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the per-package overrides of the [`ResolutionMode`].
+    #[must_use]
+    pub fn resolution_package(
+        mut self,
+        resolution_package: PackageOverrides<ResolutionMode>,
+    ) -> Self {
+        self.resolution_package = resolution_package;
+        self
+    }
+
     /// Sets the [`PreReleaseMode`].
     #[must_use]
     pub fn prerelease_mode(mut self, prerelease_mode: PreReleaseMode) -> Self {
@@ -42,6 +61,16 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the per-package overrides of the [`PreReleaseMode`].
+    #[must_use]
+    pub fn prerelease_package(
+        mut self,
+        prerelease_package: PackageOverrides<PreReleaseMode>,
+    ) -> Self {
+        self.prerelease_package = prerelease_package;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -63,14 +92,33 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the conflicting extras and dependency groups, on which the universal resolver forks.
+    #[must_use]
+    pub fn conflicts(mut self, conflicts: Conflicts) -> Self {
+        self.conflicts = conflicts;
+        self
+    }
+
+    /// Sets the environments to which a universal resolution is restricted. If empty, all
+    /// environments are supported.
+    #[must_use]
+    pub fn environments(mut self, environments: Vec<MarkerTree>) -> Self {
+        self.environments = environments;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
+            resolution_package: self.resolution_package,
             prerelease_mode: self.prerelease_mode,
+            prerelease_package: self.prerelease_package,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
+            conflicts: self.conflicts,
+            environments: self.environments,
         }
     }
 }
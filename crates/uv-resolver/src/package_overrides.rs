@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+use uv_normalize::PackageName;
+
+/// A set of per-package overrides of a resolver setting, like the
+/// [`ResolutionMode`](crate::ResolutionMode) or [`PreReleaseMode`](crate::PreReleaseMode).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(transparent)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageOverrides<T>(BTreeMap<PackageName, T>);
+
+impl<T> Default for PackageOverrides<T> {
+    fn default() -> Self {
+        Self(BTreeMap::default())
+    }
+}
+
+impl<T: Copy> PackageOverrides<T> {
+    /// Returns the override for the given package, if any.
+    pub fn get(&self, package_name: &PackageName) -> Option<T> {
+        self.0.get(package_name).copied()
+    }
+}
+
+impl<T> PackageOverrides<T> {
+    /// Returns `true` if there are no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the overrides.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &T)> {
+        self.0.iter()
+    }
+
+    /// Merge two sets of overrides, preferring the values in `self`.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for (package, value) in other.0 {
+            self.0.entry(package).or_insert(value);
+        }
+        self
+    }
+}
+
+impl<T> FromIterator<(PackageName, T)> for PackageOverrides<T> {
+    fn from_iter<I: IntoIterator<Item = (PackageName, T)>>(iter: I) -> Self {
+        let mut map = BTreeMap::default();
+        for (package, value) in iter {
+            // Prefer the first entry for a given package, matching the precedence of the CLI.
+            map.entry(package).or_insert(value);
+        }
+        Self(map)
+    }
+}
@@ -24,6 +24,31 @@ pub struct Preference {
     version: Version,
     marker: Option<MarkerTree>,
     hashes: Vec<HashDigest>,
+    source: PreferenceSource,
+}
+
+/// The origin of a [`Preference`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreferenceSource {
+    /// A pinned requirement in an existing `requirements.txt` file (e.g., the output file).
+    RequirementsTxt,
+    /// A distribution that's already installed in the target environment.
+    Environment,
+    /// A distribution in an existing lockfile.
+    Lock,
+    /// A pinned version in a lockfile imported from another tool (e.g., `poetry.lock`).
+    Import,
+}
+
+impl std::fmt::Display for PreferenceSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RequirementsTxt => write!(f, "the existing output file"),
+            Self::Environment => write!(f, "the installed environment"),
+            Self::Lock => write!(f, "the existing lockfile"),
+            Self::Import => write!(f, "the imported lockfile"),
+        }
+    }
 }
 
 impl Preference {
@@ -59,6 +84,7 @@ impl Preference {
                 .map(String::as_str)
                 .map(HashDigest::from_str)
                 .collect::<Result<_, _>>()?,
+            source: PreferenceSource::RequirementsTxt,
         }))
     }
 
@@ -73,6 +99,7 @@ impl Preference {
             version: version.clone(),
             marker: None,
             hashes: Vec::new(),
+            source: PreferenceSource::Environment,
         }
     }
 
@@ -83,6 +110,18 @@ impl Preference {
             version: dist.id.version.clone(),
             marker: None,
             hashes: Vec::new(),
+            source: PreferenceSource::Lock,
+        }
+    }
+
+    /// Create a [`Preference`] from a pinned version in a lockfile imported from another tool.
+    pub fn from_import(name: PackageName, version: Version, marker: Option<MarkerTree>) -> Self {
+        Self {
+            name,
+            version,
+            marker,
+            hashes: Vec::new(),
+            source: PreferenceSource::Import,
         }
     }
 
@@ -95,6 +134,11 @@ impl Preference {
     pub fn version(&self) -> &Version {
         &self.version
     }
+
+    /// Return the [`PreferenceSource`] from which this [`Preference`] originated.
+    pub fn source(&self) -> PreferenceSource {
+        self.source
+    }
 }
 
 /// A set of pinned packages that should be preserved during resolution, if possible.
@@ -5,6 +5,7 @@ use std::ops::Bound;
 
 use derivative::Derivative;
 use indexmap::IndexSet;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use pubgrub::range::Range;
 use pubgrub::report::{DerivationTree, Derived, External, ReportFormatter};
@@ -18,7 +19,9 @@ use uv_normalize::PackageName;
 
 use crate::candidate_selector::CandidateSelector;
 use crate::python_requirement::{PythonRequirement, PythonTarget};
-use crate::resolver::{IncompletePackage, UnavailablePackage, UnavailableReason};
+use crate::resolver::{
+    IncompletePackage, RelaxDirection, Relaxation, UnavailablePackage, UnavailableReason,
+};
 use crate::RequiresPython;
 
 use super::{PubGrubPackage, PubGrubPackageInner, PubGrubPython};
@@ -434,7 +437,7 @@ impl PubGrubReportFormatter<'_> {
 
                             if any_prerelease {
                                 // A pre-release marker appeared in the version requirements.
-                                if !selector.prerelease_strategy().allows(name) {
+                                if !selector.prerelease_strategy(name).allows(name) {
                                     hints.insert(PubGrubHint::PreReleaseRequested {
                                         package: package.clone(),
                                         range: self.simplify_set(set, package).into_owned(),
@@ -450,7 +453,7 @@ impl PubGrubReportFormatter<'_> {
                                 })
                             {
                                 // There are pre-release versions available for the package.
-                                if !selector.prerelease_strategy().allows(name) {
+                                if !selector.prerelease_strategy(name).allows(name) {
                                     hints.insert(PubGrubHint::PreReleaseAvailable {
                                         package: package.clone(),
                                         version: version.clone(),
@@ -667,6 +670,46 @@ pub(crate) enum PubGrubHint {
         #[derivative(PartialEq = "ignore", Hash = "ignore")]
         package_requires_python: Range<Version>,
     },
+    /// Relaxing one or more direct requirements would allow the resolution to succeed.
+    Relax { relaxation: Relaxation },
+}
+
+impl PubGrubHint {
+    /// Returns a stable identifier for the kind of hint, for use in machine-readable output.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::PreReleaseAvailable { .. } => "prerelease-available",
+            Self::PreReleaseRequested { .. } => "prerelease-requested",
+            Self::NoIndex => "no-index",
+            Self::Offline => "offline",
+            Self::MissingPackageMetadata { .. } => "missing-package-metadata",
+            Self::InvalidPackageMetadata { .. } => "invalid-package-metadata",
+            Self::InvalidPackageStructure { .. } => "invalid-package-structure",
+            Self::MissingVersionMetadata { .. } => "missing-version-metadata",
+            Self::InvalidVersionMetadata { .. } => "invalid-version-metadata",
+            Self::InconsistentVersionMetadata { .. } => "inconsistent-version-metadata",
+            Self::InvalidVersionStructure { .. } => "invalid-version-structure",
+            Self::RequiresPython { .. } => "requires-python",
+            Self::Relax { .. } => "relax-requirements",
+        }
+    }
+
+    /// Returns the package to which the hint applies, if any.
+    pub(crate) fn package(&self) -> Option<&PubGrubPackage> {
+        match self {
+            Self::PreReleaseAvailable { package, .. }
+            | Self::PreReleaseRequested { package, .. }
+            | Self::MissingPackageMetadata { package }
+            | Self::InvalidPackageMetadata { package, .. }
+            | Self::InvalidPackageStructure { package, .. }
+            | Self::MissingVersionMetadata { package, .. }
+            | Self::InvalidVersionMetadata { package, .. }
+            | Self::InconsistentVersionMetadata { package, .. }
+            | Self::InvalidVersionStructure { package, .. }
+            | Self::RequiresPython { package, .. } => Some(package),
+            Self::NoIndex | Self::Offline | Self::Relax { .. } => None,
+        }
+    }
 }
 
 impl std::fmt::Display for PubGrubHint {
@@ -809,6 +852,49 @@ impl std::fmt::Display for PubGrubHint {
                     package_requires_python.bold(),
                 )
             }
+            Self::Relax { relaxation } => {
+                let verb = match relaxation
+                    .iter()
+                    .exactly_one()
+                    .map(|requirement| requirement.direction)
+                {
+                    Ok(RelaxDirection::Lower) => "Lowering",
+                    Ok(RelaxDirection::Raise) => "Raising",
+                    Ok(RelaxDirection::Other) | Err(_) => "Relaxing",
+                };
+                let changes = relaxation
+                    .iter()
+                    .map(|requirement| {
+                        format!(
+                            "{} to {}",
+                            format!("{}{}", requirement.name, requirement.specifier).bold(),
+                            format!("{}=={}", requirement.name, requirement.version).bold(),
+                        )
+                    })
+                    .join(" and ");
+                let conflicts = relaxation
+                    .iter()
+                    .filter_map(|requirement| requirement.required_by.as_ref())
+                    .unique()
+                    .map(|(name, version)| format!("{name}=={version}").bold().to_string())
+                    .collect::<Vec<_>>();
+                if conflicts.is_empty() {
+                    write!(
+                        f,
+                        "{}{} {verb} {changes} would allow the requirements to be satisfied",
+                        "hint".bold().cyan(),
+                        ":".bold(),
+                    )
+                } else {
+                    write!(
+                        f,
+                        "{}{} {verb} {changes} would resolve the conflict with {}",
+                        "hint".bold().cyan(),
+                        ":".bold(),
+                        conflicts.join(" and "),
+                    )
+                }
+            }
         }
     }
 }
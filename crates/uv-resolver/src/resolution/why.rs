@@ -0,0 +1,382 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+
+use distribution_types::{DistributionMetadata, Name, SourceAnnotation, VersionOrUrlRef};
+use pep440_rs::Version;
+use pep508_rs::RequirementOrigin;
+use pypi_types::{Requirement, RequirementSource};
+use uv_normalize::PackageName;
+
+use crate::candidate_selector::CandidateDist;
+use crate::resolution::AnnotatedDist;
+use crate::{InMemoryIndex, Manifest, ResolutionGraph, VersionsResponse};
+
+/// The maximum number of dependency paths to display for a single package.
+const MAX_PATHS: usize = 100;
+
+/// A package in the resolution, identified by its name and version (i.e., ignoring extras and
+/// dependency groups).
+type PackageKey<'a> = (&'a PackageName, &'a Version);
+
+/// A [`std::fmt::Display`] implementation that explains why a package is included in a
+/// [`ResolutionGraph`], and why it was resolved to the selected version.
+///
+/// For every selected version of the package, displays each dependency path from the direct
+/// requirements to the package (along with the specifier imposed at each step), every
+/// requirement on the package, and the reason that the next newer version was not selected.
+pub struct DisplayWhy<'a> {
+    /// The underlying graph.
+    resolution: &'a ResolutionGraph,
+    /// The manifest that was provided to the resolver.
+    manifest: &'a Manifest,
+    /// The index that was used during resolution.
+    index: &'a InMemoryIndex,
+    /// The package to explain.
+    package: &'a PackageName,
+}
+
+/// A requirement on the explained package, along with its provenance.
+#[derive(Debug)]
+struct Constraint<'a> {
+    requirement: &'a Requirement,
+    source: ConstraintSource<'a>,
+}
+
+#[derive(Debug)]
+enum ConstraintSource<'a> {
+    /// A dependency of another package in the resolution.
+    Dependent(PackageKey<'a>),
+    /// A direct requirement.
+    Requirement(Option<&'a RequirementOrigin>),
+    /// A constraint (e.g., `-c constraints.txt`).
+    Constraint(Option<&'a RequirementOrigin>),
+    /// An override (e.g., `--override overrides.txt`).
+    Override(Option<&'a RequirementOrigin>),
+}
+
+impl Display for ConstraintSource<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dependent((name, version)) => write!(f, "{name}=={version}"),
+            Self::Requirement(Some(origin)) => {
+                write!(f, "{}", SourceAnnotation::Requirement((*origin).clone()))
+            }
+            Self::Requirement(None) => write!(f, "direct requirement"),
+            Self::Constraint(Some(origin)) => {
+                write!(f, "{}", SourceAnnotation::Constraint((*origin).clone()))
+            }
+            Self::Constraint(None) => write!(f, "constraint"),
+            Self::Override(Some(origin)) => {
+                write!(f, "{}", SourceAnnotation::Override((*origin).clone()))
+            }
+            Self::Override(None) => write!(f, "override"),
+        }
+    }
+}
+
+impl<'a> DisplayWhy<'a> {
+    /// Create a new [`DisplayWhy`] for the given package.
+    ///
+    /// The manifest and index should be the same values given to the resolver that produced the
+    /// graph.
+    pub fn new(
+        resolution: &'a ResolutionGraph,
+        manifest: &'a Manifest,
+        index: &'a InMemoryIndex,
+        package: &'a PackageName,
+    ) -> Self {
+        Self {
+            resolution,
+            manifest,
+            index,
+            package,
+        }
+    }
+
+    /// Return the nodes in the graph that correspond to the given package.
+    fn nodes(&self, key: PackageKey<'a>) -> impl Iterator<Item = &'a AnnotatedDist> + 'a {
+        let petgraph = &self.resolution.petgraph;
+        petgraph
+            .node_indices()
+            .map(move |index| &petgraph[index])
+            .filter(move |dist| package_key(dist) == key)
+    }
+
+    /// Return the distinct packages in the graph that depend on the given package.
+    fn dependents(&self, key: PackageKey<'a>) -> BTreeSet<PackageKey<'a>> {
+        let petgraph = &self.resolution.petgraph;
+        petgraph
+            .node_indices()
+            .filter(|index| package_key(&petgraph[*index]) == key)
+            .flat_map(|index| petgraph.edges_directed(index, Direction::Incoming))
+            .map(|edge| package_key(&petgraph[edge.source()]))
+            .filter(|dependent| *dependent != key)
+            .collect()
+    }
+
+    /// Return the requirements that the given package declares on the package with the given
+    /// name.
+    fn requirements(&self, dependent: PackageKey<'a>, name: &PackageName) -> Vec<&'a Requirement> {
+        self.nodes(dependent)
+            .flat_map(|dist| {
+                dist.metadata
+                    .requires_dist
+                    .iter()
+                    .chain(dist.metadata.dev_dependencies.values().flatten())
+            })
+            .filter(|requirement| requirement.name == *name)
+            .unique()
+            .collect()
+    }
+
+    /// Return the direct requirements on the package with the given name.
+    fn direct_requirements(&self, name: &PackageName) -> Vec<&'a Requirement> {
+        self.manifest
+            .requirements
+            .iter()
+            .filter(|requirement| requirement.name == *name)
+            .collect()
+    }
+
+    /// Collect every path from a direct requirement to the given package, in order from the
+    /// direct requirement to the package itself.
+    fn paths(&self, key: PackageKey<'a>) -> Vec<Vec<PackageKey<'a>>> {
+        let mut paths = Vec::new();
+        self.collect_paths(key, &mut Vec::new(), &mut paths);
+        paths
+    }
+
+    fn collect_paths(
+        &self,
+        key: PackageKey<'a>,
+        path: &mut Vec<PackageKey<'a>>,
+        paths: &mut Vec<Vec<PackageKey<'a>>>,
+    ) {
+        if paths.len() >= MAX_PATHS {
+            return;
+        }
+
+        path.push(key);
+        let dependents = self.dependents(key);
+        if dependents.is_empty() || !self.direct_requirements(key.0).is_empty() {
+            paths.push(path.iter().rev().copied().collect());
+        }
+        for dependent in dependents {
+            // Avoid walking around dependency cycles.
+            if !path.contains(&dependent) {
+                self.collect_paths(dependent, path, paths);
+            }
+        }
+        path.pop();
+    }
+
+    /// Return every requirement on the given package, along with its provenance.
+    fn constraints(&self, key: PackageKey<'a>) -> Vec<Constraint<'a>> {
+        let mut constraints = Vec::new();
+        for requirement in self.direct_requirements(key.0) {
+            constraints.push(Constraint {
+                requirement,
+                source: ConstraintSource::Requirement(requirement.origin.as_ref()),
+            });
+        }
+        for dependent in self.dependents(key) {
+            for requirement in self.requirements(dependent, key.0) {
+                constraints.push(Constraint {
+                    requirement,
+                    source: ConstraintSource::Dependent(dependent),
+                });
+            }
+        }
+        for requirement in self.manifest.constraints.get(key.0).into_iter().flatten() {
+            constraints.push(Constraint {
+                requirement,
+                source: ConstraintSource::Constraint(requirement.origin.as_ref()),
+            });
+        }
+        for requirement in self.manifest.overrides.get(key.0).into_iter().flatten() {
+            constraints.push(Constraint {
+                requirement,
+                source: ConstraintSource::Override(requirement.origin.as_ref()),
+            });
+        }
+        constraints
+    }
+
+    /// Write a single dependency path, e.g., `-r requirements.in (anyio>=4) -> anyio==4.3.0
+    /// (idna>=2.8) -> idna==3.6`.
+    fn fmt_path(&self, f: &mut Formatter<'_>, path: &[PackageKey<'a>]) -> std::fmt::Result {
+        let Some((&(name, _), _)) = path.split_first() else {
+            return Ok(());
+        };
+
+        let direct = self.direct_requirements(name);
+        if !direct.is_empty() {
+            let roots = direct
+                .iter()
+                .map(|requirement| {
+                    let source = ConstraintSource::Requirement(requirement.origin.as_ref());
+                    format!("{source} ({requirement})")
+                })
+                .join(", ");
+            write!(f, "{roots} -> ")?;
+        }
+
+        for (index, &(name, version)) in path.iter().enumerate() {
+            write!(f, "{}", format!("{name}=={version}").bold())?;
+            if let Some(&(next, _)) = path.get(index + 1) {
+                let requirements = self
+                    .requirements((name, version), next)
+                    .into_iter()
+                    .join(" | ");
+                write!(f, " ({requirements}) -> ")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the reason that the given version was selected over the next newer version.
+    fn fmt_outcome(
+        &self,
+        f: &mut Formatter<'_>,
+        key: PackageKey<'a>,
+        constraints: &[Constraint<'a>],
+    ) -> std::fmt::Result {
+        let (name, version) = key;
+
+        // Packages that were provided by URL aren't selected from a set of versions.
+        if let Some(dist) = self.nodes(key).next() {
+            if let VersionOrUrlRef::Url(url) = dist.version_or_url() {
+                return writeln!(f, "{name} was requested by URL ({url})");
+            }
+        }
+
+        let Some(response) = self.index.packages().get(name) else {
+            return Ok(());
+        };
+        let VersionsResponse::Found(ref version_maps) = *response else {
+            return Ok(());
+        };
+
+        // Find the next newer version. Avoid suggesting pre-releases for stable versions.
+        let next = version_maps
+            .iter()
+            .flat_map(|version_map| version_map.iter().map(move |entry| (version_map, entry.0)))
+            .filter(|(_, candidate)| *candidate > version)
+            .filter(|(_, candidate)| version.any_prerelease() || !candidate.any_prerelease())
+            .min_by_key(|(_, candidate)| *candidate);
+        let Some((version_map, next)) = next else {
+            return writeln!(
+                f,
+                "{} is the latest available version",
+                format!("{name}=={version}").bold()
+            );
+        };
+        let next_display = format!("{name}=={next}").bold().to_string();
+
+        // If any overrides are present, they replace all other requirements on the package
+        // (except for constraints).
+        let overridden = constraints
+            .iter()
+            .any(|constraint| matches!(constraint.source, ConstraintSource::Override(_)));
+        let excluded = constraints
+            .iter()
+            .filter(|constraint| {
+                !overridden
+                    || matches!(
+                        constraint.source,
+                        ConstraintSource::Override(_) | ConstraintSource::Constraint(_)
+                    )
+            })
+            .filter(|constraint| match &constraint.requirement.source {
+                RequirementSource::Registry { specifier, .. } => !specifier.contains(next),
+                _ => false,
+            })
+            .map(|constraint| format!("{} ({})", constraint.requirement, constraint.source))
+            .collect::<Vec<_>>();
+        if !excluded.is_empty() {
+            return writeln!(
+                f,
+                "{next_display} is excluded by {}",
+                excluded.into_iter().join(", ")
+            );
+        }
+
+        if let Some(dist) = version_map.get(next) {
+            if let CandidateDist::Incompatible(incompatibility) = CandidateDist::from(dist) {
+                return writeln!(f, "{next_display} {incompatibility}");
+            }
+        }
+
+        if let Some(preference) = self
+            .manifest
+            .preferences
+            .iter()
+            .find(|preference| preference.name() == name && preference.version() == version)
+        {
+            return writeln!(
+                f,
+                "{next_display} is available, but {} was preferred by {}",
+                format!("{name}=={version}").bold(),
+                preference.source()
+            );
+        }
+
+        writeln!(
+            f,
+            "{next_display} is available and satisfies all requirements on {name}, but was not selected"
+        )
+    }
+}
+
+impl Display for DisplayWhy<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let keys = self
+            .resolution
+            .petgraph
+            .node_indices()
+            .map(|index| package_key(&self.resolution.petgraph[index]))
+            .filter(|(name, _)| *name == self.package)
+            .collect::<BTreeSet<_>>();
+
+        for (index, key) in keys.into_iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            let (name, version) = key;
+            writeln!(f, "{}", format!("{name}=={version}").bold())?;
+
+            let paths = self.paths(key);
+            for path in &paths {
+                write!(f, "  ")?;
+                self.fmt_path(f, path)?;
+                writeln!(f)?;
+            }
+            if paths.len() >= MAX_PATHS {
+                writeln!(f, "  ... (additional paths omitted)")?;
+            }
+
+            let constraints = self.constraints(key);
+            if !constraints.is_empty() {
+                writeln!(f, "Requirements on {name}:")?;
+                for constraint in &constraints {
+                    writeln!(f, "  {} ({})", constraint.requirement, constraint.source)?;
+                }
+            }
+
+            self.fmt_outcome(f, key, &constraints)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Return the [`PackageKey`] for the given distribution.
+fn package_key(dist: &AnnotatedDist) -> PackageKey<'_> {
+    (dist.name(), &dist.metadata.version)
+}
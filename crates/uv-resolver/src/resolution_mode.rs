@@ -20,6 +20,16 @@ pub enum ResolutionMode {
     LowestDirect,
 }
 
+impl std::fmt::Display for ResolutionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Highest => write!(f, "highest"),
+            Self::Lowest => write!(f, "lowest"),
+            Self::LowestDirect => write!(f, "lowest-direct"),
+        }
+    }
+}
+
 /// Like [`ResolutionMode`], but with any additional information required to select a candidate,
 /// like the set of direct dependencies.
 #[derive(Debug, Clone)]
@@ -21,16 +21,16 @@ use tracing::{debug, enabled, instrument, trace, warn, Level};
 
 use distribution_types::{
     BuiltDist, Dist, DistributionMetadata, IncompatibleDist, IncompatibleSource, IncompatibleWheel,
-    InstalledDist, PythonRequirementKind, RemoteSource, ResolvedDist, ResolvedDistRef, SourceDist,
-    VersionOrUrlRef,
+    InstalledDist, PrioritizedDist, PythonRequirementKind, RemoteSource, ResolvedDist,
+    ResolvedDistRef, SourceDist, VersionOrUrlRef,
 };
 pub(crate) use locals::Locals;
-use pep440_rs::{Version, MIN_VERSION};
-use pep508_rs::{MarkerEnvironment, MarkerTree};
+use pep440_rs::{Version, VersionSpecifiers, MIN_VERSION};
+use pep508_rs::{ExtraOperator, MarkerEnvironment, MarkerExpression, MarkerTree};
 use platform_tags::Tags;
-use pypi_types::{Metadata23, Requirement, VerbatimParsedUrl};
+use pypi_types::{Metadata23, Requirement, RequirementSource, VerbatimParsedUrl};
 pub(crate) use urls::Urls;
-use uv_configuration::{Constraints, Overrides};
+use uv_configuration::{ConflictItem, ConflictKind, Conflicts, Constraints, Overrides};
 use uv_distribution::{ArchiveMetadata, DistributionDatabase};
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -38,7 +38,7 @@ use uv_types::{BuildContext, HashStrategy, InstalledPackagesProvider};
 
 use crate::candidate_selector::{CandidateDist, CandidateSelector};
 use crate::dependency_provider::UvDependencyProvider;
-use crate::error::ResolveError;
+use crate::error::{NoSolutionError, ResolveError};
 use crate::manifest::Manifest;
 use crate::pins::FilePins;
 use crate::preferences::Preferences;
@@ -59,8 +59,11 @@ pub use crate::resolver::provider::{
     DefaultResolverProvider, MetadataResponse, PackageVersionsResult, ResolverProvider,
     VersionsResponse, WheelMetadataResult,
 };
+use crate::resolver::relax::{relax, RelaxCandidate, RelaxProvider};
+pub(crate) use crate::resolver::relax::{RelaxDirection, Relaxation};
 use crate::resolver::reporter::Facade;
 pub use crate::resolver::reporter::{BuildId, Reporter};
+use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
 use crate::{DependencyMode, Exclusions, FlatIndex, Options};
 
@@ -69,6 +72,7 @@ mod batch_prefetch;
 mod index;
 mod locals;
 mod provider;
+mod relax;
 mod reporter;
 mod urls;
 
@@ -85,6 +89,7 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     constraints: Constraints,
     overrides: Overrides,
     dev: Vec<GroupName>,
+    conflicts: Conflicts,
     preferences: Preferences,
     git: GitResolver,
     exclusions: Exclusions,
@@ -98,7 +103,8 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     /// This is derived from `PythonRequirement` once at initialization
     /// time. It's used in universal mode to filter our dependencies with
     /// a `python_version` marker expression that has no overlap with the
-    /// `Requires-Python` specifier.
+    /// `Requires-Python` specifier, or with a marker expression that has no
+    /// overlap with any of the declared target environments.
     ///
     /// This is non-None if and only if the resolver is operating in
     /// universal mode. (i.e., when `markers` is `None`.)
@@ -155,13 +161,13 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             python_requirement.clone(),
             AllowedYanks::from_manifest(&manifest, markers, options.dependency_mode),
             hasher,
-            options.exclude_newer,
+            options.exclude_newer.clone(),
             build_context.build_options(),
         );
 
         Self::new_custom_io(
             manifest,
-            options,
+            &options,
             hasher,
             markers,
             python_requirement,
@@ -180,7 +186,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
     #[allow(clippy::too_many_arguments)]
     pub fn new_custom_io(
         manifest: Manifest,
-        options: Options,
+        options: &Options,
         hasher: &HashStrategy,
         markers: Option<&MarkerEnvironment>,
         python_requirement: &PythonRequirement,
@@ -192,12 +198,19 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let requires_python = if markers.is_some() {
             None
         } else {
-            Some(
-                python_requirement
-                    .requires_python()
-                    .map(RequiresPython::to_marker_tree)
-                    .unwrap_or_else(|| MarkerTree::And(vec![])),
-            )
+            let requires_python = python_requirement
+                .requires_python()
+                .map(RequiresPython::to_marker_tree)
+                .unwrap_or_else(|| MarkerTree::And(vec![]));
+            if options.environments.is_empty() {
+                Some(requires_python)
+            } else {
+                // Restrict the resolution to the declared target environments.
+                Some(MarkerTree::And(vec![
+                    requires_python,
+                    MarkerTree::Or(options.environments.clone()),
+                ]))
+            }
         };
         let state = ResolverState {
             index: index.clone(),
@@ -221,6 +234,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             requires_python,
             reporter: None,
             installed_packages,
+            conflicts: options.conflicts.clone(),
         };
         Ok(Self { state, provider })
     }
@@ -305,16 +319,33 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         request_sink: Sender<Request>,
     ) -> Result<ResolutionGraph, (ResolveError, FxHashSet<PackageName>)> {
         let mut visited = FxHashSet::default();
-        self.solve_tracked(&mut visited, request_sink)
-            .map_err(|err| (err, visited))
+        let mut fork_markers = Vec::new();
+        self.clone()
+            .solve_tracked(&mut visited, &mut fork_markers, request_sink)
+            .map_err(|err| {
+                // Attach the markers of the fork in which the failure occurred, along with any
+                // relaxations that would avoid it.
+                let err = match err {
+                    ResolveError::NoSolution(err) => {
+                        let relaxations = self.relaxations(&err);
+                        ResolveError::NoSolution(
+                            err.with_fork_markers(&fork_markers)
+                                .with_relaxations(relaxations),
+                        )
+                    }
+                    err => err,
+                };
+                (err, visited)
+            })
     }
 
     /// Run the PubGrub solver, updating the `visited` set for each package visited during
-    /// resolution.
+    /// resolution, and the `fork_markers` for the fork that is currently being solved.
     #[instrument(skip_all)]
     fn solve_tracked(
         self: Arc<Self>,
         visited: &mut FxHashSet<PackageName>,
+        fork_markers: &mut Vec<MarkerTree>,
         request_sink: Sender<Request>,
     ) -> Result<ResolutionGraph, ResolveError> {
         let root = PubGrubPackage::from(PubGrubPackageInner::Root(self.project.clone()));
@@ -325,6 +356,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             pins: FilePins::default(),
             priorities: PubGrubPriorities::default(),
             added_dependencies: FxHashMap::default(),
+            markers: Vec::new(),
         };
         let mut forked_states = vec![state];
         let mut resolutions = vec![];
@@ -338,6 +370,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         }
 
         'FORK: while let Some(mut state) = forked_states.pop() {
+            fork_markers.clone_from(&state.markers);
             loop {
                 // Run unit propagation.
                 state.pubgrub.unit_propagation(state.next.clone())?;
@@ -572,6 +605,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 if !is_last {
                                     cur_state = Some(forked_state.clone());
                                 }
+                                forked_state.markers.extend(fork.markers);
 
                                 // Add that package and version if the dependencies are not problematic.
                                 let dep_incompats =
@@ -951,7 +985,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 Dependencies::Unavailable(err) => ForkedDependencies::Unavailable(err),
             });
         }
-        Ok(result?.fork())
+        Ok(result?.fork_conflicts(&self.conflicts))
     }
 
     /// Given a candidate package and version, return its dependencies.
@@ -1503,6 +1537,187 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             reporter.on_complete();
         }
     }
+
+    /// Compute the direct requirements that could be relaxed to avoid the given resolution
+    /// failure, by way of sub-resolutions over the metadata that has already been requested.
+    fn relaxations(&self, err: &NoSolutionError) -> Vec<Relaxation> {
+        // Only consider the direct requirements that are involved in the failure.
+        let involved = err
+            .packages()
+            .filter_map(|package| match &**package {
+                PubGrubPackageInner::Package {
+                    name, url: None, ..
+                } => Some(name),
+                _ => None,
+            })
+            .collect::<FxHashSet<_>>();
+
+        let candidates = self
+            .requirements
+            .iter()
+            .filter(|requirement| involved.contains(&requirement.name))
+            // Skip packages with multiple direct requirements, which can't be relaxed in isolation.
+            .filter(|requirement| {
+                self.requirements
+                    .iter()
+                    .filter(|other| other.name == requirement.name)
+                    .count()
+                    == 1
+            })
+            .filter_map(|requirement| {
+                let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                    return None;
+                };
+                if specifier.is_empty() {
+                    return None;
+                }
+                let range = PubGrubSpecifier::try_from(specifier).ok()?.into();
+                Some(RelaxCandidate {
+                    name: requirement.name.clone(),
+                    specifier: specifier.clone(),
+                    range,
+                })
+            })
+            .collect::<Vec<_>>();
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+
+        relax(self, &candidates)
+    }
+}
+
+impl<InstalledPackages: InstalledPackagesProvider> RelaxProvider
+    for ResolverState<InstalledPackages>
+{
+    fn root_dependencies(&self, relaxed: &[&PackageName]) -> Vec<(PackageName, Range<Version>)> {
+        let requirements = self
+            .requirements
+            .iter()
+            .map(|requirement| match &requirement.source {
+                RequirementSource::Registry { index, .. }
+                    if relaxed.contains(&&requirement.name) =>
+                {
+                    Requirement {
+                        source: RequirementSource::Registry {
+                            specifier: VersionSpecifiers::empty(),
+                            index: index.clone(),
+                        },
+                        ..requirement.clone()
+                    }
+                }
+                _ => requirement.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        PubGrubDependencies::from_requirements(
+            &requirements,
+            &BTreeMap::default(),
+            &self.constraints,
+            &self.overrides,
+            None,
+            None,
+            None,
+            &self.urls,
+            &self.locals,
+            &self.git,
+            self.markers.as_ref(),
+            self.requires_python.as_ref(),
+        )
+        .map(relaxable_dependencies)
+        .unwrap_or_default()
+    }
+
+    fn versions(&self, name: &PackageName) -> Vec<Version> {
+        // Only use versions that have already been fetched; the request loop may have shut down,
+        // so waiting on any in-flight requests could hang.
+        let Some(response) = self.index.packages().get(name) else {
+            return Vec::new();
+        };
+        let VersionsResponse::Found(ref version_maps) = *response else {
+            return Vec::new();
+        };
+
+        let allow_prereleases = self.selector.prerelease_strategy(name).allows(name);
+        let mut versions = version_maps
+            .iter()
+            .flat_map(VersionMap::iter)
+            .filter(|(version, _)| allow_prereleases || !version.any_prerelease())
+            .filter(|(_, dist)| {
+                dist.prioritized_dist()
+                    .and_then(PrioritizedDist::get)
+                    .is_some()
+            })
+            .map(|(version, _)| version.clone())
+            .collect::<Vec<_>>();
+        versions.sort_unstable();
+        versions.dedup();
+        if self.selector.use_highest_version(name) {
+            versions.reverse();
+        }
+        versions
+    }
+
+    fn dependencies(
+        &self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Option<Vec<(PackageName, Range<Version>)>> {
+        if self.dependency_mode.is_direct() {
+            return Some(Vec::new());
+        }
+
+        // Only use metadata that was fetched during resolution, without waiting on any in-flight
+        // requests.
+        let dist = PubGrubDistribution::from_registry(name, version);
+        let response = self.index.distributions().get(&dist.version_id())?;
+        let MetadataResponse::Found(archive) = &*response else {
+            return None;
+        };
+
+        PubGrubDependencies::from_requirements(
+            &archive.metadata.requires_dist,
+            &archive.metadata.dev_dependencies,
+            &self.constraints,
+            &self.overrides,
+            Some(name),
+            None,
+            None,
+            &self.urls,
+            &self.locals,
+            &self.git,
+            self.markers.as_ref(),
+            self.requires_python.as_ref(),
+        )
+        .ok()
+        .map(relaxable_dependencies)
+    }
+}
+
+/// Reduce a set of [`PubGrubDependencies`] to the registry packages they depend on, for use in a
+/// relaxed sub-resolution.
+///
+/// Extras and markers are reduced to their base package; URL dependencies are omitted, since
+/// they can't be relaxed.
+fn relaxable_dependencies(dependencies: PubGrubDependencies) -> Vec<(PackageName, Range<Version>)> {
+    Vec::from(dependencies)
+        .into_iter()
+        .filter_map(|(package, range)| match &*package {
+            PubGrubPackageInner::Package {
+                name, url: None, ..
+            }
+            | PubGrubPackageInner::Marker {
+                name, url: None, ..
+            }
+            | PubGrubPackageInner::Extra {
+                name, url: None, ..
+            }
+            | PubGrubPackageInner::Dev {
+                name, url: None, ..
+            } => Some((name.clone(), range)),
+            _ => None,
+        })
+        .collect()
 }
 
 /// State that is used during unit propagation in the resolver.
@@ -1537,6 +1752,9 @@ struct SolveState {
     /// This keeps track of the set of versions for each package that we've
     /// already visited during resolution. This avoids doing redundant work.
     added_dependencies: FxHashMap<PubGrubPackage, FxHashSet<Version>>,
+    /// The marker expressions that distinguish this fork from its siblings, one per fork taken on
+    /// the way to this state. Empty if resolution never forked.
+    markers: Vec<MarkerTree>,
 }
 
 impl SolveState {
@@ -1865,6 +2083,41 @@ enum Dependencies {
 }
 
 impl Dependencies {
+    /// Fork the dependencies on any [`Conflicts`], such that at most one item of each conflicting
+    /// set is enabled in each fork, and then fork each of those on disjoint markers.
+    fn fork_conflicts(self, conflicts: &Conflicts) -> ForkedDependencies {
+        let deps = match self {
+            Dependencies::Available(deps) => deps,
+            Dependencies::Unavailable(err) => return ForkedDependencies::Unavailable(err),
+        };
+
+        let mut splits = split_conflicts(deps, conflicts);
+        if splits.len() == 1 {
+            let (deps, _) = splits.pop().unwrap();
+            return Dependencies::Available(deps).fork();
+        }
+
+        let mut forks = vec![];
+        for (deps, markers) in splits {
+            match Dependencies::Available(deps).fork() {
+                ForkedDependencies::Unforked(dependencies) => forks.push(Fork {
+                    dependencies,
+                    markers,
+                }),
+                ForkedDependencies::Forked(marker_forks) => {
+                    forks.extend(marker_forks.into_iter().map(|mut fork| {
+                        fork.markers.splice(0..0, markers.iter().cloned());
+                        fork
+                    }));
+                }
+                ForkedDependencies::Unavailable(_) => {
+                    unreachable!("available dependencies are never forked as unavailable")
+                }
+            }
+        }
+        ForkedDependencies::Forked(forks)
+    }
+
     fn fork(self) -> ForkedDependencies {
         use std::collections::hash_map::Entry;
 
@@ -1960,6 +2213,7 @@ impl Dependencies {
         }
         let mut forks = vec![Fork {
             dependencies: vec![],
+            markers: vec![],
         }];
         for (_, possible_forks) in by_name {
             let fork_groups = match possible_forks {
@@ -1976,8 +2230,15 @@ impl Dependencies {
                 }
             };
             let mut new_forks: Vec<Fork> = vec![];
+            let has_fork = fork_groups.forks.len() > 1;
             for group in fork_groups.forks {
                 let mut new_forks_for_group = forks.clone();
+                if has_fork {
+                    let marker = group.marker();
+                    for fork in &mut new_forks_for_group {
+                        fork.markers.push(marker.clone());
+                    }
+                }
                 for (index, _) in group.packages {
                     for fork in &mut new_forks_for_group {
                         fork.dependencies.push(deps[index].clone());
@@ -2008,6 +2269,8 @@ enum ForkedDependencies {
 #[derive(Clone, Debug)]
 struct Fork {
     dependencies: Vec<(PubGrubPackage, Range<Version>)>,
+    /// The marker expressions of the fork groups that produced this fork.
+    markers: Vec<MarkerTree>,
 }
 
 #[derive(Debug)]
@@ -2102,6 +2365,99 @@ impl<'a> PossibleFork<'a> {
         }
         false
     }
+
+    /// Returns the marker expression covered by this possible fork, i.e., the union of the
+    /// markers of its packages.
+    fn marker(&self) -> MarkerTree {
+        let mut markers: Vec<MarkerTree> = Vec::with_capacity(self.packages.len());
+        for (_, marker) in &self.packages {
+            if !markers.contains(*marker) {
+                markers.push((*marker).clone());
+            }
+        }
+        if markers.len() == 1 {
+            markers.pop().unwrap()
+        } else {
+            MarkerTree::Or(markers)
+        }
+    }
+}
+
+/// Split the dependencies such that, for each set of conflicts, at most one of its items is enabled
+/// in each split.
+///
+/// If a set has fewer than two of its items enabled by the dependencies, it's ignored.
+fn split_conflicts(
+    deps: Vec<(PubGrubPackage, Range<Version>)>,
+    conflicts: &Conflicts,
+) -> Vec<(Vec<(PubGrubPackage, Range<Version>)>, Vec<MarkerTree>)> {
+    let mut splits = vec![(deps, vec![])];
+    for set in conflicts.iter() {
+        let mut next = Vec::with_capacity(splits.len());
+        for (deps, markers) in splits {
+            // Determine which items of the set are enabled by the dependencies.
+            let mut enabled: Vec<ConflictItem> = vec![];
+            for (package, _) in &deps {
+                if let Some(item) = conflict_item(package) {
+                    if set.contains(&item) && !enabled.contains(&item) {
+                        enabled.push(item);
+                    }
+                }
+            }
+            if enabled.len() < 2 {
+                next.push((deps, markers));
+                continue;
+            }
+
+            // Create a split for each enabled item, omitting the others, and mark it with the
+            // item it enables.
+            for keep in &enabled {
+                let mut markers = markers.clone();
+                markers.push(conflict_marker(keep));
+                next.push((
+                    deps.iter()
+                        .filter(|(package, _)| {
+                            conflict_item(package)
+                                .map_or(true, |item| item == *keep || !enabled.contains(&item))
+                        })
+                        .cloned()
+                        .collect(),
+                    markers,
+                ));
+            }
+        }
+        splits = next;
+    }
+    splits
+}
+
+/// Returns a marker that identifies the fork in which the given [`ConflictItem`] is enabled, e.g.,
+/// `extra == 'extra-project-cpu'` for the `cpu` extra of `project`.
+///
+/// Dependency groups have no PEP 508 marker, so both extras and groups are encoded as a synthetic
+/// extra, prefixed by the kind of item and the package that declares it.
+fn conflict_marker(item: &ConflictItem) -> MarkerTree {
+    let name = match item.kind() {
+        ConflictKind::Extra(extra) => format!("extra-{}-{extra}", item.package()),
+        ConflictKind::Group(group) => format!("group-{}-{group}", item.package()),
+    };
+    MarkerTree::Expression(MarkerExpression::Extra {
+        operator: ExtraOperator::Equal,
+        name: ExtraName::new(name).expect("package, extra, and group names form a valid extra"),
+    })
+}
+
+/// Returns the [`ConflictItem`] enabled by a dependency on the given package, if any.
+fn conflict_item(package: &PubGrubPackage) -> Option<ConflictItem> {
+    match &**package {
+        PubGrubPackageInner::Extra { name, extra, .. } => {
+            Some(ConflictItem::extra(name.clone(), extra.clone()))
+        }
+        PubGrubPackageInner::Dev { name, dev, .. } => {
+            Some(ConflictItem::group(name.clone(), dev.clone()))
+        }
+        _ => None,
+    }
 }
 
 fn uncapitalize<T: AsRef<str>>(string: T) -> String {
@@ -0,0 +1,490 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use pubgrub::range::Range;
+use rustc_hash::FxHashMap;
+
+use pep440_rs::{Version, VersionSpecifiers};
+use uv_normalize::PackageName;
+
+/// The maximum number of direct requirements that are considered for relaxation.
+const MAX_CANDIDATES: usize = 6;
+
+/// The maximum number of versions that a single sub-resolution may select before giving up.
+const MAX_SELECTIONS: usize = 500;
+
+/// The maximum number of relaxations to suggest.
+const MAX_RELAXATIONS: usize = 3;
+
+/// A source of package versions and dependencies for relaxed sub-resolutions.
+///
+/// Sub-resolutions are only run over data that the resolver has already fetched, so
+/// implementations should never trigger new requests.
+pub(crate) trait RelaxProvider {
+    /// Return the dependencies of the root, with the direct requirements on the given packages
+    /// relaxed to allow any version.
+    fn root_dependencies(&self, relaxed: &[&PackageName]) -> Vec<(PackageName, Range<Version>)>;
+
+    /// Return the usable versions of the given package, in order of preference.
+    fn versions(&self, name: &PackageName) -> Vec<Version>;
+
+    /// Return the dependencies of the given package version, or `None` if they aren't known.
+    fn dependencies(
+        &self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Option<Vec<(PackageName, Range<Version>)>>;
+}
+
+/// A direct requirement that may be relaxed to resolve a conflict.
+#[derive(Debug, Clone)]
+pub(crate) struct RelaxCandidate {
+    pub(crate) name: PackageName,
+    pub(crate) specifier: VersionSpecifiers,
+    pub(crate) range: Range<Version>,
+}
+
+/// A set of direct requirements which, if relaxed, would allow the resolution to succeed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Relaxation(Vec<RelaxedRequirement>);
+
+impl Relaxation {
+    /// Iterate over the relaxed requirements.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &RelaxedRequirement> {
+        self.0.iter()
+    }
+}
+
+/// A direct requirement in a [`Relaxation`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct RelaxedRequirement {
+    /// The name of the package.
+    pub(crate) name: PackageName,
+    /// The specifier of the original requirement.
+    pub(crate) specifier: VersionSpecifiers,
+    /// The version selected once the requirement is relaxed.
+    pub(crate) version: Version,
+    /// How the selected version relates to the original requirement.
+    pub(crate) direction: RelaxDirection,
+    /// The package version whose dependency excludes the original requirement, if any.
+    pub(crate) required_by: Option<(PackageName, Version)>,
+}
+
+/// The direction in which a [`RelaxedRequirement`] was relaxed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum RelaxDirection {
+    /// The selected version is lower than any version allowed by the original requirement.
+    Lower,
+    /// The selected version is higher than any version allowed by the original requirement.
+    Raise,
+    /// The selected version falls within a gap in the original requirement (e.g., `!=1.0`).
+    Other,
+}
+
+/// Compute minimal sets of direct requirements that, if relaxed, would allow the resolution to
+/// succeed.
+///
+/// Relaxations of a single requirement are preferred; pairs of requirements are only considered
+/// if no single requirement suffices. Each candidate set is tested with a bounded sub-resolution
+/// over the data returned by the [`RelaxProvider`].
+pub(crate) fn relax(
+    provider: &impl RelaxProvider,
+    candidates: &[RelaxCandidate],
+) -> Vec<Relaxation> {
+    let candidates = &candidates[..candidates.len().min(MAX_CANDIDATES)];
+
+    let relaxations = candidates
+        .iter()
+        .filter_map(|candidate| try_relax(provider, &[candidate]))
+        .take(MAX_RELAXATIONS)
+        .collect::<Vec<_>>();
+    if !relaxations.is_empty() {
+        return relaxations;
+    }
+
+    candidates
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(a, b)| try_relax(provider, &[a, b]))
+        .take(MAX_RELAXATIONS)
+        .collect()
+}
+
+/// Attempt a sub-resolution with the given direct requirements relaxed.
+fn try_relax(provider: &impl RelaxProvider, relaxed: &[&RelaxCandidate]) -> Option<Relaxation> {
+    let names = relaxed
+        .iter()
+        .map(|candidate| &candidate.name)
+        .collect::<Vec<_>>();
+
+    let mut solver = Solver::new(provider);
+    let mut partial = Partial::default();
+    for (name, range) in provider.root_dependencies(&names) {
+        if !partial.require(name, &range) {
+            return None;
+        }
+    }
+    let solution = solver.solve(partial)?;
+
+    let mut requirements = Vec::with_capacity(relaxed.len());
+    for candidate in relaxed {
+        let version = solution.selected.get(&candidate.name)?;
+
+        // If the original requirement is satisfied, the relaxation isn't necessary.
+        if candidate.range.contains(version) {
+            return None;
+        }
+
+        let direction = if candidate
+            .range
+            .intersection(&Range::strictly_lower_than(version.clone()))
+            == Range::empty()
+        {
+            RelaxDirection::Lower
+        } else if candidate
+            .range
+            .intersection(&Range::strictly_higher_than(version.clone()))
+            == Range::empty()
+        {
+            RelaxDirection::Raise
+        } else {
+            RelaxDirection::Other
+        };
+
+        // Find the package whose dependency excludes the original requirement.
+        let required_by = solution
+            .selected
+            .iter()
+            .filter(|(name, _)| **name != candidate.name)
+            .find(|(name, version)| {
+                solver
+                    .dependencies(name, version)
+                    .is_some_and(|dependencies| {
+                        dependencies.iter().any(|(dependency, range)| {
+                            *dependency == candidate.name
+                                && range.intersection(&candidate.range) == Range::empty()
+                        })
+                    })
+            })
+            .map(|(name, version)| (name.clone(), version.clone()));
+
+        requirements.push(RelaxedRequirement {
+            name: candidate.name.clone(),
+            specifier: candidate.specifier.clone(),
+            version: version.clone(),
+            direction,
+            required_by,
+        });
+    }
+
+    Some(Relaxation(requirements))
+}
+
+/// A partial solution in a sub-resolution.
+#[derive(Debug, Clone, Default)]
+struct Partial {
+    /// The accumulated range for every package required so far.
+    ranges: BTreeMap<PackageName, Range<Version>>,
+    /// The selected version for every package decided so far.
+    selected: BTreeMap<PackageName, Version>,
+}
+
+impl Partial {
+    /// Require the given package to fall within the given range, returning `false` if the
+    /// requirement conflicts with the partial solution.
+    fn require(&mut self, name: PackageName, range: &Range<Version>) -> bool {
+        let selected = self.selected.get(&name);
+        let accumulated = self.ranges.entry(name).or_insert_with(Range::full);
+        *accumulated = accumulated.intersection(range);
+        match selected {
+            Some(version) => accumulated.contains(version),
+            None => *accumulated != Range::empty(),
+        }
+    }
+}
+
+/// A bounded, depth-first solver over the data returned by a [`RelaxProvider`].
+struct Solver<'a, Provider: RelaxProvider> {
+    provider: &'a Provider,
+    selections: usize,
+    versions: FxHashMap<PackageName, Vec<Version>>,
+    dependencies: FxHashMap<(PackageName, Version), Option<Vec<(PackageName, Range<Version>)>>>,
+}
+
+impl<'a, Provider: RelaxProvider> Solver<'a, Provider> {
+    fn new(provider: &'a Provider) -> Self {
+        Self {
+            provider,
+            selections: 0,
+            versions: FxHashMap::default(),
+            dependencies: FxHashMap::default(),
+        }
+    }
+
+    /// Extend the partial solution to a complete solution, if possible within the budget.
+    fn solve(&mut self, partial: Partial) -> Option<Partial> {
+        // Decide the undecided package with the fewest candidate versions.
+        let next = partial
+            .ranges
+            .iter()
+            .filter(|(name, _)| !partial.selected.contains_key(*name))
+            .map(|(name, range)| (name.clone(), self.candidates(name, range)))
+            .min_by_key(|(_, candidates)| candidates.len());
+        let Some((name, candidates)) = next else {
+            return Some(partial);
+        };
+
+        for version in candidates {
+            if self.selections >= MAX_SELECTIONS {
+                return None;
+            }
+            self.selections += 1;
+
+            let Some(dependencies) = self.dependencies(&name, &version) else {
+                continue;
+            };
+
+            let mut next = partial.clone();
+            next.selected.insert(name.clone(), version);
+            if dependencies
+                .into_iter()
+                .all(|(dependency, range)| next.require(dependency, &range))
+            {
+                if let Some(solution) = self.solve(next) {
+                    return Some(solution);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Return the candidate versions of the given package within the given range.
+    fn candidates(&mut self, name: &PackageName, range: &Range<Version>) -> Vec<Version> {
+        self.versions
+            .entry(name.clone())
+            .or_insert_with(|| self.provider.versions(name))
+            .iter()
+            .filter(|version| range.contains(version))
+            .cloned()
+            .collect()
+    }
+
+    /// Return the dependencies of the given package version, if known.
+    fn dependencies(
+        &mut self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Option<Vec<(PackageName, Range<Version>)>> {
+        self.dependencies
+            .entry((name.clone(), version.clone()))
+            .or_insert_with(|| self.provider.dependencies(name, version))
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pubgrub::range::Range;
+    use rustc_hash::FxHashMap;
+
+    use pep440_rs::{Version, VersionSpecifiers};
+    use uv_normalize::PackageName;
+
+    use super::{relax, RelaxCandidate, RelaxDirection, RelaxProvider, Relaxation};
+    use crate::pubgrub::PubGrubSpecifier;
+
+    type Dependencies = Vec<(PackageName, VersionSpecifiers)>;
+
+    /// An in-memory [`RelaxProvider`], with versions listed in order of preference.
+    #[derive(Default)]
+    struct Universe {
+        root: Vec<(PackageName, VersionSpecifiers)>,
+        packages: FxHashMap<PackageName, Vec<(Version, Option<Dependencies>)>>,
+    }
+
+    impl Universe {
+        fn root(mut self, name: &str, specifier: &str) -> Self {
+            self.root
+                .push((name.parse().unwrap(), specifier.parse().unwrap()));
+            self
+        }
+
+        fn package(self, name: &str, version: &str, dependencies: &[(&str, &str)]) -> Self {
+            let dependencies = dependencies
+                .iter()
+                .map(|(name, specifier)| (name.parse().unwrap(), specifier.parse().unwrap()))
+                .collect();
+            self.insert(name, version, Some(dependencies))
+        }
+
+        fn package_without_metadata(self, name: &str, version: &str) -> Self {
+            self.insert(name, version, None)
+        }
+
+        fn insert(mut self, name: &str, version: &str, dependencies: Option<Dependencies>) -> Self {
+            self.packages
+                .entry(name.parse().unwrap())
+                .or_default()
+                .push((version.parse().unwrap(), dependencies));
+            self
+        }
+
+        fn candidates(&self) -> Vec<RelaxCandidate> {
+            self.root
+                .iter()
+                .map(|(name, specifier)| RelaxCandidate {
+                    name: name.clone(),
+                    specifier: specifier.clone(),
+                    range: range(specifier),
+                })
+                .collect()
+        }
+    }
+
+    impl RelaxProvider for Universe {
+        fn root_dependencies(
+            &self,
+            relaxed: &[&PackageName],
+        ) -> Vec<(PackageName, Range<Version>)> {
+            self.root
+                .iter()
+                .map(|(name, specifier)| {
+                    if relaxed.contains(&name) {
+                        (name.clone(), Range::full())
+                    } else {
+                        (name.clone(), range(specifier))
+                    }
+                })
+                .collect()
+        }
+
+        fn versions(&self, name: &PackageName) -> Vec<Version> {
+            self.packages
+                .get(name)
+                .into_iter()
+                .flatten()
+                .map(|(version, _)| version.clone())
+                .collect()
+        }
+
+        fn dependencies(
+            &self,
+            name: &PackageName,
+            version: &Version,
+        ) -> Option<Vec<(PackageName, Range<Version>)>> {
+            let (_, dependencies) = self
+                .packages
+                .get(name)?
+                .iter()
+                .find(|(candidate, _)| candidate == version)?;
+            Some(
+                dependencies
+                    .as_ref()?
+                    .iter()
+                    .map(|(name, specifier)| (name.clone(), range(specifier)))
+                    .collect(),
+            )
+        }
+    }
+
+    fn range(specifier: &VersionSpecifiers) -> Range<Version> {
+        PubGrubSpecifier::try_from(specifier).unwrap().into()
+    }
+
+    /// Render each relaxation as a list of `name==version` selections.
+    fn selections(relaxations: &[Relaxation]) -> Vec<Vec<String>> {
+        relaxations
+            .iter()
+            .map(|relaxation| {
+                relaxation
+                    .iter()
+                    .map(|requirement| format!("{}=={}", requirement.name, requirement.version))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lower_direct_requirement() {
+        let universe = Universe::default()
+            .root("numpy", ">=2")
+            .root("pandas", "")
+            .package("numpy", "2.0.0", &[])
+            .package("numpy", "1.26.4", &[])
+            .package("pandas", "2.1.0", &[("numpy", "<2")]);
+
+        let relaxations = relax(&universe, &universe.candidates());
+        assert_eq!(selections(&relaxations), vec![vec!["numpy==1.26.4"]]);
+
+        let requirement = relaxations[0].iter().next().unwrap();
+        assert_eq!(requirement.direction, RelaxDirection::Lower);
+        assert_eq!(
+            requirement.required_by,
+            Some((
+                PackageName::from_str("pandas").unwrap(),
+                Version::from_str("2.1.0").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn relax_either_requirement() {
+        // Only older releases of `fastapi` support `pydantic<2`.
+        let universe = Universe::default()
+            .root("fastapi", ">=0.100")
+            .root("pydantic", "<2")
+            .package("fastapi", "0.110.0", &[("pydantic", ">=2")])
+            .package("fastapi", "0.99.1", &[("pydantic", "<2")])
+            .package("pydantic", "2.7.0", &[])
+            .package("pydantic", "1.10.15", &[]);
+
+        let relaxations = relax(&universe, &universe.candidates());
+        assert_eq!(
+            selections(&relaxations),
+            vec![vec!["fastapi==0.99.1"], vec!["pydantic==2.7.0"]]
+        );
+
+        let directions = relaxations
+            .iter()
+            .flat_map(Relaxation::iter)
+            .map(|requirement| requirement.direction)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            directions,
+            vec![RelaxDirection::Lower, RelaxDirection::Raise]
+        );
+    }
+
+    #[test]
+    fn relax_pair() {
+        // `c` conflicts with both `a>=2` and `b>=2`, so both must be relaxed.
+        let universe = Universe::default()
+            .root("a", ">=2")
+            .root("b", ">=2")
+            .root("c", "")
+            .package("a", "2.0.0", &[])
+            .package("a", "1.0.0", &[])
+            .package("b", "2.0.0", &[])
+            .package("b", "1.0.0", &[])
+            .package("c", "1.0.0", &[("a", "<2"), ("b", "<2")]);
+
+        let relaxations = relax(&universe, &universe.candidates());
+        assert_eq!(selections(&relaxations), vec![vec!["a==1.0.0", "b==1.0.0"]]);
+    }
+
+    #[test]
+    fn unknown_metadata() {
+        // Without metadata for `numpy==1.26.4`, the relaxation can't be verified.
+        let universe = Universe::default()
+            .root("numpy", ">=2")
+            .root("pandas", "")
+            .package("numpy", "2.0.0", &[])
+            .package_without_metadata("numpy", "1.26.4")
+            .package("pandas", "2.1.0", &[("numpy", "<2")]);
+
+        assert!(relax(&universe, &universe.candidates()).is_empty());
+    }
+}
@@ -21,7 +21,9 @@ use uv_types::HashStrategy;
 use uv_warnings::warn_user_once;
 
 use crate::flat_index::FlatDistributions;
-use crate::{python_requirement::PythonRequirement, yanks::AllowedYanks, ExcludeNewer};
+use crate::{
+    python_requirement::PythonRequirement, yanks::AllowedYanks, ExcludeNewer, ExcludeNewerTimestamp,
+};
 
 /// A map from versions to distributions.
 #[derive(Debug)]
@@ -109,7 +111,8 @@ impl VersionMap {
                 index: index.clone(),
                 tags: tags.cloned(),
                 python_requirement: python_requirement.clone(),
-                exclude_newer: exclude_newer.copied(),
+                exclude_newer: exclude_newer
+                    .and_then(|exclude_newer| exclude_newer.for_package(package_name)),
                 allowed_yanks,
                 required_hashes,
             }),
@@ -291,7 +294,7 @@ struct VersionMapLazy {
     /// exists) is satisfied or not.
     python_requirement: PythonRequirement,
     /// Whether files newer than this timestamp should be excluded or not.
-    exclude_newer: Option<ExcludeNewer>,
+    exclude_newer: Option<ExcludeNewerTimestamp>,
     /// Which yanked versions are allowed
     allowed_yanks: FxHashSet<Version>,
     /// The hashes of allowed distributions.
@@ -9,6 +9,7 @@ import sys
 import json
 import os
 import platform
+import site
 import struct
 import sysconfig
 
@@ -415,6 +416,39 @@ def get_scheme():
         return get_distutils_scheme()
 
 
+def get_user_scheme():
+    """Return the Scheme for user installations (i.e., `pip install --user`), if supported.
+
+    The paths returned should be absolute, relative to `site.USER_BASE`.
+
+    This is based on pip's path discovery logic:
+        https://github.com/pypa/pip/blob/ae5fff36b0aad6e5e0037884927eaa29163c0611/src/pip/_internal/locations/_sysconfig.py#L124
+    """
+    user_base = site.getuserbase()
+    if not user_base:
+        return None
+
+    try:
+        if hasattr(sysconfig, "get_preferred_scheme"):
+            scheme_name = sysconfig.get_preferred_scheme("user")
+        elif sys.platform == "darwin" and sysconfig.get_config_var("PYTHONFRAMEWORK"):
+            scheme_name = "osx_framework_user"
+        else:
+            scheme_name = f"{os.name}_user"
+        paths = sysconfig.get_paths(scheme=scheme_name, vars={"userbase": user_base})
+    except KeyError:
+        # The platform doesn't support user installations (e.g., Emscripten).
+        return None
+
+    return {
+        "platlib": paths["platlib"],
+        "purelib": paths["purelib"],
+        "include": paths["include"],
+        "scripts": paths["scripts"],
+        "data": paths["data"],
+    }
+
+
 def get_operating_system_and_architecture():
     """Determine the Python interpreter architecture and operating system.
 
@@ -554,6 +588,8 @@ def main() -> None:
         "stdlib": sysconfig.get_path("stdlib"),
         "scheme": get_scheme(),
         "virtualenv": get_virtualenv(),
+        "user_base": site.getuserbase(),
+        "user_scheme": get_user_scheme(),
         "platform": get_operating_system_and_architecture(),
         # The `t` abiflag for freethreading Python.
         # https://peps.python.org/pep-0703/#build-configuration-changes
@@ -8,7 +8,7 @@ use uv_fs::{LockedFile, Simplified};
 
 use crate::toolchain::Toolchain;
 use crate::virtualenv::{virtualenv_python_executable, PyVenvConfiguration};
-use crate::{Error, Interpreter, Prefix, Target};
+use crate::{Error, Interpreter, Prefix, Root, Target};
 
 /// A Python environment, consisting of a Python [`Interpreter`] and its associated paths.
 #[derive(Debug, Clone)]
@@ -74,6 +74,27 @@ impl PythonEnvironment {
         }))
     }
 
+    /// Create a [`PythonEnvironment`] from an existing [`Interpreter`] that installs into the
+    /// user site-packages directory (i.e., `--user`).
+    #[must_use]
+    pub fn with_user(self) -> Self {
+        let inner = Arc::unwrap_or_clone(self.0);
+        Self(Arc::new(PythonEnvironmentShared {
+            interpreter: inner.interpreter.with_user(),
+            ..inner
+        }))
+    }
+
+    /// Create a [`PythonEnvironment`] from an existing [`Interpreter`] and `--root` directory.
+    #[must_use]
+    pub fn with_root(self, root: Root) -> Self {
+        let inner = Arc::unwrap_or_clone(self.0);
+        Self(Arc::new(PythonEnvironmentShared {
+            interpreter: inner.interpreter.with_root(root),
+            ..inner
+        }))
+    }
+
     /// Returns the root (i.e., `prefix`) of the Python interpreter.
     pub fn root(&self) -> &Path {
         &self.0.root
@@ -113,18 +134,35 @@ impl PythonEnvironment {
             .prefix()
             .map(|prefix| prefix.site_packages(self.0.interpreter.virtualenv()));
 
-        let interpreter = if target.is_none() && prefix.is_none() {
+        let user = if target.is_none() && prefix.is_none() {
+            self.0.interpreter.user().map(|scheme| {
+                std::iter::once(scheme.purelib.as_path())
+                    .chain((scheme.purelib != scheme.platlib).then_some(scheme.platlib.as_path()))
+            })
+        } else {
+            None
+        };
+
+        let interpreter = if target.is_none() && prefix.is_none() && user.is_none() {
             Some(self.0.interpreter.site_packages())
         } else {
             None
         };
 
+        // If we're installing under a `--root` directory, re-root every `site-packages` directory.
+        let root = self.0.interpreter.root();
+
         target
             .into_iter()
             .flatten()
             .map(Cow::Borrowed)
             .chain(prefix.into_iter().flatten().map(Cow::Owned))
+            .chain(user.into_iter().flatten().map(Cow::Borrowed))
             .chain(interpreter.into_iter().flatten().map(Cow::Borrowed))
+            .map(move |site_packages| match root {
+                Some(root) => Cow::Owned(root.join(&site_packages)),
+                None => site_packages,
+            })
     }
 
     /// Returns the path to the `bin` directory inside this environment.
@@ -137,6 +175,9 @@ impl PythonEnvironment {
         if let Some(target) = self.0.interpreter.target() {
             // If we're installing into a `--target`, use a target-specific lock file.
             LockedFile::acquire(target.root().join(".lock"), target.root().user_display())
+        } else if let Some(root) = self.0.interpreter.root() {
+            // If we're installing under a `--root`, use a root-specific lock file.
+            LockedFile::acquire(root.root().join(".lock"), root.root().user_display())
         } else if self.0.interpreter.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lock file.
             LockedFile::acquire(self.0.root.join(".lock"), self.0.root.user_display())
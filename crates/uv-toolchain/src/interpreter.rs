@@ -21,7 +21,7 @@ use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness, Timestamp};
 use uv_fs::{write_atomic_sync, PythonExt, Simplified};
 
 use crate::pointer_size::PointerSize;
-use crate::{Prefix, PythonVersion, Target, VirtualEnvironment};
+use crate::{Prefix, PythonVersion, Root, Target, VirtualEnvironment};
 
 /// A Python executable and its associated platform markers.
 #[derive(Debug, Clone)]
@@ -30,6 +30,8 @@ pub struct Interpreter {
     markers: Box<MarkerEnvironment>,
     scheme: Scheme,
     virtualenv: Scheme,
+    user_base: Option<PathBuf>,
+    user_scheme: Option<Scheme>,
     sys_prefix: PathBuf,
     sys_base_exec_prefix: PathBuf,
     sys_base_prefix: PathBuf,
@@ -40,6 +42,8 @@ pub struct Interpreter {
     tags: OnceCell<Tags>,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    user: bool,
+    root: Option<Root>,
     pointer_size: PointerSize,
     gil_disabled: bool,
 }
@@ -60,6 +64,8 @@ impl Interpreter {
             markers: Box::new(info.markers),
             scheme: info.scheme,
             virtualenv: info.virtualenv,
+            user_base: info.user_base,
+            user_scheme: info.user_scheme,
             sys_prefix: info.sys_prefix,
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
@@ -72,6 +78,8 @@ impl Interpreter {
             tags: OnceCell::new(),
             target: None,
             prefix: None,
+            user: false,
+            root: None,
         })
     }
 
@@ -94,6 +102,8 @@ impl Interpreter {
                 scripts: PathBuf::from("/dev/null"),
                 data: PathBuf::from("/dev/null"),
             },
+            user_base: None,
+            user_scheme: None,
             sys_prefix: PathBuf::from("/dev/null"),
             sys_base_exec_prefix: PathBuf::from("/dev/null"),
             sys_base_prefix: PathBuf::from("/dev/null"),
@@ -104,6 +114,8 @@ impl Interpreter {
             tags: OnceCell::new(),
             target: None,
             prefix: None,
+            user: false,
+            root: None,
             pointer_size: PointerSize::_64,
             gil_disabled: false,
         }
@@ -118,6 +130,8 @@ impl Interpreter {
             sys_prefix: virtualenv.root,
             target: None,
             prefix: None,
+            user: false,
+            root: None,
             ..self
         }
     }
@@ -140,6 +154,22 @@ impl Interpreter {
         }
     }
 
+    /// Return a new [`Interpreter`] to install into the user site-packages directory (i.e.,
+    /// `--user`), using the `user` installation scheme.
+    #[must_use]
+    pub fn with_user(self) -> Self {
+        Self { user: true, ..self }
+    }
+
+    /// Return a new [`Interpreter`] to install relative to the given `--root` directory.
+    #[must_use]
+    pub fn with_root(self, root: Root) -> Self {
+        Self {
+            root: Some(root),
+            ..self
+        }
+    }
+
     /// Returns the path to the Python virtual environment.
     #[inline]
     pub fn platform(&self) -> &Platform {
@@ -183,6 +213,16 @@ impl Interpreter {
         self.prefix.is_some()
     }
 
+    /// Returns `true` if the environment is a `--user` environment.
+    pub fn is_user(&self) -> bool {
+        self.user
+    }
+
+    /// Returns `true` if the environment is re-rooted under a `--root` directory.
+    pub fn is_root(&self) -> bool {
+        self.root.is_some()
+    }
+
     /// Returns `Some` if the environment is externally managed, optionally including an error
     /// message from the `EXTERNALLY-MANAGED` file.
     ///
@@ -193,8 +233,9 @@ impl Interpreter {
             return None;
         }
 
-        // If we're installing into a target or prefix directory, it's never externally managed.
-        if self.is_target() || self.is_prefix() {
+        // If we're installing into a target, prefix, or root directory, it's never externally
+        // managed. (Like pip, we continue to respect the marker for `--user` installations.)
+        if self.is_target() || self.is_prefix() || self.is_root() {
             return None;
         }
 
@@ -355,6 +396,17 @@ impl Interpreter {
         &self.virtualenv
     }
 
+    /// Return the `site.USER_BASE` directory for this Python interpreter, if any.
+    pub fn user_base(&self) -> Option<&Path> {
+        self.user_base.as_deref()
+    }
+
+    /// Return the [`Scheme`] for user installations (i.e., `--user`) with this Python interpreter,
+    /// if the platform supports them.
+    pub fn user_scheme(&self) -> Option<&Scheme> {
+        self.user_scheme.as_ref()
+    }
+
     /// Return the [`PointerSize`] of the Python interpreter (i.e., 32- vs. 64-bit).
     pub fn pointer_size(&self) -> PointerSize {
         self.pointer_size
@@ -379,34 +431,57 @@ impl Interpreter {
         self.prefix.as_ref()
     }
 
+    /// Return the user [`Scheme`] if this interpreter installs into the user site-packages
+    /// directory (i.e., `--user`).
+    pub fn user(&self) -> Option<&Scheme> {
+        if self.user {
+            self.user_scheme.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Return the `--root` directory for this interpreter, if any.
+    pub fn root(&self) -> Option<&Root> {
+        self.root.as_ref()
+    }
+
     /// Return the [`Layout`] environment used to install wheels into this interpreter.
     pub fn layout(&self) -> Layout {
+        let scheme = if let Some(target) = self.target.as_ref() {
+            target.scheme()
+        } else if let Some(prefix) = self.prefix.as_ref() {
+            prefix.scheme(&self.virtualenv)
+        } else if let Some(user) = self.user() {
+            user.clone()
+        } else {
+            Scheme {
+                purelib: self.purelib().to_path_buf(),
+                platlib: self.platlib().to_path_buf(),
+                scripts: self.scripts().to_path_buf(),
+                data: self.data().to_path_buf(),
+                include: if self.is_virtualenv() {
+                    // If the interpreter is a venv, then the `include` directory has a different structure.
+                    // See: https://github.com/pypa/pip/blob/0ad4c94be74cc24874c6feb5bb3c2152c398a18e/src/pip/_internal/locations/_sysconfig.py#L172
+                    self.sys_prefix.join("include").join("site").join(format!(
+                        "python{}.{}",
+                        self.python_major(),
+                        self.python_minor()
+                    ))
+                } else {
+                    self.include().to_path_buf()
+                },
+            }
+        };
+
         Layout {
             python_version: self.python_tuple(),
             sys_executable: self.sys_executable().to_path_buf(),
             os_name: self.markers.os_name().to_string(),
-            scheme: if let Some(target) = self.target.as_ref() {
-                target.scheme()
-            } else if let Some(prefix) = self.prefix.as_ref() {
-                prefix.scheme(&self.virtualenv)
+            scheme: if let Some(root) = self.root.as_ref() {
+                root.scheme(&scheme)
             } else {
-                Scheme {
-                    purelib: self.purelib().to_path_buf(),
-                    platlib: self.platlib().to_path_buf(),
-                    scripts: self.scripts().to_path_buf(),
-                    data: self.data().to_path_buf(),
-                    include: if self.is_virtualenv() {
-                        // If the interpreter is a venv, then the `include` directory has a different structure.
-                        // See: https://github.com/pypa/pip/blob/0ad4c94be74cc24874c6feb5bb3c2152c398a18e/src/pip/_internal/locations/_sysconfig.py#L172
-                        self.sys_prefix.join("include").join("site").join(format!(
-                            "python{}.{}",
-                            self.python_major(),
-                            self.python_minor()
-                        ))
-                    } else {
-                        self.include().to_path_buf()
-                    },
-                }
+                scheme
             },
         }
     }
@@ -515,6 +590,8 @@ struct InterpreterInfo {
     markers: MarkerEnvironment,
     scheme: Scheme,
     virtualenv: Scheme,
+    user_base: Option<PathBuf>,
+    user_scheme: Option<Scheme>,
     sys_prefix: PathBuf,
     sys_base_exec_prefix: PathBuf,
     sys_base_prefix: PathBuf,
@@ -10,6 +10,7 @@ pub use crate::interpreter::Interpreter;
 pub use crate::pointer_size::PointerSize;
 pub use crate::prefix::Prefix;
 pub use crate::python_version::PythonVersion;
+pub use crate::root::Root;
 pub use crate::target::Target;
 pub use crate::toolchain::Toolchain;
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
@@ -25,6 +26,7 @@ mod pointer_size;
 mod prefix;
 mod py_launcher;
 mod python_version;
+mod root;
 mod target;
 mod toolchain;
 mod virtualenv;
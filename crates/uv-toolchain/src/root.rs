@@ -0,0 +1,55 @@
+use std::path::{Component, Path, PathBuf};
+
+use pypi_types::Scheme;
+
+/// A `--root` directory relative to which all installation paths are re-rooted, e.g., to stage
+/// an installation for packaging.
+///
+/// Unlike `--prefix`, the installation scheme itself is unchanged: installing into `/usr/lib/...`
+/// with `--root /tmp/staging` writes to `/tmp/staging/usr/lib/...`, and any scripts continue to
+/// reference the installing interpreter.
+#[derive(Debug, Clone)]
+pub struct Root(PathBuf);
+
+impl Root {
+    /// Return the [`Scheme`] that results from re-rooting the given [`Scheme`] under the `--root`
+    /// directory.
+    pub fn scheme(&self, scheme: &Scheme) -> Scheme {
+        Scheme {
+            purelib: self.join(&scheme.purelib),
+            platlib: self.join(&scheme.platlib),
+            scripts: self.join(&scheme.scripts),
+            data: self.join(&scheme.data),
+            include: self.join(&scheme.include),
+        }
+    }
+
+    /// Re-root the given path under the `--root` directory.
+    ///
+    /// Any root or prefix (e.g., a drive letter on Windows) is stripped from the path before
+    /// joining, mirroring `distutils.util.change_root`.
+    pub fn join(&self, path: &Path) -> PathBuf {
+        let relative = path
+            .components()
+            .filter(|component| !matches!(component, Component::Prefix(_) | Component::RootDir))
+            .collect::<PathBuf>();
+        self.0.join(relative)
+    }
+
+    /// Initialize the `--root` directory.
+    pub fn init(&self) -> std::io::Result<()> {
+        fs_err::create_dir_all(&self.0)?;
+        Ok(())
+    }
+
+    /// Return the path to the `--root` directory.
+    pub fn root(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl From<PathBuf> for Root {
+    fn from(path: PathBuf) -> Self {
+        Self(path)
+    }
+}
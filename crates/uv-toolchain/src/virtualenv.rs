@@ -28,6 +28,10 @@ pub struct PyVenvConfiguration {
     pub(crate) virtualenv: bool,
     /// If the `uv` package was used to create the virtual environment.
     pub(crate) uv: bool,
+    /// The Python version of the virtual environment, e.g., `3.12.1` (or, for `virtualenv`,
+    /// `3.12.1.final.0`), as recorded in `version_info` or, for the standard library's `venv`
+    /// module, `version`.
+    pub(crate) version_info: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -136,6 +140,8 @@ impl PyVenvConfiguration {
     pub fn parse(cfg: impl AsRef<Path>) -> Result<Self, Error> {
         let mut virtualenv = false;
         let mut uv = false;
+        let mut version_info = None;
+        let mut version = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -143,7 +149,7 @@ impl PyVenvConfiguration {
         let content = fs::read_to_string(&cfg)
             .map_err(|err| Error::ParsePyVenvCfg(cfg.as_ref().to_path_buf(), err))?;
         for line in content.lines() {
-            let Some((key, _value)) = line.split_once('=') else {
+            let Some((key, value)) = line.split_once('=') else {
                 continue;
             };
             match key.trim() {
@@ -153,11 +159,23 @@ impl PyVenvConfiguration {
                 "uv" => {
                     uv = true;
                 }
+                "version_info" => {
+                    version_info = Some(value.trim().to_string());
+                }
+                "version" => {
+                    version = Some(value.trim().to_string());
+                }
                 _ => {}
             }
         }
 
-        Ok(Self { virtualenv, uv })
+        Ok(Self {
+            virtualenv,
+            uv,
+            // The standard library's `venv` module records the version as `version`, rather than
+            // `version_info`.
+            version_info: version_info.or(version),
+        })
     }
 
     /// Returns true if the virtual environment was created with the `virtualenv` package.
@@ -169,4 +187,19 @@ impl PyVenvConfiguration {
     pub fn is_uv(&self) -> bool {
         self.uv
     }
+
+    /// Returns the Python version of the virtual environment, as recorded in `version_info` (or
+    /// `version`).
+    pub fn version_info(&self) -> Option<&str> {
+        self.version_info.as_deref()
+    }
+
+    /// Returns the major and minor Python version of the virtual environment, as recorded in
+    /// `version_info` (or `version`).
+    pub fn python_tuple(&self) -> Option<(u8, u8)> {
+        let mut parts = self.version_info.as_deref()?.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        Some((major, minor))
+    }
 }
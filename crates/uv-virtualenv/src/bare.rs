@@ -4,7 +4,7 @@ use std::env;
 use std::env::consts::EXE_SUFFIX;
 use std::io;
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use fs_err as fs;
 use fs_err::File;
@@ -13,7 +13,7 @@ use tracing::info;
 
 use pypi_types::Scheme;
 use uv_fs::{cachedir, Simplified};
-use uv_toolchain::{Interpreter, VirtualEnvironment};
+use uv_toolchain::{Interpreter, PyVenvConfiguration, VirtualEnvironment};
 use uv_version::version;
 
 use crate::{Error, Prompt};
@@ -43,6 +43,17 @@ fn write_cfg(f: &mut impl Write, data: &[(String, String)]) -> io::Result<()> {
     Ok(())
 }
 
+/// Very basic `.cfg` file format reader, preserving the order of the keys.
+///
+/// Like CPython, each line is partitioned on the first equals sign.
+fn read_cfg(path: &Path) -> io::Result<Vec<(String, String)>> {
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect())
+}
+
 /// Write all the files that belong to a venv without any packages installed.
 pub fn create_bare_venv(
     location: &Path,
@@ -51,36 +62,7 @@ pub fn create_bare_venv(
     system_site_packages: bool,
     allow_existing: bool,
 ) -> Result<VirtualEnvironment, Error> {
-    // Determine the base Python executable; that is, the Python executable that should be
-    // considered the "base" for the virtual environment. This is typically the Python executable
-    // from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
-    // the base Python executable is the Python executable of the interpreter's base interpreter.
-    let base_python = if cfg!(unix) {
-        // On Unix, follow symlinks to resolve the base interpreter, since the Python executable in
-        // a virtual environment is a symlink to the base interpreter.
-        uv_fs::canonicalize_executable(interpreter.sys_executable())?
-    } else if cfg!(windows) {
-        // On Windows, follow `virtualenv`. If we're in a virtual environment, use
-        // `sys._base_executable` if it exists; if not, use `sys.base_prefix`. For example, with
-        // Python installed from the Windows Store, `sys.base_prefix` is slightly "incorrect".
-        //
-        // If we're _not_ in a virtual environment, use the interpreter's executable, since it's
-        // already a "system Python". We canonicalize the path to ensure that it's real and
-        // consistent, though we don't expect any symlinks on Windows.
-        if interpreter.is_virtualenv() {
-            if let Some(base_executable) = interpreter.sys_base_executable() {
-                base_executable.to_path_buf()
-            } else {
-                // Assume `python.exe`, though the exact executable name is never used (below) on
-                // Windows, only its parent directory.
-                interpreter.sys_base_prefix().join("python.exe")
-            }
-        } else {
-            uv_fs::canonicalize_executable(interpreter.sys_executable())?
-        }
-    } else {
-        unimplemented!("Only Windows and Unix are supported")
-    };
+    let base_python = base_python_executable(interpreter)?;
 
     // Validate the existing location.
     match location.metadata() {
@@ -144,56 +126,11 @@ pub fn create_bare_venv(
     fs::write(location.join(".gitignore"), "*")?;
 
     // Per PEP 405, the Python `home` is the parent directory of the interpreter.
-    let python_home = base_python.parent().ok_or_else(|| {
-        io::Error::new(
-            io::ErrorKind::NotFound,
-            "The Python interpreter needs to have a parent directory",
-        )
-    })?;
+    let python_home = python_home(&base_python)?;
 
     // Different names for the python interpreter
     fs::create_dir_all(&scripts)?;
-    let executable = scripts.join(format!("python{EXE_SUFFIX}"));
-
-    #[cfg(unix)]
-    {
-        uv_fs::replace_symlink(&base_python, &executable)?;
-        uv_fs::replace_symlink(
-            "python",
-            scripts.join(format!("python{}", interpreter.python_major())),
-        )?;
-        uv_fs::replace_symlink(
-            "python",
-            scripts.join(format!(
-                "python{}.{}",
-                interpreter.python_major(),
-                interpreter.python_minor(),
-            )),
-        )?;
-    }
-
-    // No symlinking on Windows, at least not on a regular non-dev non-admin Windows install.
-    if cfg!(windows) {
-        copy_launcher_windows(
-            WindowsExecutable::Python,
-            interpreter,
-            &base_python,
-            &scripts,
-            python_home,
-        )?;
-        copy_launcher_windows(
-            WindowsExecutable::Pythonw,
-            interpreter,
-            &base_python,
-            &scripts,
-            python_home,
-        )?;
-    }
-
-    #[cfg(not(any(unix, windows)))]
-    {
-        compile_error!("Only Windows and Unix are supported")
-    }
+    let executable = link_executables(interpreter, &base_python, &scripts, python_home)?;
 
     // Add all the activate scripts for different shells
     for (name, template) in ACTIVATE_TEMPLATES {
@@ -300,6 +237,180 @@ pub fn create_bare_venv(
     })
 }
 
+/// Determine the base Python executable; that is, the Python executable that should be
+/// considered the "base" for the virtual environment. This is typically the Python executable
+/// from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
+/// the base Python executable is the Python executable of the interpreter's base interpreter.
+fn base_python_executable(interpreter: &Interpreter) -> io::Result<PathBuf> {
+    Ok(if cfg!(unix) {
+        // On Unix, follow symlinks to resolve the base interpreter, since the Python executable in
+        // a virtual environment is a symlink to the base interpreter.
+        uv_fs::canonicalize_executable(interpreter.sys_executable())?
+    } else if cfg!(windows) {
+        // On Windows, follow `virtualenv`. If we're in a virtual environment, use
+        // `sys._base_executable` if it exists; if not, use `sys.base_prefix`. For example, with
+        // Python installed from the Windows Store, `sys.base_prefix` is slightly "incorrect".
+        //
+        // If we're _not_ in a virtual environment, use the interpreter's executable, since it's
+        // already a "system Python". We canonicalize the path to ensure that it's real and
+        // consistent, though we don't expect any symlinks on Windows.
+        if interpreter.is_virtualenv() {
+            if let Some(base_executable) = interpreter.sys_base_executable() {
+                base_executable.to_path_buf()
+            } else {
+                // Assume `python.exe`, though the exact executable name is never used (below) on
+                // Windows, only its parent directory.
+                interpreter.sys_base_prefix().join("python.exe")
+            }
+        } else {
+            uv_fs::canonicalize_executable(interpreter.sys_executable())?
+        }
+    } else {
+        unimplemented!("Only Windows and Unix are supported")
+    })
+}
+
+/// Per PEP 405, the Python `home` is the parent directory of the base interpreter.
+fn python_home(base_python: &Path) -> io::Result<&Path> {
+    base_python.parent().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "The Python interpreter needs to have a parent directory",
+        )
+    })
+}
+
+/// Link (or, on Windows, copy) the Python executables for the base interpreter into the
+/// `scripts` directory of a virtual environment, returning the path to the `python` executable.
+fn link_executables(
+    interpreter: &Interpreter,
+    base_python: &Path,
+    scripts: &Path,
+    python_home: &Path,
+) -> Result<PathBuf, Error> {
+    let executable = scripts.join(format!("python{EXE_SUFFIX}"));
+
+    #[cfg(unix)]
+    {
+        uv_fs::replace_symlink(base_python, &executable)?;
+        uv_fs::replace_symlink(
+            "python",
+            scripts.join(format!("python{}", interpreter.python_major())),
+        )?;
+        uv_fs::replace_symlink(
+            "python",
+            scripts.join(format!(
+                "python{}.{}",
+                interpreter.python_major(),
+                interpreter.python_minor(),
+            )),
+        )?;
+    }
+
+    // No symlinking on Windows, at least not on a regular non-dev non-admin Windows install.
+    if cfg!(windows) {
+        copy_launcher_windows(
+            WindowsExecutable::Python,
+            interpreter,
+            base_python,
+            scripts,
+            python_home,
+        )?;
+        copy_launcher_windows(
+            WindowsExecutable::Pythonw,
+            interpreter,
+            base_python,
+            scripts,
+            python_home,
+        )?;
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        compile_error!("Only Windows and Unix are supported")
+    }
+
+    Ok(executable)
+}
+
+/// Upgrade the virtual environment at the given location in-place to use the given interpreter,
+/// which must be a different patch release of the same minor Python version (e.g., after the
+/// base Python installation was upgraded).
+///
+/// Rewrites the `home` and `version_info` (and, if present, `version`) keys in `pyvenv.cfg` and the
+/// Python executables in the `scripts` directory, preserving all other files (including installed
+/// packages).
+pub fn upgrade_bare_venv(
+    location: &Path,
+    interpreter: &Interpreter,
+) -> Result<VirtualEnvironment, Error> {
+    let location = location.canonicalize()?;
+
+    // Read the existing `pyvenv.cfg`.
+    let cfg_path = location.join("pyvenv.cfg");
+    if !cfg_path.is_file() {
+        return Err(Error::NotAVirtualenv(location));
+    }
+    let cfg = PyVenvConfiguration::parse(&cfg_path)?;
+
+    // Verify that the interpreter is compatible with the existing environment.
+    let Some(version_info) = cfg.version_info() else {
+        return Err(Error::MissingVersionInfo(location));
+    };
+    if cfg.python_tuple() != Some(interpreter.python_tuple()) {
+        return Err(Error::IncompatibleUpgrade {
+            location,
+            from: version_info.to_string(),
+            to: interpreter.python_version().to_string(),
+        });
+    }
+
+    let base_python = base_python_executable(interpreter)?;
+    let python_home = python_home(&base_python)?;
+
+    // Replace the Python executables.
+    let scripts = location.join(&interpreter.virtualenv().scripts);
+    fs::create_dir_all(&scripts)?;
+    let executable = link_executables(interpreter, &base_python, &scripts, python_home)?;
+
+    // Repoint `pyvenv.cfg` at the new interpreter, retaining any other keys.
+    let mut pyvenv_cfg_data = read_cfg(&cfg_path)?;
+    // The standard library's `venv` module records the version as `version`.
+    if let Some((_, existing)) = pyvenv_cfg_data.iter_mut().find(|(k, _)| k == "version") {
+        existing.clone_from(&interpreter.markers().python_full_version().string);
+    }
+    for (key, value) in [
+        ("home", python_home.simplified_display().to_string()),
+        (
+            "version_info",
+            interpreter.markers().python_full_version().string.clone(),
+        ),
+        ("uv", version().to_string()),
+    ] {
+        if let Some((_, existing)) = pyvenv_cfg_data.iter_mut().find(|(k, _)| k == key) {
+            *existing = value;
+        } else {
+            pyvenv_cfg_data.push((key.to_string(), value));
+        }
+    }
+
+    let mut pyvenv_cfg = BufWriter::new(File::create(&cfg_path)?);
+    write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
+    drop(pyvenv_cfg);
+
+    Ok(VirtualEnvironment {
+        scheme: Scheme {
+            purelib: location.join(&interpreter.virtualenv().purelib),
+            platlib: location.join(&interpreter.virtualenv().platlib),
+            scripts: location.join(&interpreter.virtualenv().scripts),
+            data: location.join(&interpreter.virtualenv().data),
+            include: location.join(&interpreter.virtualenv().include),
+        },
+        root: location,
+        executable,
+    })
+}
+
 #[derive(Debug, Copy, Clone)]
 enum WindowsExecutable {
     /// The `python.exe` executable (or `venvlauncher.exe` launcher shim).
@@ -1,12 +1,13 @@
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use thiserror::Error;
 
 use platform_tags::PlatformError;
+use uv_fs::Simplified;
 use uv_toolchain::{Interpreter, PythonEnvironment};
 
-pub use crate::bare::create_bare_venv;
+pub use crate::bare::{create_bare_venv, upgrade_bare_venv};
 
 mod bare;
 
@@ -22,6 +23,18 @@ pub enum Error {
     Platform(#[from] PlatformError),
     #[error("Could not find a suitable Python executable for the virtual environment based on the interpreter: {0}")]
     NotFound(String),
+    #[error(transparent)]
+    VirtualEnv(#[from] uv_toolchain::VirtualEnvError),
+    #[error("The directory `{}` is not a virtual environment (missing `pyvenv.cfg`)", .0.user_display())]
+    NotAVirtualenv(PathBuf),
+    #[error("Unable to determine the Python version of the virtual environment at `{}` (missing `version_info` or `version` in `pyvenv.cfg`)", .0.user_display())]
+    MissingVersionInfo(PathBuf),
+    #[error("Can't upgrade the virtual environment at `{}` from Python {from} to Python {to}; only upgrades to a patch release of the same minor version are supported", location.user_display())]
+    IncompatibleUpgrade {
+        location: PathBuf,
+        from: String,
+        to: String,
+    },
 }
 
 /// The value to use for the shell prompt when inside a virtual environment.
@@ -68,3 +81,14 @@ pub fn create_venv(
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// Upgrade the virtualenv at the given location in-place to use the given interpreter, which must
+/// be a patch release of the environment's minor Python version.
+pub fn upgrade_venv(location: &Path, interpreter: Interpreter) -> Result<PythonEnvironment, Error> {
+    // Repoint the virtualenv at the new interpreter.
+    let virtualenv = upgrade_bare_venv(location, &interpreter)?;
+
+    // Create the corresponding `PythonEnvironment`.
+    let interpreter = interpreter.with_virtualenv(virtualenv);
+    Ok(PythonEnvironment::from_interpreter(interpreter))
+}
@@ -3,8 +3,13 @@ use std::path::PathBuf;
 
 use distribution_types::IndexUrl;
 use install_wheel_rs::linker::LinkMode;
-use uv_configuration::{ConfigSettings, IndexStrategy, KeyringProviderType, TargetTriple};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_configuration::{
+    ConfigSettings, FileConflictStrategy, IndexStrategy, KeyringProviderType, TargetTriple,
+};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewerPackage, ExcludeNewerSpan, ExcludeNewerTimestamp,
+    PackageOverrides, PreReleaseMode, ResolutionMode,
+};
 use uv_toolchain::PythonVersion;
 
 use crate::{Options, PipOptions, Workspace};
@@ -50,6 +55,9 @@ impl Combine for Options {
             override_dependencies: self
                 .override_dependencies
                 .combine(other.override_dependencies),
+            constraint_dependencies: self
+                .constraint_dependencies
+                .combine(other.constraint_dependencies),
         }
     }
 }
@@ -73,6 +81,8 @@ impl Combine for PipOptions {
                 .combine(other.break_system_packages),
             target: self.target.combine(other.target),
             prefix: self.prefix.combine(other.prefix),
+            user: self.user.combine(other.user),
+            root: self.root.combine(other.root),
             index_url: self.index_url.combine(other.index_url),
             extra_index_url: self.extra_index_url.combine(other.extra_index_url),
             no_index: self.no_index.combine(other.no_index),
@@ -88,7 +98,9 @@ impl Combine for PipOptions {
             all_extras: self.all_extras.combine(other.all_extras),
             no_deps: self.no_deps.combine(other.no_deps),
             resolution: self.resolution.combine(other.resolution),
+            resolution_package: self.resolution_package.combine(other.resolution_package),
             prerelease: self.prerelease.combine(other.prerelease),
+            prerelease_package: self.prerelease_package.combine(other.prerelease_package),
             output_file: self.output_file.combine(other.output_file),
             no_strip_extras: self.no_strip_extras.combine(other.no_strip_extras),
             no_annotate: self.no_annotate.combine(other.no_annotate),
@@ -102,6 +114,12 @@ impl Combine for PipOptions {
             python_version: self.python_version.combine(other.python_version),
             python_platform: self.python_platform.combine(other.python_platform),
             exclude_newer: self.exclude_newer.combine(other.exclude_newer),
+            exclude_newer_cooldown: self
+                .exclude_newer_cooldown
+                .combine(other.exclude_newer_cooldown),
+            exclude_newer_package: self
+                .exclude_newer_package
+                .combine(other.exclude_newer_package),
             no_emit_package: self.no_emit_package.combine(other.no_emit_package),
             emit_index_url: self.emit_index_url.combine(other.emit_index_url),
             emit_find_links: self.emit_find_links.combine(other.emit_find_links),
@@ -113,7 +131,11 @@ impl Combine for PipOptions {
                 .combine(other.emit_index_annotation),
             annotation_style: self.annotation_style.combine(other.annotation_style),
             link_mode: self.link_mode.combine(other.link_mode),
+            on_conflict: self.on_conflict.combine(other.on_conflict),
             compile_bytecode: self.compile_bytecode.combine(other.compile_bytecode),
+            compile_optimization: self
+                .compile_optimization
+                .combine(other.compile_optimization),
             require_hashes: self.require_hashes.combine(other.require_hashes),
             concurrent_downloads: self
                 .concurrent_downloads
@@ -135,7 +157,9 @@ macro_rules! impl_combine_or {
 }
 
 impl_combine_or!(AnnotationStyle);
-impl_combine_or!(ExcludeNewer);
+impl_combine_or!(ExcludeNewerSpan);
+impl_combine_or!(ExcludeNewerTimestamp);
+impl_combine_or!(FileConflictStrategy);
 impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
@@ -163,6 +187,28 @@ impl<T> Combine for Option<Vec<T>> {
     }
 }
 
+impl Combine for Option<ExcludeNewerPackage> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Option<ExcludeNewerPackage>) -> Option<ExcludeNewerPackage> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl<T> Combine for Option<PackageOverrides<T>> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Option<PackageOverrides<T>>) -> Option<PackageOverrides<T>> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ConfigSettings> {
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
@@ -6,10 +6,14 @@ use distribution_types::{FlatIndexLocation, IndexUrl};
 use install_wheel_rs::linker::LinkMode;
 use pypi_types::VerbatimParsedUrl;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
+    BytecodeOptimization, ConfigSettings, FileConflictStrategy, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, TargetTriple,
 };
 use uv_normalize::{ExtraName, PackageName};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewerPackage, ExcludeNewerSpan, ExcludeNewerTimestamp,
+    PackageOverrides, PreReleaseMode, ResolutionMode,
+};
 use uv_toolchain::PythonVersion;
 
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
@@ -46,6 +50,14 @@ pub struct Options {
         )
     )]
     pub override_dependencies: Option<Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "PEP 508 style requirements, e.g. `flask==3.0.0`, or `black @ https://...`."
+        )
+    )]
+    pub constraint_dependencies: Option<Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
 }
 
 /// A `[tool.uv.pip]` section.
@@ -59,6 +71,8 @@ pub struct PipOptions {
     pub break_system_packages: Option<bool>,
     pub target: Option<PathBuf>,
     pub prefix: Option<PathBuf>,
+    pub user: Option<bool>,
+    pub root: Option<PathBuf>,
     pub index_url: Option<IndexUrl>,
     pub extra_index_url: Option<Vec<IndexUrl>>,
     pub no_index: Option<bool>,
@@ -74,7 +88,9 @@ pub struct PipOptions {
     pub all_extras: Option<bool>,
     pub no_deps: Option<bool>,
     pub resolution: Option<ResolutionMode>,
+    pub resolution_package: Option<PackageOverrides<ResolutionMode>>,
     pub prerelease: Option<PreReleaseMode>,
+    pub prerelease_package: Option<PackageOverrides<PreReleaseMode>>,
     pub output_file: Option<PathBuf>,
     pub no_strip_extras: Option<bool>,
     pub no_annotate: Option<bool>,
@@ -85,7 +101,9 @@ pub struct PipOptions {
     pub config_settings: Option<ConfigSettings>,
     pub python_version: Option<PythonVersion>,
     pub python_platform: Option<TargetTriple>,
-    pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer: Option<ExcludeNewerTimestamp>,
+    pub exclude_newer_cooldown: Option<ExcludeNewerSpan>,
+    pub exclude_newer_package: Option<ExcludeNewerPackage>,
     pub no_emit_package: Option<Vec<PackageName>>,
     pub emit_index_url: Option<bool>,
     pub emit_find_links: Option<bool>,
@@ -93,7 +111,9 @@ pub struct PipOptions {
     pub emit_index_annotation: Option<bool>,
     pub annotation_style: Option<AnnotationStyle>,
     pub link_mode: Option<LinkMode>,
+    pub on_conflict: Option<FileConflictStrategy>,
     pub compile_bytecode: Option<bool>,
+    pub compile_optimization: Option<Vec<BytecodeOptimization>>,
     pub require_hashes: Option<bool>,
     pub concurrent_downloads: Option<NonZeroUsize>,
     pub concurrent_builds: Option<NonZeroUsize>,
@@ -4,18 +4,22 @@ use std::str::FromStr;
 
 use anyhow::Result;
 
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use distribution_types::{FlatIndexLocation, IndexUrl};
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
+    BytecodeOptimization, ConfigSettingEntry, FileConflictStrategy, IndexStrategy,
+    KeyringProviderType, PackageNameSpecifier, TargetTriple,
+};
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerSpan, ExcludeNewerTimestamp,
+    PreReleaseMode, ResolutionMode,
 };
-use uv_normalize::{ExtraName, PackageName};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
 use uv_toolchain::PythonVersion;
 
-use crate::commands::{extra_name_with_clap_error, ListFormat, VersionFormat};
+use crate::commands::{extra_name_with_clap_error, ErrorFormat, ListFormat, VersionFormat};
 use crate::compat;
 
 #[derive(Parser)]
@@ -66,6 +70,20 @@ pub(crate) struct GlobalArgs {
     )]
     pub(crate) color: ColorChoice,
 
+    /// The format in which to report resolution failures.
+    ///
+    /// With `json`, resolution failures are written to stdout as a JSON document, including the
+    /// incompatibilities that make up the failure's derivation tree, any hints, and the resolver
+    /// forks in which the failure occurred.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        default_value = "text",
+        value_name = "ERROR_FORMAT"
+    )]
+    pub(crate) error_format: ErrorFormat,
+
     /// Whether to load TLS certificates from the platform's native certificate store.
     ///
     /// By default, `uv` loads certificates from the bundled `webpki-roots` crate. The
@@ -82,6 +100,9 @@ pub(crate) struct GlobalArgs {
     pub(crate) no_native_tls: bool,
 
     /// Disable network access, relying only on locally cached data and locally available files.
+    ///
+    /// When installing, any wheels that were previously downloaded or built are treated as
+    /// available, even if the corresponding index responses are missing from the cache.
     #[arg(global = true, long, overrides_with("no_offline"))]
     pub(crate) offline: bool,
 
@@ -101,7 +122,7 @@ pub(crate) struct GlobalArgs {
     pub(crate) isolated: bool,
 }
 
-#[derive(Debug, Clone, clap::ValueEnum)]
+#[derive(Debug, Copy, Clone, clap::ValueEnum)]
 pub(crate) enum ColorChoice {
     /// Enables colored output only when the output is going to a terminal or TTY with support.
     Auto,
@@ -218,6 +239,9 @@ pub(crate) enum PipCommand {
     Show(PipShowArgs),
     /// Verify installed packages have compatible dependencies.
     Check(PipCheckArgs),
+    /// Explain why a package was included in a resolution, and why it was resolved to the
+    /// selected version.
+    Why(PipWhyArgs),
 }
 
 #[derive(Subcommand)]
@@ -295,6 +319,17 @@ fn parse_maybe_file_path(input: &str) -> Result<Maybe<PathBuf>, String> {
     }
 }
 
+/// Parse a `PACKAGE=MODE` entry into a package name and a value of the given [`ValueEnum`], as in
+/// `--resolution-package anyio=lowest`.
+fn parse_package_override<T: ValueEnum>(input: &str) -> Result<(PackageName, T), String> {
+    let Some((package, value)) = input.split_once('=') else {
+        return Err(format!("expected `PACKAGE=MODE`, found: `{input}`"));
+    };
+    let package = PackageName::from_str(package.trim()).map_err(|err| err.to_string())?;
+    let value = T::from_str(value.trim(), false)?;
+    Ok((package, value))
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub(crate) struct PipCompileArgs {
@@ -357,6 +392,13 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, value_enum, env = "UV_RESOLUTION")]
     pub(crate) resolution: Option<ResolutionMode>,
 
+    /// Override the resolution strategy for a specific package, specified as `PACKAGE=MODE`
+    /// (e.g., `--resolution-package anyio=lowest`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = parse_package_override::<ResolutionMode>)]
+    pub(crate) resolution_package: Option<Vec<(PackageName, ResolutionMode)>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, `uv` will accept pre-releases for packages that _only_ publish pre-releases,
@@ -365,6 +407,13 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, value_enum, env = "UV_PRERELEASE")]
     pub(crate) prerelease: Option<PreReleaseMode>,
 
+    /// Override the pre-release strategy for a specific package, specified as `PACKAGE=MODE`
+    /// (e.g., `--prerelease-package my-sdk=allow`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = parse_package_override::<PreReleaseMode>)]
+    pub(crate) prerelease_package: Option<Vec<(PackageName, PreReleaseMode)>>,
+
     #[arg(long, hide = true)]
     pub(crate) pre: bool,
 
@@ -595,7 +644,21 @@ pub(crate) struct PipCompileArgs {
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
     /// format (e.g., `2006-12-02`).
     #[arg(long, env = "UV_EXCLUDE_NEWER")]
-    pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer: Option<ExcludeNewerTimestamp>,
+
+    /// Limit candidate packages to those that were uploaded at least the given span of time ago.
+    ///
+    /// Accepts an integer followed by a unit of weeks, days, hours, or minutes (e.g., `7 days` or
+    /// `12h`). If `--exclude-newer` is also provided, the earlier of the two cutoffs applies.
+    #[arg(long, env = "UV_EXCLUDE_NEWER_COOLDOWN")]
+    pub(crate) exclude_newer_cooldown: Option<ExcludeNewerSpan>,
+
+    /// Override the upload date cutoff for a specific package, specified as `PACKAGE=DATE`, or
+    /// exempt the package from the cutoff entirely with `PACKAGE=false`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub(crate) exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
@@ -640,6 +703,19 @@ pub(crate) struct PipCompileArgs {
     pub(crate) compat_args: compat::PipCompileCompatArgs,
 }
 
+#[derive(Args)]
+pub(crate) struct PipWhyArgs {
+    /// The package to explain.
+    pub(crate) package: PackageName,
+
+    /// Resolve the requirements as `uv pip compile` would, accepting the same arguments.
+    ///
+    /// If an `--output-file` is provided, it's used as a source of preferences, but is not
+    /// modified.
+    #[command(flatten)]
+    pub(crate) compile: PipCompileArgs,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub(crate) struct PipSyncArgs {
@@ -696,6 +772,14 @@ pub(crate) struct PipSyncArgs {
     #[arg(long, value_enum, env = "UV_LINK_MODE")]
     pub(crate) link_mode: Option<install_wheel_rs::linker::LinkMode>,
 
+    /// The strategy to use when an installed package would overwrite files that belong to another
+    /// installed package, like `opencv-python` and `opencv-python-headless`, which both provide
+    /// the `cv2` module.
+    ///
+    /// By default, uv overwrites the files and warns about the conflict (`warn`).
+    #[arg(long, value_enum, env = "UV_ON_CONFLICT")]
+    pub(crate) on_conflict: Option<FileConflictStrategy>,
+
     #[command(flatten)]
     pub(crate) index_args: IndexArgs,
 
@@ -876,8 +960,8 @@ pub(crate) struct PipSyncArgs {
     /// first start time matters, such as CLI applications and docker containers, this option can
     /// trade longer install time for faster startup.
     ///
-    /// The compile option will process the entire site-packages directory for consistency and
-    /// (like pip) ignore all errors.
+    /// The compile option will process the files of all newly installed packages, recording the
+    /// generated bytecode in each package's `RECORD`, and (like pip) ignore all errors.
     #[arg(long, alias = "compile", overrides_with("no_compile_bytecode"))]
     pub(crate) compile_bytecode: bool,
 
@@ -889,6 +973,15 @@ pub(crate) struct PipSyncArgs {
     )]
     pub(crate) no_compile_bytecode: bool,
 
+    /// The optimization level(s) at which to compile Python files to bytecode, when compiling
+    /// with `--compile-bytecode`.
+    ///
+    /// Python only loads bytecode compiled at its own optimization level, so use `1` for
+    /// environments that run with `python -O` and `2` for `python -OO`. May be provided multiple
+    /// times to compile at several levels. Defaults to `0`.
+    #[arg(long, value_enum)]
+    pub(crate) compile_optimization: Option<Vec<BytecodeOptimization>>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(long, short = 'C', alias = "config-settings")]
     pub(crate) config_setting: Option<Vec<ConfigSettingEntry>>,
@@ -928,7 +1021,21 @@ pub(crate) struct PipSyncArgs {
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
     /// format (e.g., `2006-12-02`).
     #[arg(long, env = "UV_EXCLUDE_NEWER")]
-    pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer: Option<ExcludeNewerTimestamp>,
+
+    /// Limit candidate packages to those that were uploaded at least the given span of time ago.
+    ///
+    /// Accepts an integer followed by a unit of weeks, days, hours, or minutes (e.g., `7 days` or
+    /// `12h`). If `--exclude-newer` is also provided, the earlier of the two cutoffs applies.
+    #[arg(long, env = "UV_EXCLUDE_NEWER_COOLDOWN")]
+    pub(crate) exclude_newer_cooldown: Option<ExcludeNewerSpan>,
+
+    /// Override the upload date cutoff for a specific package, specified as `PACKAGE=DATE`, or
+    /// exempt the package from the cutoff entirely with `PACKAGE=false`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub(crate) exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
@@ -995,6 +1102,11 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, overrides_with("all_extras"), hide = true)]
     pub(crate) no_all_extras: bool,
 
+    /// Include dependencies from the specified dependency group; may be provided more than once.
+    /// Only applies to `pyproject.toml` sources.
+    #[arg(long)]
+    pub(crate) group: Option<Vec<GroupName>>,
+
     /// Allow package upgrades.
     #[arg(long, short = 'U', overrides_with("no_upgrade"))]
     pub(crate) upgrade: bool,
@@ -1048,6 +1160,14 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, value_enum, env = "UV_LINK_MODE")]
     pub(crate) link_mode: Option<install_wheel_rs::linker::LinkMode>,
 
+    /// The strategy to use when an installed package would overwrite files that belong to another
+    /// installed package, like `opencv-python` and `opencv-python-headless`, which both provide
+    /// the `cv2` module.
+    ///
+    /// By default, uv overwrites the files and warns about the conflict (`warn`).
+    #[arg(long, value_enum, env = "UV_ON_CONFLICT")]
+    pub(crate) on_conflict: Option<FileConflictStrategy>,
+
     /// The strategy to use when selecting between the different compatible versions for a given
     /// package requirement.
     ///
@@ -1055,6 +1175,13 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, value_enum, env = "UV_RESOLUTION")]
     pub(crate) resolution: Option<ResolutionMode>,
 
+    /// Override the resolution strategy for a specific package, specified as `PACKAGE=MODE`
+    /// (e.g., `--resolution-package anyio=lowest`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = parse_package_override::<ResolutionMode>)]
+    pub(crate) resolution_package: Option<Vec<(PackageName, ResolutionMode)>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, `uv` will accept pre-releases for packages that _only_ publish pre-releases,
@@ -1063,6 +1190,13 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, value_enum, env = "UV_PRERELEASE")]
     pub(crate) prerelease: Option<PreReleaseMode>,
 
+    /// Override the pre-release strategy for a specific package, specified as `PACKAGE=MODE`
+    /// (e.g., `--prerelease-package my-sdk=allow`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = parse_package_override::<PreReleaseMode>)]
+    pub(crate) prerelease_package: Option<Vec<(PackageName, PreReleaseMode)>>,
+
     #[arg(long, hide = true)]
     pub(crate) pre: bool,
 
@@ -1173,9 +1307,35 @@ pub(crate) struct PipInstallArgs {
     /// scripts and other artifacts installed via `--prefix` will reference the installing
     /// interpreter, rather than any interpreter added to the `--prefix` directory, rendering them
     /// non-portable.
-    #[arg(long, conflicts_with = "target")]
+    #[arg(long, conflicts_with_all = ["target", "user"])]
     pub(crate) prefix: Option<PathBuf>,
 
+    /// Install packages into the user site-packages directory, as determined by `site.USER_BASE`
+    /// (typically `~/.local` on Unix), rather than into the system Python interpreter.
+    ///
+    /// Uses the `user` installation scheme of the Python interpreter. Can't be used to install into
+    /// a virtual environment, as user site-packages are not visible from within virtual
+    /// environments.
+    #[arg(
+        long,
+        conflicts_with_all = ["target", "prefix"],
+        overrides_with("no_user")
+    )]
+    pub(crate) user: bool,
+
+    #[arg(long, overrides_with("user"), hide = true)]
+    pub(crate) no_user: bool,
+
+    /// Install packages relative to the specified alternate root directory, e.g., to stage an
+    /// installation for packaging.
+    ///
+    /// All installation paths (including `site-packages`, scripts, and data files) are re-rooted
+    /// under the specified directory, such that installing into `/usr/lib/python3.12` with
+    /// `--root /tmp/staging` writes to `/tmp/staging/usr/lib/python3.12`. Scripts will continue to
+    /// reference the installing interpreter.
+    #[arg(long, conflicts_with = "target")]
+    pub(crate) root: Option<PathBuf>,
+
     /// Use legacy `setuptools` behavior when building source distributions without a
     /// `pyproject.toml`.
     #[arg(long, overrides_with("no_legacy_setup_py"))]
@@ -1250,8 +1410,8 @@ pub(crate) struct PipInstallArgs {
     /// first start time matters, such as CLI applications and docker containers, this option can
     /// trade longer install time for faster startup.
     ///
-    /// The compile option will process the entire site-packages directory for consistency and
-    /// (like pip) ignore all errors.
+    /// The compile option will process the files of all newly installed packages, recording the
+    /// generated bytecode in each package's `RECORD`, and (like pip) ignore all errors.
     #[arg(long, alias = "compile", overrides_with("no_compile_bytecode"))]
     pub(crate) compile_bytecode: bool,
 
@@ -1263,6 +1423,15 @@ pub(crate) struct PipInstallArgs {
     )]
     pub(crate) no_compile_bytecode: bool,
 
+    /// The optimization level(s) at which to compile Python files to bytecode, when compiling
+    /// with `--compile-bytecode`.
+    ///
+    /// Python only loads bytecode compiled at its own optimization level, so use `1` for
+    /// environments that run with `python -O` and `2` for `python -OO`. May be provided multiple
+    /// times to compile at several levels. Defaults to `0`.
+    #[arg(long, value_enum)]
+    pub(crate) compile_optimization: Option<Vec<BytecodeOptimization>>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(long, short = 'C', alias = "config-settings")]
     pub(crate) config_setting: Option<Vec<ConfigSettingEntry>>,
@@ -1302,15 +1471,26 @@ pub(crate) struct PipInstallArgs {
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
     /// format (e.g., `2006-12-02`).
     #[arg(long, env = "UV_EXCLUDE_NEWER")]
-    pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer: Option<ExcludeNewerTimestamp>,
+
+    /// Limit candidate packages to those that were uploaded at least the given span of time ago.
+    ///
+    /// Accepts an integer followed by a unit of weeks, days, hours, or minutes (e.g., `7 days` or
+    /// `12h`). If `--exclude-newer` is also provided, the earlier of the two cutoffs applies.
+    #[arg(long, env = "UV_EXCLUDE_NEWER_COOLDOWN")]
+    pub(crate) exclude_newer_cooldown: Option<ExcludeNewerSpan>,
+
+    /// Override the upload date cutoff for a specific package, specified as `PACKAGE=DATE`, or
+    /// exempt the package from the cutoff entirely with `PACKAGE=false`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub(crate) exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
     #[arg(long)]
     pub(crate) dry_run: bool,
-
-    #[command(flatten)]
-    pub(crate) compat_args: compat::PipInstallCompatArgs,
 }
 
 #[derive(Args)]
@@ -1647,6 +1827,31 @@ pub(crate) struct VenvArgs {
     #[clap(long)]
     pub(crate) allow_existing: bool,
 
+    /// Clear the contents of an existing virtual environment at the target path, rather than
+    /// replacing the directory itself.
+    ///
+    /// By default, `uv venv` removes an existing virtual environment and recreates its directory.
+    /// The `--clear` option instead removes the contents of the directory and creates the virtual
+    /// environment within it, preserving the directory itself (e.g., a mount point, or a symlink to
+    /// a virtual environment elsewhere). As with the default, `uv venv` will exit with an error if
+    /// the path is non-empty but _not_ a virtual environment.
+    #[clap(long, conflicts_with = "allow_existing")]
+    pub(crate) clear: bool,
+
+    /// Upgrade an existing virtual environment in-place to use a newer patch release of its Python
+    /// version, e.g., after the base Python installation was upgraded.
+    ///
+    /// Repoints the `home` in `pyvenv.cfg` and the Python executables in the virtual environment at
+    /// the requested interpreter, which must share the major and minor version of the existing
+    /// environment. If no interpreter is requested, uses the environment's minor version (e.g.,
+    /// `3.12`). Installed packages are preserved; any that are incompatible with the new
+    /// interpreter are reported.
+    #[clap(
+        long,
+        conflicts_with_all = ["allow_existing", "clear", "seed", "prompt", "system_site_packages"]
+    )]
+    pub(crate) upgrade: bool,
+
     /// The path to the virtual environment to create.
     #[arg(default_value = ".venv")]
     pub(crate) name: PathBuf,
@@ -1739,7 +1944,7 @@ pub(crate) struct VenvArgs {
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
     /// format (e.g., `2006-12-02`).
     #[arg(long, env = "UV_EXCLUDE_NEWER")]
-    pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer: Option<ExcludeNewerTimestamp>,
 
     #[command(flatten)]
     pub(crate) compat_args: compat::VenvCompatArgs,
@@ -1769,6 +1974,23 @@ pub(crate) struct RunArgs {
     #[arg(long, overrides_with("dev"))]
     pub(crate) no_dev: bool,
 
+    /// Include dependencies from the specified dependency group; may be provided more than once.
+    ///
+    /// Groups are read from the `dependency-groups` table in the project's `pyproject.toml`.
+    #[arg(long, conflicts_with = "only_group")]
+    pub(crate) group: Option<Vec<GroupName>>,
+
+    /// Exclude dependencies from the specified dependency group; may be provided more than once.
+    #[arg(long)]
+    pub(crate) no_group: Option<Vec<GroupName>>,
+
+    /// Only include dependencies from the specified dependency group; may be provided more than
+    /// once.
+    ///
+    /// The project itself, and its dependencies, will not be installed.
+    #[arg(long, conflicts_with = "group")]
+    pub(crate) only_group: Option<Vec<GroupName>>,
+
     /// The command to run.
     pub(crate) target: Option<String>,
 
@@ -1829,11 +2051,19 @@ pub(crate) struct RunArgs {
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
     /// format (e.g., `2006-12-02`).
     #[arg(long, env = "UV_EXCLUDE_NEWER")]
-    pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer: Option<ExcludeNewerTimestamp>,
 
     /// Run the command in a different package in the workspace.
     #[arg(long, conflicts_with = "isolated")]
     pub(crate) package: Option<PackageName>,
+
+    /// Run the command in every package in the workspace.
+    ///
+    /// All workspace members are installed into the workspace environment, and the command is run
+    /// from each member's directory in turn. The exit code of the command is reported for each
+    /// member.
+    #[arg(long, conflicts_with_all = ["package", "isolated"])]
+    pub(crate) all_packages: bool,
 }
 
 #[derive(Args)]
@@ -1860,6 +2090,35 @@ pub(crate) struct SyncArgs {
     #[arg(long, overrides_with("dev"))]
     pub(crate) no_dev: bool,
 
+    /// Include dependencies from the specified dependency group; may be provided more than once.
+    ///
+    /// Groups are read from the `dependency-groups` table in the project's `pyproject.toml`.
+    #[arg(long, conflicts_with = "only_group")]
+    pub(crate) group: Option<Vec<GroupName>>,
+
+    /// Exclude dependencies from the specified dependency group; may be provided more than once.
+    #[arg(long)]
+    pub(crate) no_group: Option<Vec<GroupName>>,
+
+    /// Only include dependencies from the specified dependency group; may be provided more than
+    /// once.
+    ///
+    /// The project itself, and its dependencies, will not be installed.
+    #[arg(long, conflicts_with = "group")]
+    pub(crate) only_group: Option<Vec<GroupName>>,
+
+    /// Do not remove extraneous packages.
+    ///
+    /// By default, `uv sync` makes the environment exactly match the lockfile, removing any
+    /// packages that are not included in the lockfile. With `--inexact`, extraneous packages are
+    /// retained.
+    #[arg(long, overrides_with("exact"))]
+    pub(crate) inexact: bool,
+
+    /// Remove any extraneous packages, such that the environment exactly matches the lockfile.
+    #[arg(long, overrides_with("inexact"), hide = true)]
+    pub(crate) exact: bool,
+
     /// Refresh all cached data.
     #[arg(long, conflicts_with("offline"), overrides_with("no_refresh"))]
     pub(crate) refresh: bool,
@@ -1892,6 +2151,19 @@ pub(crate) struct SyncArgs {
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
     pub(crate) python: Option<String>,
+
+    /// Sync the given package in the workspace; may be provided more than once.
+    ///
+    /// The given workspace members, and their dependencies, are installed into the workspace
+    /// environment, rather than the current project.
+    #[arg(long, conflicts_with = "all_packages")]
+    pub(crate) package: Vec<PackageName>,
+
+    /// Sync all packages in the workspace.
+    ///
+    /// Every workspace member, and its dependencies, is installed into the workspace environment.
+    #[arg(long, conflicts_with = "package")]
+    pub(crate) all_packages: bool,
 }
 
 #[derive(Args)]
@@ -1924,6 +2196,15 @@ pub(crate) struct LockArgs {
     #[arg(long, short = 'P')]
     pub(crate) upgrade_package: Vec<PackageName>,
 
+    /// Import pinned versions from an existing `requirements.txt`, `poetry.lock`, or `pdm.lock`
+    /// file.
+    ///
+    /// The pinned versions are preferred when resolving (e.g., when migrating a project to `uv`,
+    /// such that the initial lockfile matches the versions that are already in use), but are
+    /// discarded if they conflict with the project requirements.
+    #[arg(long, value_parser = parse_file_path)]
+    pub(crate) from: Option<PathBuf>,
+
     #[command(flatten)]
     pub(crate) index_args: IndexArgs,
 
@@ -1946,7 +2227,63 @@ pub(crate) struct LockArgs {
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
     /// format (e.g., `2006-12-02`).
     #[arg(long, env = "UV_EXCLUDE_NEWER")]
-    pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer: Option<ExcludeNewerTimestamp>,
+
+    /// Limit candidate packages to those that were uploaded at least the given span of time ago.
+    ///
+    /// Accepts an integer followed by a unit of weeks, days, hours, or minutes (e.g., `7 days` or
+    /// `12h`). If `--exclude-newer` is also provided, the earlier of the two cutoffs applies.
+    #[arg(long, env = "UV_EXCLUDE_NEWER_COOLDOWN")]
+    pub(crate) exclude_newer_cooldown: Option<ExcludeNewerSpan>,
+
+    /// Override the upload date cutoff for a specific package, specified as `PACKAGE=DATE`, or
+    /// exempt the package from the cutoff entirely with `PACKAGE=false`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub(crate) exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
+
+    /// The strategy to use when selecting between the different compatible versions for a given
+    /// package requirement.
+    ///
+    /// By default, `uv` will use the latest compatible version of each package (`highest`).
+    #[arg(long, value_enum, env = "UV_RESOLUTION")]
+    pub(crate) resolution: Option<ResolutionMode>,
+
+    /// Override the resolution strategy for a specific package, specified as `PACKAGE=MODE`
+    /// (e.g., `--resolution-package anyio=lowest`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = parse_package_override::<ResolutionMode>)]
+    pub(crate) resolution_package: Option<Vec<(PackageName, ResolutionMode)>>,
+
+    /// The strategy to use when considering pre-release versions.
+    ///
+    /// By default, `uv` will accept pre-releases for packages that _only_ publish pre-releases,
+    /// along with first-party requirements that contain an explicit pre-release marker in the
+    /// declared specifiers (`if-necessary-or-explicit`).
+    #[arg(long, value_enum, env = "UV_PRERELEASE")]
+    pub(crate) prerelease: Option<PreReleaseMode>,
+
+    /// Override the pre-release strategy for a specific package, specified as `PACKAGE=MODE`
+    /// (e.g., `--prerelease-package my-sdk=allow`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = parse_package_override::<PreReleaseMode>)]
+    pub(crate) prerelease_package: Option<Vec<(PackageName, PreReleaseMode)>>,
+
+    /// Perform a dry run, i.e., resolve the dependencies and print the changes to the lockfile,
+    /// without writing it.
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+
+    /// Validate the existing lockfile against the lockfile format, without performing a
+    /// resolution.
+    ///
+    /// Fails if the lockfile is missing, can't be parsed, or was written by a newer version of
+    /// `uv`.
+    #[arg(long, conflicts_with_all = ["upgrade", "upgrade_package", "from", "dry_run"])]
+    pub(crate) check_format: bool,
 }
 
 #[derive(Args)]
@@ -1970,6 +2307,13 @@ pub(crate) struct AddArgs {
     /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
     #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
     pub(crate) python: Option<String>,
+
+    /// Add the dependencies to the inline metadata of the given Python script, rather than to
+    /// the project.
+    ///
+    /// If the script doesn't contain a PEP 723 `script` metadata block, one will be created.
+    #[arg(long, conflicts_with = "python", value_parser = parse_file_path)]
+    pub(crate) script: Option<PathBuf>,
 }
 
 #[derive(Args)]
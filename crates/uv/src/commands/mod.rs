@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
@@ -13,6 +14,7 @@ pub(crate) use pip::compile::{extra_name_with_clap_error, pip_compile};
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
+pub(crate) use pip::operations::Modifications;
 pub(crate) use pip::show::pip_show;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::uninstall::pip_uninstall;
@@ -27,9 +29,10 @@ pub(crate) use tool::run::run as run_tool;
 pub(crate) use toolchain::install::install as toolchain_install;
 pub(crate) use toolchain::list::list as toolchain_list;
 use uv_cache::Cache;
-use uv_fs::Simplified;
-use uv_installer::compile_tree;
+use uv_configuration::BytecodeOptimization;
+use uv_installer::compile_dists;
 use uv_normalize::PackageName;
+use uv_resolver::{NoSolutionError, ResolveError};
 use uv_toolchain::PythonEnvironment;
 pub(crate) use venv::venv;
 pub(crate) use version::version;
@@ -115,6 +118,15 @@ pub(crate) enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ErrorFormat {
+    /// Display resolution failures as a human-readable report.
+    #[default]
+    Text,
+    /// Display resolution failures in a machine-readable JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub(crate) enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -127,27 +139,68 @@ pub(crate) enum ListFormat {
     Json,
 }
 
-/// Compile all Python source files in site-packages to bytecode, to speed up the
-/// initial run of any subsequent executions.
+/// Report a resolution failure to the user, in the requested [`ErrorFormat`].
+///
+/// In the text format, the report is written to stderr; in the JSON format, it's written to stdout.
+pub(super) fn report_no_solution(
+    err: &NoSolutionError,
+    error_format: ErrorFormat,
+) -> anyhow::Result<()> {
+    match error_format {
+        ErrorFormat::Text => {
+            let report = miette::Report::msg(format!("{err}"))
+                .context("No solution found when resolving dependencies:");
+            anstream::eprint!("{report:?}");
+        }
+        ErrorFormat::Json => {
+            anstream::println!("{}", serde_json::to_string_pretty(&err.report())?);
+        }
+    }
+    Ok(())
+}
+
+/// Report the given error to the user in the requested [`ErrorFormat`] if it's a resolution
+/// failure, returning the [`ExitStatus`] for the command; otherwise, return the error unchanged.
+pub(super) fn report_resolution_failure(
+    err: anyhow::Error,
+    error_format: ErrorFormat,
+) -> anyhow::Result<ExitStatus> {
+    let no_solution = match err.downcast_ref::<project::ProjectError>() {
+        Some(project::ProjectError::Operation(pip::operations::Error::Resolve(
+            ResolveError::NoSolution(err),
+        ))) => Some(err),
+        _ => match err.downcast_ref::<pip::operations::Error>() {
+            Some(pip::operations::Error::Resolve(ResolveError::NoSolution(err))) => Some(err),
+            _ => None,
+        },
+    };
+    let Some(no_solution) = no_solution else {
+        return Err(err);
+    };
+    report_no_solution(no_solution, error_format)?;
+    Ok(ExitStatus::Failure)
+}
+
+/// Compile the Python source files of the given installed distributions to bytecode, to speed up
+/// the initial run of any subsequent executions.
 ///
 /// See the `--compile` option on `pip sync` and `pip install`.
 pub(super) async fn compile_bytecode(
+    dist_infos: &[PathBuf],
+    optimization: &[BytecodeOptimization],
     venv: &PythonEnvironment,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
-    let mut files = 0;
-    for site_packages in venv.site_packages() {
-        files += compile_tree(&site_packages, venv.python_executable(), cache.root())
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to bytecode-compile Python file in: {}",
-                    site_packages.user_display()
-                )
-            })?;
-    }
+    let files = compile_dists(
+        dist_infos,
+        venv.python_executable(),
+        cache.root(),
+        optimization,
+    )
+    .await
+    .context("Failed to bytecode-compile Python files of the installed packages")?;
     let s = if files == 1 { "" } else { "s" };
     writeln!(
         printer.stderr(),
@@ -5,7 +5,7 @@ use std::ops::Deref;
 use std::path::Path;
 use std::str::FromStr;
 
-use anstream::{eprint, AutoStream, StripStream};
+use anstream::{AutoStream, StripStream};
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
@@ -35,9 +35,9 @@ use uv_requirements::{
     RequirementsSource, RequirementsSpecification, SourceTreeResolver,
 };
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, Exclusions, FlatIndex,
-    InMemoryIndex, Manifest, OptionsBuilder, PreReleaseMode, PythonRequirement, ResolutionMode,
-    Resolver,
+    AnnotationStyle, DependencyMode, DisplayResolutionGraph, DisplayWhy, ExcludeNewer, Exclusions,
+    FlatIndex, InMemoryIndex, Manifest, OptionsBuilder, PackageOverrides, PreReleaseMode,
+    PythonRequirement, ResolutionMode, Resolver,
 };
 use uv_toolchain::{
     PythonEnvironment, PythonVersion, SystemPython, Toolchain, ToolchainRequest, VersionRequest,
@@ -47,7 +47,7 @@ use uv_warnings::warn_user;
 
 use crate::commands::pip::{operations, resolution_environment};
 use crate::commands::reporters::ResolverReporter;
-use crate::commands::{elapsed, ExitStatus};
+use crate::commands::{elapsed, report_no_solution, ErrorFormat, ExitStatus};
 use crate::printer::Printer;
 
 /// Resolve a set of requirements into a set of pinned versions.
@@ -59,8 +59,11 @@ pub(crate) async fn pip_compile(
     overrides_from_workspace: Vec<Requirement>,
     extras: ExtrasSpecification,
     output_file: Option<&Path>,
+    why: Option<&PackageName>,
     resolution_mode: ResolutionMode,
+    resolution_package: PackageOverrides<ResolutionMode>,
     prerelease_mode: PreReleaseMode,
+    prerelease_package: PackageOverrides<PreReleaseMode>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
@@ -92,6 +95,7 @@ pub(crate) async fn pip_compile(
     concurrency: Concurrency,
     native_tls: bool,
     quiet: bool,
+    error_format: ErrorFormat,
     preview: PreviewMode,
     cache: Cache,
     printer: Printer,
@@ -295,7 +299,11 @@ pub(crate) async fn pip_compile(
         concurrency,
         preview,
     )
-    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
+    .with_options(
+        OptionsBuilder::new()
+            .exclude_newer(exclude_newer.clone())
+            .build(),
+    );
 
     // Resolve the requirements from the provided sources.
     let requirements = {
@@ -466,7 +474,9 @@ pub(crate) async fn pip_compile(
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_package(resolution_package)
         .prerelease_mode(prerelease_mode)
+        .prerelease_package(prerelease_package)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
@@ -490,9 +500,7 @@ pub(crate) async fn pip_compile(
 
     let resolution = match resolver.resolve().await {
         Err(uv_resolver::ResolveError::NoSolution(err)) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
+            report_no_solution(&err, error_format)?;
             return Ok(ExitStatus::Failure);
         }
         result => result,
@@ -510,6 +518,20 @@ pub(crate) async fn pip_compile(
         .dimmed()
     )?;
 
+    // If we're explaining a package, display the explanation in lieu of the resolution, and leave
+    // the output file untouched.
+    if let Some(package) = why {
+        if !resolution.contains(package) {
+            return Err(anyhow!("`{package}` is not included in the resolution"));
+        }
+        write!(
+            printer.stdout(),
+            "{}",
+            DisplayWhy::new(&resolution, &manifest, &top_level_index, package)
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file)?;
 
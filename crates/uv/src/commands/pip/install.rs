@@ -1,6 +1,5 @@
 use std::fmt::Write;
 
-use anstream::eprint;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::{debug, enabled, Level};
@@ -12,25 +11,30 @@ use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy, NoBinary,
-    NoBuild, PreviewMode, Reinstall, SetupPyStrategy, Upgrade,
+    BuildOptions, BytecodeOptimization, Concurrency, ConfigSettings, ExtrasSpecification,
+    FileConflictStrategy, IndexStrategy, NoBinary, NoBuild, PreviewMode, Reinstall,
+    SetupPyStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
+use uv_distribution::CachedFlatIndex;
 use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_installer::{SatisfiesResult, SitePackages};
+use uv_normalize::GroupName;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, InMemoryIndex, OptionsBuilder, PreReleaseMode,
-    ResolutionMode,
+    DependencyMode, ExcludeNewer, FlatIndex, InMemoryIndex, OptionsBuilder, PackageOverrides,
+    PreReleaseMode, ResolutionMode,
+};
+use uv_toolchain::{
+    Prefix, PythonEnvironment, PythonVersion, Root, SystemPython, Target, Toolchain,
 };
-use uv_toolchain::{Prefix, PythonEnvironment, PythonVersion, SystemPython, Target, Toolchain};
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::{operations, resolution_environment};
-use crate::commands::{elapsed, ExitStatus};
+use crate::commands::{elapsed, report_no_solution, ErrorFormat, ExitStatus};
 use crate::printer::Printer;
 
 /// Install packages into the current environment.
@@ -41,8 +45,11 @@ pub(crate) async fn pip_install(
     overrides: &[RequirementsSource],
     overrides_from_workspace: Vec<Requirement>,
     extras: &ExtrasSpecification,
+    groups: &[GroupName],
     resolution_mode: ResolutionMode,
+    resolution_package: PackageOverrides<ResolutionMode>,
     prerelease_mode: PreReleaseMode,
+    prerelease_package: PackageOverrides<PreReleaseMode>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
@@ -50,7 +57,9 @@ pub(crate) async fn pip_install(
     keyring_provider: KeyringProviderType,
     reinstall: Reinstall,
     link_mode: LinkMode,
+    on_conflict: FileConflictStrategy,
     compile: bool,
+    compile_optimization: &[BytecodeOptimization],
     require_hashes: bool,
     setup_py: SetupPyStrategy,
     connectivity: Connectivity,
@@ -67,8 +76,11 @@ pub(crate) async fn pip_install(
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    user: bool,
+    root: Option<Root>,
     concurrency: Concurrency,
     native_tls: bool,
+    error_format: ErrorFormat,
     preview: PreviewMode,
     cache: Cache,
     dry_run: bool,
@@ -100,6 +112,7 @@ pub(crate) async fn pip_install(
         constraints,
         overrides,
         extras,
+        groups,
         &client_builder,
     )
     .await?;
@@ -133,7 +146,7 @@ pub(crate) async fn pip_install(
         environment.python_executable().user_display().cyan()
     );
 
-    // Apply any `--target` or `--prefix` directories.
+    // Apply any `--target`, `--prefix`, or `--user` directories.
     let environment = if let Some(target) = target {
         debug!(
             "Using `--target` directory at {}",
@@ -148,6 +161,33 @@ pub(crate) async fn pip_install(
         );
         prefix.init()?;
         environment.with_prefix(prefix)
+    } else if user {
+        if environment.interpreter().is_virtualenv() {
+            return Err(anyhow::anyhow!(
+                "Can't perform a `--user` install: user site-packages are not visible in the virtual environment at {}",
+                environment.root().user_display().cyan()
+            ));
+        }
+        let Some(scheme) = environment.interpreter().user_scheme() else {
+            return Err(anyhow::anyhow!(
+                "Can't perform a `--user` install: the interpreter at {} does not support the `user` installation scheme",
+                environment.python_executable().user_display().cyan()
+            ));
+        };
+        debug!(
+            "Using `--user` site-packages directory at {}",
+            scheme.purelib.user_display()
+        );
+        environment.with_user()
+    } else {
+        environment
+    };
+
+    // Apply any `--root` directory.
+    let environment = if let Some(root) = root {
+        debug!("Using `--root` directory at {}", root.root().user_display());
+        root.init()?;
+        environment.with_root(root)
     } else {
         environment
     };
@@ -271,7 +311,13 @@ pub(crate) async fn pip_install(
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, &cache);
-        let entries = client.fetch(index_locations.flat_index()).await?;
+        let mut entries = client.fetch(index_locations.flat_index()).await?;
+
+        // When offline, treat any wheels in the cache as available.
+        if matches!(connectivity, Connectivity::Offline) {
+            entries.extend(CachedFlatIndex::entries(&cache, &index_locations));
+        }
+
         FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
     };
 
@@ -306,13 +352,19 @@ pub(crate) async fn pip_install(
         concurrency,
         preview,
     )
-    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
+    .with_options(
+        OptionsBuilder::new()
+            .exclude_newer(exclude_newer.clone())
+            .build(),
+    );
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_package(resolution_package)
         .prerelease_mode(prerelease_mode)
+        .prerelease_package(prerelease_package)
         .dependency_mode(dependency_mode)
-        .exclude_newer(exclude_newer)
+        .exclude_newer(exclude_newer.clone())
         .index_strategy(index_strategy)
         .build();
 
@@ -325,6 +377,7 @@ pub(crate) async fn pip_install(
         source_trees,
         project,
         extras,
+        groups,
         preferences,
         site_packages.clone(),
         &hasher,
@@ -347,9 +400,7 @@ pub(crate) async fn pip_install(
     {
         Ok(resolution) => Resolution::from(resolution),
         Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
+            report_no_solution(&err, error_format)?;
             return Ok(ExitStatus::Failure);
         }
         Err(err) => return Err(err.into()),
@@ -391,7 +442,9 @@ pub(crate) async fn pip_install(
         &reinstall,
         &build_options,
         link_mode,
+        on_conflict,
         compile,
+        compile_optimization,
         &index_locations,
         &hasher,
         &tags,
@@ -21,8 +21,8 @@ use pypi_types::Requirement;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
-    BuildOptions, Concurrency, Constraints, ExtrasSpecification, Overrides, PreviewMode, Reinstall,
-    Upgrade,
+    BuildOptions, BytecodeOptimization, Concurrency, Constraints, ExtrasSpecification,
+    FileConflictStrategy, Overrides, PreviewMode, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -51,6 +51,7 @@ pub(crate) async fn read_requirements(
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     extras: &ExtrasSpecification,
+    groups: &[GroupName],
     client_builder: &BaseClientBuilder<'_>,
 ) -> Result<RequirementsSpecification, Error> {
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
@@ -62,6 +63,15 @@ pub(crate) async fn read_requirements(
         .into());
     }
 
+    // Similarly, dependency groups can only be read from a `pyproject.toml`.
+    if !groups.is_empty()
+        && !requirements
+            .iter()
+            .any(|source| matches!(source, RequirementsSource::PyprojectToml(_)))
+    {
+        return Err(anyhow!("Requesting groups requires a `pyproject.toml` file.").into());
+    }
+
     // Read all requirements from the provided sources.
     Ok(RequirementsSpecification::from_sources(
         requirements,
@@ -82,6 +92,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     source_trees: Vec<PathBuf>,
     mut project: Option<PackageName>,
     extras: &ExtrasSpecification,
+    groups: &[GroupName],
     preferences: Vec<Preference>,
     installed_packages: InstalledPackages,
     hasher: &HashStrategy,
@@ -124,6 +135,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 index,
                 DistributionDatabase::new(client, build_dispatch, concurrency.downloads, preview),
             )
+            .with_groups(groups)
             .with_reporter(ResolverReporter::from(printer))
             .resolve()
             .await?;
@@ -291,7 +303,9 @@ pub(crate) async fn install(
     reinstall: &Reinstall,
     build_options: &BuildOptions,
     link_mode: LinkMode,
+    on_conflict: FileConflictStrategy,
     compile: bool,
+    compile_optimization: &[BytecodeOptimization],
     index_urls: &IndexLocations,
     hasher: &HashStrategy,
     tags: &Tags,
@@ -450,10 +464,12 @@ pub(crate) async fn install(
 
     // Install the resolved distributions.
     let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
+    let mut dist_infos = Vec::new();
     if !wheels.is_empty() {
         let start = std::time::Instant::now();
-        uv_installer::Installer::new(venv)
+        dist_infos = uv_installer::Installer::new(venv)
             .with_link_mode(link_mode)
+            .with_on_conflict(on_conflict)
             .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
             .install(&wheels)?;
 
@@ -470,8 +486,8 @@ pub(crate) async fn install(
         )?;
     }
 
-    if compile {
-        compile_bytecode(venv, cache, printer).await?;
+    if compile && !dist_infos.is_empty() {
+        compile_bytecode(&dist_infos, compile_optimization, venv, cache, printer).await?;
     }
 
     // Notify the user of any environment modifications.
@@ -1,6 +1,5 @@
 use std::fmt::Write;
 
-use anstream::eprint;
 use anyhow::Result;
 use owo_colors::OwoColorize;
 use tracing::debug;
@@ -11,11 +10,13 @@ use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy, NoBinary,
-    NoBuild, PreviewMode, Reinstall, SetupPyStrategy, Upgrade,
+    BuildOptions, BytecodeOptimization, Concurrency, ConfigSettings, ExtrasSpecification,
+    FileConflictStrategy, IndexStrategy, NoBinary, NoBuild, PreviewMode, Reinstall,
+    SetupPyStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
+use uv_distribution::CachedFlatIndex;
 use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_installer::SitePackages;
@@ -29,7 +30,7 @@ use uv_types::{BuildIsolation, HashStrategy, InFlight};
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::{operations, resolution_environment};
-use crate::commands::ExitStatus;
+use crate::commands::{report_no_solution, ErrorFormat, ExitStatus};
 use crate::printer::Printer;
 
 /// Install a set of locked requirements into the current Python environment.
@@ -39,7 +40,9 @@ pub(crate) async fn pip_sync(
     constraints: &[RequirementsSource],
     reinstall: &Reinstall,
     link_mode: LinkMode,
+    on_conflict: FileConflictStrategy,
     compile: bool,
+    compile_optimization: &[BytecodeOptimization],
     require_hashes: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -61,6 +64,7 @@ pub(crate) async fn pip_sync(
     prefix: Option<Prefix>,
     concurrency: Concurrency,
     native_tls: bool,
+    error_format: ErrorFormat,
     preview: PreviewMode,
     cache: Cache,
     dry_run: bool,
@@ -98,6 +102,7 @@ pub(crate) async fn pip_sync(
         constraints,
         overrides,
         &extras,
+        &[],
         &client_builder,
     )
     .await?;
@@ -214,7 +219,13 @@ pub(crate) async fn pip_sync(
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, &cache);
-        let entries = client.fetch(index_locations.flat_index()).await?;
+        let mut entries = client.fetch(index_locations.flat_index()).await?;
+
+        // When offline, treat any wheels in the cache as available.
+        if matches!(connectivity, Connectivity::Offline) {
+            entries.extend(CachedFlatIndex::entries(&cache, &index_locations));
+        }
+
         FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
     };
 
@@ -256,7 +267,11 @@ pub(crate) async fn pip_sync(
         concurrency,
         preview,
     )
-    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
+    .with_options(
+        OptionsBuilder::new()
+            .exclude_newer(exclude_newer.clone())
+            .build(),
+    );
 
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_executable(&environment)?;
@@ -265,7 +280,7 @@ pub(crate) async fn pip_sync(
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
-        .exclude_newer(exclude_newer)
+        .exclude_newer(exclude_newer.clone())
         .index_strategy(index_strategy)
         .build();
 
@@ -277,6 +292,7 @@ pub(crate) async fn pip_sync(
         source_trees,
         project,
         &extras,
+        &[],
         preferences,
         site_packages.clone(),
         &hasher,
@@ -299,9 +315,7 @@ pub(crate) async fn pip_sync(
     {
         Ok(resolution) => Resolution::from(resolution),
         Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
+            report_no_solution(&err, error_format)?;
             return Ok(ExitStatus::Failure);
         }
         Err(err) => return Err(err.into()),
@@ -343,7 +357,9 @@ pub(crate) async fn pip_sync(
         reinstall,
         &build_options,
         link_mode,
+        on_conflict,
         compile,
+        compile_optimization,
         &index_locations,
         &hasher,
         &tags,
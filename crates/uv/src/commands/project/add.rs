@@ -1,14 +1,19 @@
 use anyhow::Result;
+use std::path::PathBuf;
 use std::str::FromStr;
 use uv_distribution::pyproject_mut::PyProjectTomlMut;
+use uv_distribution::script::Pep723Script;
 
 use distribution_types::IndexLocations;
 use pep508_rs::Requirement;
 use uv_cache::Cache;
-use uv_configuration::{ExtrasSpecification, PreviewMode, Upgrade};
-use uv_distribution::ProjectWorkspace;
+use uv_client::Connectivity;
+use uv_configuration::{DevSpecification, PreviewMode, Upgrade};
+use uv_distribution::{ProjectWorkspace, DEV_DEPENDENCIES};
+use uv_resolver::LockOptions;
 use uv_warnings::warn_user;
 
+use crate::commands::pip::operations::Modifications;
 use crate::commands::{project, ExitStatus};
 use crate::printer::Printer;
 
@@ -17,6 +22,9 @@ use crate::printer::Printer;
 pub(crate) async fn add(
     requirements: Vec<String>,
     python: Option<String>,
+    script: Option<PathBuf>,
+    lock_options: LockOptions,
+    connectivity: Connectivity,
     preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
@@ -25,6 +33,21 @@ pub(crate) async fn add(
         warn_user!("`uv add` is experimental and may change without warning.");
     }
 
+    // If a script was provided, add the dependencies to its inline metadata. The script's
+    // environment is created when the script is run, so there's nothing to lock or sync.
+    if let Some(script) = script {
+        let script = Pep723Script::read_or_init(&script).await?;
+
+        let mut toml = PyProjectTomlMut::from_script(&script)?;
+        for req in requirements {
+            let req = Requirement::from_str(&req)?;
+            toml.add_dependency(&req)?;
+        }
+
+        script.write(&toml.to_string()).await?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Find the project requirements.
     let project = ProjectWorkspace::discover(&std::env::current_dir()?, None).await?;
 
@@ -55,13 +78,18 @@ pub(crate) async fn add(
         .as_ref()
         .map(|project| project.name.clone());
 
+    let existing = project::lock::read(project.workspace()).await?;
     let lock = project::lock::do_lock(
         root_project_name,
         project.workspace(),
         venv.interpreter(),
         &index_locations,
         upgrade,
+        vec![],
+        existing.as_ref(),
         exclude_newer,
+        lock_options,
+        false,
         preview,
         cache,
         printer,
@@ -70,17 +98,19 @@ pub(crate) async fn add(
 
     // Perform a full sync, because we don't know what exactly is affected by the removal.
     // TODO(ibraheem): Should we accept CLI overrides for this? Should we even sync here?
-    let extras = ExtrasSpecification::All;
-    let dev = true;
+    let extras = project::sync_extras(project.workspace(), project.project_name());
+    let dev = DevSpecification::Include(vec![DEV_DEPENDENCIES.clone()]);
 
     project::sync::do_sync(
-        project.project_name(),
-        project.workspace().root(),
+        project.workspace(),
+        std::slice::from_ref(project.project_name()),
         &venv,
         &lock,
         &index_locations,
         extras,
         dev,
+        Modifications::Sufficient,
+        connectivity,
         preview,
         cache,
         printer,
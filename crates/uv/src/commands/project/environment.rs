@@ -0,0 +1,126 @@
+use anyhow::Result;
+use itertools::Itertools;
+use tracing::debug;
+
+use distribution_types::IndexLocations;
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::PreviewMode;
+use uv_fs::{LockedFile, Simplified};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_toolchain::{Interpreter, PythonEnvironment};
+
+use crate::commands::project::{resolve_environment, sync_environment};
+use crate::printer::Printer;
+
+/// An ephemeral [`PythonEnvironment`] for running a command with a set of requirements (e.g.,
+/// `uv run --with` or `uv tool run`), stored in the cache such that it can be reused across
+/// invocations.
+///
+/// The environment is locked for as long as the [`CachedEnvironment`] is alive, such that it can't
+/// be modified or pruned (e.g., by `uv cache prune`) while a command is running within it.
+#[derive(Debug)]
+pub(crate) struct CachedEnvironment {
+    environment: PythonEnvironment,
+    _lock: LockedFile,
+}
+
+impl CachedEnvironment {
+    /// Get or create a [`CachedEnvironment`] in which the given requirements are installed.
+    ///
+    /// The requirements are resolved up-front, and the environment is keyed by the interpreter, the
+    /// resolved distributions, and the index settings, such that repeated invocations that resolve
+    /// to the same set of distributions reuse the same environment.
+    pub(crate) async fn get_or_create(
+        requirements: &[RequirementsSource],
+        interpreter: Interpreter,
+        index_locations: &IndexLocations,
+        connectivity: Connectivity,
+        cache: &Cache,
+        printer: Printer,
+        preview: PreviewMode,
+    ) -> Result<Self> {
+        let client_builder = BaseClientBuilder::default().connectivity(connectivity);
+
+        // Read the requirements from the given sources, including any constraints and overrides.
+        let spec = RequirementsSpecification::from_sources(requirements, &[], &[], &client_builder)
+            .await?;
+
+        // Resolve the requirements, such that the key reflects the distributions that will
+        // actually be installed, rather than the (e.g., unpinned) requirements as written.
+        let resolution = resolve_environment(
+            spec,
+            &interpreter,
+            index_locations,
+            connectivity,
+            cache,
+            printer,
+            preview,
+        )
+        .await?;
+
+        // Key the environment by the interpreter, the (sorted) set of resolved distributions, and
+        // the index settings, since the same version may be served by different indexes.
+        let key = std::iter::once(interpreter.sys_executable().to_string_lossy().to_string())
+            .chain(resolution.distributions().map(ToString::to_string).sorted())
+            .chain(index_locations.urls().map(ToString::to_string))
+            .chain(index_locations.flat_index().map(ToString::to_string))
+            .collect::<Vec<_>>();
+        let digest = cache_key::digest(&key);
+        let root = cache.shard(CacheBucket::CachedEnvironments, &digest);
+
+        // Lock the environment, to avoid concurrent invocations creating or modifying it.
+        fs_err::create_dir_all(cache.bucket(CacheBucket::CachedEnvironments))?;
+        let lock = LockedFile::acquire(
+            cache
+                .bucket(CacheBucket::CachedEnvironments)
+                .join(format!("{digest}.lock")),
+            root.as_ref().user_display(),
+        )?;
+
+        let venv = match PythonEnvironment::from_root(&root, cache) {
+            Ok(venv) => {
+                debug!(
+                    "Reusing cached environment at: {}",
+                    root.as_ref().user_display()
+                );
+                venv
+            }
+            Err(err) => {
+                debug!(
+                    "Creating cached environment at: {} ({err})",
+                    root.as_ref().user_display()
+                );
+                uv_virtualenv::create_venv(
+                    root.as_ref(),
+                    interpreter,
+                    uv_virtualenv::Prompt::None,
+                    false,
+                    false,
+                )?
+            }
+        };
+
+        // Install the resolved distributions, if they're not already installed.
+        let venv = sync_environment(
+            venv,
+            &resolution,
+            index_locations,
+            connectivity,
+            cache,
+            printer,
+            preview,
+        )
+        .await?;
+
+        Ok(Self {
+            environment: venv,
+            _lock: lock,
+        })
+    }
+
+    /// Return the underlying [`PythonEnvironment`].
+    pub(crate) fn environment(&self) -> &PythonEnvironment {
+        &self.environment
+    }
+}
@@ -1,9 +1,18 @@
-use std::collections::Bound;
+use std::collections::{BTreeMap, BTreeSet, Bound};
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
 
-use anstream::eprint;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
 
 use distribution_types::{IndexLocations, UnresolvedRequirementSpecification};
 use install_wheel_rs::linker::LinkMode;
+use pep440_rs::Version;
+use pep508_rs::{MarkerTree, RequirementOrigin};
+use platform_tags::Tags;
+use pypi_types::{Metadata23, Requirement};
 use uv_cache::Cache;
 use uv_client::{FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
@@ -11,17 +20,20 @@ use uv_configuration::{
     SetupPyStrategy, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution::{Workspace, DEV_DEPENDENCIES};
+use uv_distribution::{Metadata, RequiresDist, Workspace, DEV_DEPENDENCIES};
 use uv_git::GitResolver;
 use uv_normalize::PackageName;
-use uv_requirements::upgrade::{read_lockfile, LockedRequirements};
-use uv_resolver::{ExcludeNewer, FlatIndex, InMemoryIndex, Lock, OptionsBuilder, RequiresPython};
-use uv_toolchain::Interpreter;
+use uv_requirements::upgrade::{read_import, read_lockfile, LockedRequirements};
+use uv_resolver::{
+    ExcludeNewer, FlatIndex, InMemoryIndex, Lock, LockOptions, OptionsBuilder, Preference,
+    RequiresPython, LOCK_VERSION,
+};
+use uv_toolchain::{Interpreter, PythonVersion};
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
 use crate::commands::project::{find_requires_python, ProjectError};
-use crate::commands::{pip, project, ExitStatus};
+use crate::commands::{elapsed, pip, project, report_no_solution, ErrorFormat, ExitStatus};
 use crate::printer::Printer;
 
 /// Resolve the project requirements into a lockfile.
@@ -29,8 +41,13 @@ use crate::printer::Printer;
 pub(crate) async fn lock(
     index_locations: IndexLocations,
     upgrade: Upgrade,
+    from: Option<PathBuf>,
     exclude_newer: Option<ExcludeNewer>,
+    lock_options: LockOptions,
     python: Option<String>,
+    dry_run: bool,
+    check_format: bool,
+    error_format: ErrorFormat,
     preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
@@ -42,6 +59,11 @@ pub(crate) async fn lock(
     // Find the project requirements.
     let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
 
+    // Validate the existing lockfile, without performing a resolution.
+    if check_format {
+        return validate_format(&workspace, printer).await;
+    }
+
     // Find an interpreter for the project
     let interpreter = project::find_interpreter(&workspace, python.as_deref(), cache, printer)?;
 
@@ -53,26 +75,43 @@ pub(crate) async fn lock(
             .as_ref()
             .map(|project| project.name.clone())
     });
+    // Import the pinned versions from another tool's lockfile, if provided.
+    let imported = if let Some(from) = from.as_deref() {
+        read_import(from, &upgrade).await?
+    } else {
+        vec![]
+    };
+
+    // Read the existing lockfile, to report any changes.
+    let previous = read(&workspace).await?;
+
     match do_lock(
         root_project_name,
         &workspace,
         &interpreter,
         &index_locations,
         upgrade,
+        imported,
+        previous.as_ref(),
         exclude_newer,
+        lock_options,
+        dry_run,
         preview,
         cache,
         printer,
     )
     .await
     {
-        Ok(_) => Ok(ExitStatus::Success),
+        Ok(lock) => {
+            if let Some(previous) = previous.as_ref() {
+                report_changes(previous, &lock, printer)?;
+            }
+            Ok(ExitStatus::Success)
+        }
         Err(ProjectError::Operation(pip::operations::Error::Resolve(
             uv_resolver::ResolveError::NoSolution(err),
         ))) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
+            report_no_solution(&err, error_format)?;
             Ok(ExitStatus::Failure)
         }
         Err(err) => Err(err.into()),
@@ -80,6 +119,9 @@ pub(crate) async fn lock(
 }
 
 /// Lock the project requirements into a lockfile.
+///
+/// The `existing` lockfile, if any, is used to seed the resolution, and is returned as-is if it
+/// still satisfies the workspace requirements.
 #[allow(clippy::too_many_arguments)]
 pub(super) async fn do_lock(
     root_project_name: Option<PackageName>,
@@ -87,20 +129,41 @@ pub(super) async fn do_lock(
     interpreter: &Interpreter,
     index_locations: &IndexLocations,
     upgrade: Upgrade,
+    imported: Vec<Preference>,
+    existing: Option<&Lock>,
     exclude_newer: Option<ExcludeNewer>,
+    lock_options: LockOptions,
+    dry_run: bool,
     preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
 ) -> Result<Lock, ProjectError> {
+    let start = std::time::Instant::now();
+
     // When locking, include the project itself (as editable).
     let requirements = workspace
         .members_as_requirements()
         .into_iter()
         .map(UnresolvedRequirementSpecification::from)
         .collect();
-    let constraints = vec![];
-    let overrides = vec![];
-    let dev = vec![DEV_DEPENDENCIES.clone()];
+    let constraints = lock_options
+        .constraints
+        .iter()
+        .cloned()
+        .map(|requirement| Requirement::from(requirement.with_origin(RequirementOrigin::Workspace)))
+        .collect();
+    let overrides = lock_options
+        .overrides
+        .iter()
+        .cloned()
+        .map(|requirement| Requirement::from(requirement.with_origin(RequirementOrigin::Workspace)))
+        .map(UnresolvedRequirementSpecification::from)
+        .collect();
+
+    // Lock all dependency groups, including `tool.uv.dev-dependencies`.
+    let mut dev = workspace.dependency_groups();
+    dev.insert(DEV_DEPENDENCIES.clone());
+    let dev = dev.into_iter().collect();
 
     let source_trees = vec![];
 
@@ -156,8 +219,105 @@ pub(super) async fn do_lock(
     let reinstall = Reinstall::default();
     let setup_py = SetupPyStrategy::default();
 
+    // If an existing lockfile exists, build up a set of preferences. When upgrading all packages,
+    // the existing lockfile is ignored entirely.
+    let existing = existing.filter(|_| !upgrade.is_all());
+    let LockedRequirements { preferences, git } = existing
+        .map(|lock| read_lockfile(lock, &upgrade))
+        .unwrap_or_default();
+
+    // If the resolver settings have changed since the lockfile was generated, ignore the existing
+    // pins and re-resolve from scratch.
+    let (preferences, git) = match existing.and_then(Lock::options) {
+        Some(locked_options) if *locked_options != lock_options => {
+            debug!("Ignoring existing lockfile due to a change in resolver settings");
+            (vec![], vec![])
+        }
+        _ => (preferences, git),
+    };
+
+    // Reuse the cooldown cutoff from the existing lockfile, so that re-locking is stable.
+    let exclude_newer = exclude_newer.map(|exclude_newer| {
+        exclude_newer.with_previous(existing.and_then(Lock::exclude_newer), &upgrade)
+    });
+
+    // Drop any pins that were uploaded after the `exclude-newer` cutoff, based on the upload times
+    // recorded in the existing lockfile.
+    let preferences = match exclude_newer.as_ref() {
+        Some(exclude_newer) if !preferences.is_empty() => {
+            let excluded = existing
+                .map(|lock| excluded_by_upload_time(lock, exclude_newer))
+                .unwrap_or_default();
+            preferences
+                .into_iter()
+                .filter(|preference| {
+                    !excluded.contains(&(preference.name().clone(), preference.version().clone()))
+                })
+                .collect()
+        }
+        _ => preferences,
+    };
+
+    // Prefer any imported pins over those in the existing lockfile.
+    let has_imports = !imported.is_empty();
+    let preferences = preferences.into_iter().chain(imported).collect();
+
+    // Restrict the resolution to the declared target environments, if any.
+    let environments = workspace
+        .environments()
+        .map(<[_]>::to_vec)
+        .unwrap_or_default();
+
+    // If the existing lockfile is still satisfied by the workspace, skip the resolution entirely.
+    // Imported pins are only respected when resolving.
+    if upgrade.is_none() && !has_imports {
+        if let Some(lock) = existing {
+            if is_satisfied(
+                lock,
+                workspace,
+                &requires_python,
+                &environments,
+                index_locations,
+                exclude_newer.as_ref(),
+                &lock_options,
+                preview,
+            )
+            .await?
+            {
+                debug!(
+                    "Existing lockfile satisfies the workspace requirements; skipping resolution"
+                );
+                let s = if lock.distributions().len() == 1 {
+                    ""
+                } else {
+                    "s"
+                };
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!(
+                        "Resolved {} in {}",
+                        format!("{} package{}", lock.distributions().len(), s).bold(),
+                        elapsed(start.elapsed())
+                    )
+                    .dimmed()
+                )?;
+                validate_required_environments(workspace, interpreter, lock)?;
+                return Ok(lock.clone());
+            }
+        }
+    }
+
     let hasher = HashStrategy::Generate;
-    let options = OptionsBuilder::new().exclude_newer(exclude_newer).build();
+    let options = OptionsBuilder::new()
+        .resolution_mode(lock_options.resolution_mode)
+        .resolution_package(lock_options.resolution_package.clone())
+        .prerelease_mode(lock_options.prerelease_mode)
+        .prerelease_package(lock_options.prerelease_package.clone())
+        .exclude_newer(exclude_newer.clone())
+        .conflicts(workspace.conflicts())
+        .environments(environments)
+        .build();
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -166,9 +326,6 @@ pub(super) async fn do_lock(
         FlatIndex::from_entries(entries, None, &hasher, &build_options)
     };
 
-    // If an existing lockfile exists, build up a set of preferences.
-    let LockedRequirements { preferences, git } = read_lockfile(workspace, &upgrade).await?;
-
     // Create the Git resolver.
     let git = GitResolver::from_refs(git);
 
@@ -200,6 +357,7 @@ pub(super) async fn do_lock(
         source_trees,
         root_project_name,
         &extras,
+        &[],
         preferences,
         EmptyInstalledPackages,
         &hasher,
@@ -214,7 +372,7 @@ pub(super) async fn do_lock(
         &index,
         &build_dispatch,
         concurrency,
-        options,
+        options.clone(),
         printer,
         preview,
     )
@@ -224,9 +382,292 @@ pub(super) async fn do_lock(
     pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     // Write the lockfile to disk.
-    let lock = Lock::from_resolution_graph(&resolution)?;
-    let encoded = lock.to_toml()?;
-    fs_err::tokio::write(workspace.root().join("uv.lock"), encoded.as_bytes()).await?;
+    let lock = Lock::from_resolution_graph(&resolution, &options, lock_options)?;
+    validate_required_environments(workspace, interpreter, &lock)?;
+
+    if !dry_run {
+        let encoded = lock.to_toml()?;
+        fs_err::tokio::write(workspace.root().join("uv.lock"), encoded.as_bytes()).await?;
+    }
 
     Ok(lock)
 }
+
+/// Report the changes between the previous and the new [`Lock`] (e.g., `Updated anyio v3.7.0 ->
+/// v4.3.0`, or `Downgraded anyio v4.3.0 -> v3.7.0`).
+fn report_changes(previous: &Lock, lock: &Lock, printer: Printer) -> Result<(), ProjectError> {
+    // Collect the locked versions of each package, as a package may be locked at multiple
+    // versions across forks.
+    let mut versions: BTreeMap<&PackageName, (BTreeSet<&Version>, BTreeSet<&Version>)> =
+        BTreeMap::new();
+    for dist in previous.distributions() {
+        versions
+            .entry(dist.name())
+            .or_default()
+            .0
+            .insert(dist.version());
+    }
+    for dist in lock.distributions() {
+        versions
+            .entry(dist.name())
+            .or_default()
+            .1
+            .insert(dist.version());
+    }
+
+    for (name, (previous, current)) in versions {
+        if previous == current {
+            continue;
+        }
+        let format = |versions: &BTreeSet<&Version>| {
+            versions
+                .iter()
+                .map(|version| format!("v{version}"))
+                .join(", ")
+        };
+        if previous.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "{} {name} {}",
+                "Added".green().bold(),
+                format(&current)
+            )?;
+        } else if current.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "{} {name} {}",
+                "Removed".red().bold(),
+                format(&previous)
+            )?;
+        } else if current.last() < previous.last() {
+            writeln!(
+                printer.stderr(),
+                "{} {name} {} -> {}",
+                "Downgraded".yellow().bold(),
+                format(&previous),
+                format(&current)
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "{} {name} {} -> {}",
+                "Updated".green().bold(),
+                format(&previous),
+                format(&current)
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Read the existing lockfile for the workspace, if any.
+///
+/// Returns `None` if the lockfile doesn't exist or can't be parsed, in which case the workspace
+/// should be re-resolved.
+pub(super) async fn read(workspace: &Workspace) -> Result<Option<Lock>, ProjectError> {
+    match fs_err::tokio::read_to_string(workspace.root().join("uv.lock")).await {
+        Ok(encoded) => match Lock::from_toml(&encoded) {
+            Ok(lock) => Ok(Some(lock)),
+            // Never overwrite a lockfile that was written by a newer version of uv.
+            Err(err) if err.is_unsupported_version() => Err(err.into()),
+            Err(err) => {
+                debug!("Failed to parse lockfile: {err}");
+                Ok(None)
+            }
+        },
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Validate that the existing lockfile matches the lockfile format, and can be read by this
+/// version of `uv`.
+async fn validate_format(workspace: &Workspace, printer: Printer) -> anyhow::Result<ExitStatus> {
+    let encoded = fs_err::tokio::read_to_string(workspace.root().join("uv.lock")).await?;
+    let lock = Lock::from_toml(&encoded)?;
+
+    if lock.version() < LOCK_VERSION {
+        writeln!(
+            printer.stderr(),
+            "Lockfile is valid, but uses version {} of the lockfile format; it will be upgraded to version {} when next written",
+            lock.version(),
+            LOCK_VERSION
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Lockfile is valid (version {})",
+            lock.version()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns the locked distributions that were uploaded after the `exclude-newer` cutoff that
+/// applies to them, and so should no longer be preferred.
+fn excluded_by_upload_time(
+    lock: &Lock,
+    exclude_newer: &ExcludeNewer,
+) -> BTreeSet<(PackageName, Version)> {
+    lock.distributions()
+        .iter()
+        .filter(|dist| {
+            match (dist.upload_time(), exclude_newer.for_package(dist.name())) {
+                (Some(upload_time), Some(cutoff)) => upload_time >= cutoff.timestamp_millis(),
+                _ => false,
+            }
+        })
+        .inspect(|dist| {
+            debug!(
+                "Ignoring locked version of {} ({}), which was uploaded after the `exclude-newer` cutoff",
+                dist.name(),
+                dist.version()
+            );
+        })
+        .map(|dist| (dist.name().clone(), dist.version().clone()))
+        .collect()
+}
+
+/// Returns `true` if the existing [`Lock`] is provably satisfied by the workspace, such that it
+/// can be reused without performing a resolution.
+///
+/// This requires that the lockfile uses the current version of the lockfile format, that the
+/// resolver settings and indexes are unchanged, and that the requirements of every workspace
+/// member can be read statically from its `pyproject.toml` and match those recorded in the
+/// lockfile.
+async fn is_satisfied(
+    lock: &Lock,
+    workspace: &Workspace,
+    requires_python: &RequiresPython,
+    environments: &[MarkerTree],
+    index_locations: &IndexLocations,
+    exclude_newer: Option<&ExcludeNewer>,
+    lock_options: &LockOptions,
+    preview: PreviewMode,
+) -> Result<bool, ProjectError> {
+    if lock.version() < LOCK_VERSION {
+        debug!("Existing lockfile uses an outdated version of the lockfile format");
+        return Ok(false);
+    }
+    if lock.requires_python() != Some(requires_python) {
+        debug!("Existing lockfile has a different `requires-python`");
+        return Ok(false);
+    }
+    if lock.environments() != environments {
+        debug!("Existing lockfile has different target environments");
+        return Ok(false);
+    }
+    if lock.options() != Some(lock_options) || lock.exclude_newer() != exclude_newer {
+        debug!("Existing lockfile has different resolver settings");
+        return Ok(false);
+    }
+    if !lock.satisfies_indexes(index_locations) {
+        debug!("Existing lockfile references indexes that are no longer configured");
+        return Ok(false);
+    }
+    // Conflicting extras and groups aren't recorded in the lockfile.
+    if !workspace.conflicts().is_empty() {
+        debug!("Workspace declares conflicts; re-resolving");
+        return Ok(false);
+    }
+
+    let mut members = Vec::with_capacity(workspace.packages().len());
+    for (name, member) in workspace.packages() {
+        let contents = fs_err::tokio::read_to_string(member.root().join("pyproject.toml")).await?;
+        let metadata = match Metadata23::parse_pyproject_toml(&contents) {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                debug!("Failed to read static metadata for `{name}`: {err}");
+                return Ok(false);
+            }
+        };
+        let Some(project) = workspace.clone().with_current_project(name.clone()) else {
+            return Ok(false);
+        };
+        let requires_dist = match RequiresDist::from_project_workspace(
+            pypi_types::RequiresDist {
+                name: metadata.name,
+                requires_dist: metadata.requires_dist,
+                provides_extras: metadata.provides_extras,
+            },
+            &project,
+            preview,
+        ) {
+            Ok(requires_dist) => requires_dist,
+            Err(err) => {
+                debug!("Failed to lower requirements for `{name}`: {err}");
+                return Ok(false);
+            }
+        };
+        members.push(Metadata {
+            name: requires_dist.name,
+            version: metadata.version,
+            requires_dist: requires_dist.requires_dist,
+            requires_python: metadata.requires_python,
+            provides_extras: requires_dist.provides_extras,
+            dev_dependencies: requires_dist.dev_dependencies,
+        });
+    }
+
+    if !lock.satisfies(&members) {
+        debug!("Existing lockfile does not match the workspace requirements");
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Validate that every distribution in the [`Lock`] provides a wheel for each of the
+/// environments in `tool.uv.required-environments`.
+///
+/// The environments are evaluated at the lowest Python version supported by the lock (i.e., the
+/// lower bound of its `requires-python`), rather than that of the current interpreter.
+fn validate_required_environments(
+    workspace: &Workspace,
+    interpreter: &Interpreter,
+    lock: &Lock,
+) -> Result<(), ProjectError> {
+    let python_version =
+        lock.requires_python()
+            .and_then(|requires_python| match requires_python.bound() {
+                Bound::Included(version) | Bound::Excluded(version) => {
+                    PythonVersion::from_str(&version.to_string()).ok()
+                }
+                Bound::Unbounded => None,
+            });
+    let (base, python_tuple) = match python_version {
+        Some(python_version) => {
+            let python_tuple = (python_version.major(), python_version.minor());
+            (python_version.markers(interpreter.markers()), python_tuple)
+        }
+        None => (interpreter.markers().clone(), interpreter.python_tuple()),
+    };
+
+    for environment in workspace.required_environments().unwrap_or_default() {
+        let targets = environment.targets(&base);
+        if targets.is_empty() {
+            return Err(ProjectError::EmptyRequiredEnvironment(environment));
+        }
+        for target in targets {
+            let markers = target.markers(&base);
+            let tags = Tags::from_env(
+                &target.platform(),
+                python_tuple,
+                interpreter.implementation_name(),
+                interpreter.implementation_tuple(),
+                interpreter.gil_disabled(),
+            )?;
+            if let Some(dist) = lock.find_missing_wheel(&markers, &tags) {
+                return Err(ProjectError::MissingWheel {
+                    name: dist.name().clone(),
+                    version: dist.version().clone(),
+                    target,
+                    environment: environment.clone(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
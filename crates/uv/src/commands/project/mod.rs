@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Write;
 
 use anyhow::{Context, Result};
@@ -8,29 +9,33 @@ use tracing::debug;
 use distribution_types::{IndexLocations, Resolution};
 use install_wheel_rs::linker::LinkMode;
 use pep440_rs::Version;
+use pep508_rs::MarkerTree;
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity, RegistryClientBuilder};
+use uv_client::{Connectivity, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, PreviewMode, Reinstall,
-    SetupPyStrategy, Upgrade,
+    BuildOptions, Concurrency, ConfigSettings, ConflictItem, DevSpecification, ExtrasSpecification,
+    FileConflictStrategy, PreviewMode, Reinstall, RequiredEnvironment, SetupPyStrategy,
+    TargetTriple, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution::Workspace;
+use uv_distribution::{Workspace, DEV_DEPENDENCIES};
 use uv_fs::Simplified;
 use uv_git::GitResolver;
-use uv_installer::{SatisfiesResult, SitePackages};
-use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_installer::SitePackages;
+use uv_normalize::{GroupName, PackageName};
+use uv_requirements::RequirementsSpecification;
 use uv_resolver::{FlatIndex, InMemoryIndex, Options, RequiresPython};
 use uv_toolchain::{
     Interpreter, PythonEnvironment, SystemPython, Toolchain, ToolchainRequest, VersionRequest,
 };
-use uv_types::{BuildIsolation, HashStrategy, InFlight};
+use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
 use crate::commands::pip;
 use crate::printer::Printer;
 
 pub(crate) mod add;
+pub(crate) mod environment;
 pub(crate) mod lock;
 pub(crate) mod remove;
 pub(crate) mod run;
@@ -41,6 +46,26 @@ pub(crate) enum ProjectError {
     #[error("The current Python version ({0}) is not compatible with the locked Python requirement ({1})")]
     PythonIncompatibility(Version, RequiresPython),
 
+    #[error("The current environment is not supported by the lockfile, which only supports: {}", _0.iter().map(|environment| format!("`{environment}`")).join(", "))]
+    UnsupportedEnvironment(Vec<MarkerTree>),
+
+    #[error("Distribution `{name}=={version}` does not provide a wheel compatible with `{target}`, which is required by `{environment}` in `tool.uv.required-environments`")]
+    MissingWheel {
+        name: PackageName,
+        version: Version,
+        target: TargetTriple,
+        environment: RequiredEnvironment,
+    },
+
+    #[error("The environment `{0}` in `tool.uv.required-environments` does not match any supported target")]
+    EmptyRequiredEnvironment(RequiredEnvironment),
+
+    #[error("Group `{0}` is not defined in the project's `dependency-groups` table")]
+    MissingGroup(GroupName),
+
+    #[error("Package `{0}` not found in workspace")]
+    MissingPackage(PackageName),
+
     #[error(transparent)]
     Interpreter(#[from] uv_toolchain::Error),
 
@@ -91,6 +116,83 @@ pub(crate) fn find_requires_python(
     }))
 }
 
+/// Validate that the dependency groups requested by the [`DevSpecification`] are defined by at
+/// least one of the given workspace members.
+///
+/// The `dev` group (i.e., `tool.uv.dev-dependencies`) is always available.
+pub(crate) fn validate_dependency_groups(
+    workspace: &Workspace,
+    packages: &[PackageName],
+    dev: &DevSpecification,
+) -> Result<(), ProjectError> {
+    for group in dev.groups() {
+        if *group == *DEV_DEPENDENCIES {
+            continue;
+        }
+        let defined = packages
+            .iter()
+            .filter_map(|package| workspace.packages().get(package))
+            .any(|member| {
+                member
+                    .pyproject_toml()
+                    .dependency_groups
+                    .as_ref()
+                    .is_some_and(|groups| groups.contains_key(group))
+            });
+        if !defined {
+            return Err(ProjectError::MissingGroup(group.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Determine the extras to install for the given workspace member when syncing after an edit
+/// (e.g., `uv add` or `uv remove`): every extra of the member, except for those that participate
+/// in a declared conflict, since they can't be installed together.
+pub(crate) fn sync_extras(workspace: &Workspace, package: &PackageName) -> ExtrasSpecification {
+    let conflicts = workspace.conflicts();
+    if conflicts.is_empty() {
+        return ExtrasSpecification::All;
+    }
+    let extras = workspace
+        .packages()
+        .get(package)
+        .and_then(|member| member.pyproject_toml().project.as_ref())
+        .and_then(|project| project.optional_dependencies.as_ref())
+        .into_iter()
+        .flat_map(BTreeMap::keys)
+        .filter(|extra| {
+            let item = ConflictItem::extra(package.clone(), (*extra).clone());
+            !conflicts.iter().any(|set| set.contains(&item))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    if extras.is_empty() {
+        ExtrasSpecification::None
+    } else {
+        ExtrasSpecification::Some(extras)
+    }
+}
+
+/// Select the workspace members to operate on, given the packages requested via `--package`.
+///
+/// If `all_packages` is set, every member of the workspace is selected.
+pub(crate) fn select_packages(
+    workspace: &Workspace,
+    packages: Vec<PackageName>,
+    all_packages: bool,
+) -> Result<Vec<PackageName>, ProjectError> {
+    if all_packages {
+        return Ok(workspace.packages().keys().cloned().collect());
+    }
+    for package in &packages {
+        if !workspace.packages().contains_key(package) {
+            return Err(ProjectError::MissingPackage(package.clone()));
+        }
+    }
+    Ok(packages.into_iter().unique().collect())
+}
+
 /// Find the virtual environment for the current project.
 pub(crate) fn find_environment(
     workspace: &Workspace,
@@ -255,53 +357,19 @@ pub(crate) fn init_environment(
     )?)
 }
 
-/// Update a [`PythonEnvironment`] to satisfy a set of [`RequirementsSource`]s.
-pub(crate) async fn update_environment(
-    venv: PythonEnvironment,
-    requirements: &[RequirementsSource],
+/// Resolve a set of requirements for the given interpreter, without installing them.
+pub(crate) async fn resolve_environment(
+    spec: RequirementsSpecification,
+    interpreter: &Interpreter,
     index_locations: &IndexLocations,
     connectivity: Connectivity,
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
-) -> Result<PythonEnvironment> {
-    // TODO(zanieb): Support client configuration
-    let client_builder = BaseClientBuilder::default().connectivity(connectivity);
-
-    // Read all requirements from the provided sources.
-    // TODO(zanieb): Consider allowing constraints and extras
-    // TODO(zanieb): Allow specifying extras somehow
-    let spec =
-        RequirementsSpecification::from_sources(requirements, &[], &[], &client_builder).await?;
-
-    // Check if the current environment satisfies the requirements
-    let site_packages = SitePackages::from_executable(&venv)?;
-    if spec.source_trees.is_empty() {
-        match site_packages.satisfies(&spec.requirements, &spec.constraints)? {
-            // If the requirements are already satisfied, we're done.
-            SatisfiesResult::Fresh {
-                recursive_requirements,
-            } => {
-                debug!(
-                    "All requirements satisfied: {}",
-                    recursive_requirements
-                        .iter()
-                        .map(|entry| entry.requirement.to_string())
-                        .sorted()
-                        .join(" | ")
-                );
-                return Ok(venv);
-            }
-            SatisfiesResult::Unsatisfied(requirement) => {
-                debug!("At least one requirement is not satisfied: {requirement}");
-            }
-        }
-    }
-
-    // Determine the tags, markers, and interpreter to use for resolution.
-    let interpreter = venv.interpreter();
-    let tags = venv.interpreter().tags()?;
-    let markers = venv.interpreter().markers();
+) -> Result<Resolution> {
+    // Determine the tags and markers to use for resolution.
+    let tags = interpreter.tags()?;
+    let markers = interpreter.markers();
 
     // Initialize the registry client.
     // TODO(zanieb): Support client options e.g. offline, tls, etc.
@@ -309,15 +377,13 @@ pub(crate) async fn update_environment(
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .markers(markers)
-        .platform(venv.interpreter().platform())
+        .platform(interpreter.platform())
         .build();
 
     // TODO(charlie): Respect project configuration.
     let build_isolation = BuildIsolation::default();
-    let compile = false;
     let concurrency = Concurrency::default();
     let config_settings = ConfigSettings::default();
-    let dry_run = false;
     let extras = ExtrasSpecification::default();
     let flat_index = FlatIndex::default();
     let git = GitResolver::default();
@@ -353,7 +419,7 @@ pub(crate) async fn update_environment(
     );
 
     // Resolve the requirements.
-    let resolution = match pip::operations::resolve(
+    let resolution = pip::operations::resolve(
         spec.requirements,
         spec.constraints,
         spec.overrides,
@@ -361,8 +427,9 @@ pub(crate) async fn update_environment(
         spec.source_trees,
         spec.project,
         &extras,
+        &[],
         preferences,
-        site_packages.clone(),
+        EmptyInstalledPackages,
         &hasher,
         &reinstall,
         &upgrade,
@@ -379,55 +446,93 @@ pub(crate) async fn update_environment(
         printer,
         preview,
     )
-    .await
-    {
-        Ok(resolution) => Resolution::from(resolution),
-        Err(err) => return Err(err.into()),
-    };
+    .await?;
+
+    Ok(Resolution::from(resolution))
+}
+
+/// Sync a [`PythonEnvironment`] with a set of resolved requirements, installing any that are
+/// missing.
+pub(crate) async fn sync_environment(
+    venv: PythonEnvironment,
+    resolution: &Resolution,
+    index_locations: &IndexLocations,
+    connectivity: Connectivity,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<PythonEnvironment> {
+    let site_packages = SitePackages::from_executable(&venv)?;
+
+    // Determine the tags, markers, and interpreter to use for installation.
+    let interpreter = venv.interpreter();
+    let tags = venv.interpreter().tags()?;
+    let markers = venv.interpreter().markers();
+
+    // Initialize the registry client.
+    // TODO(zanieb): Support client options e.g. offline, tls, etc.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .index_urls(index_locations.index_urls())
+        .markers(markers)
+        .platform(venv.interpreter().platform())
+        .build();
 
-    // Re-initialize the in-flight map.
+    // TODO(charlie): Respect project configuration.
+    let build_isolation = BuildIsolation::default();
+    let compile = false;
+    let compile_optimization = Vec::default();
+    let concurrency = Concurrency::default();
+    let config_settings = ConfigSettings::default();
+    let dry_run = false;
+    let flat_index = FlatIndex::default();
+    let git = GitResolver::default();
+    let hasher = HashStrategy::default();
     let in_flight = InFlight::default();
+    let index = InMemoryIndex::default();
+    let link_mode = LinkMode::default();
+    let on_conflict = FileConflictStrategy::default();
+    let build_options = BuildOptions::default();
+    let reinstall = Reinstall::default();
+    let setup_py = SetupPyStrategy::default();
 
-    // If we're running with `--reinstall`, initialize a separate `BuildDispatch`, since we may
-    // end up removing some distributions from the environment.
-    let install_dispatch = if reinstall.is_none() {
-        resolve_dispatch
-    } else {
-        BuildDispatch::new(
-            &client,
-            cache,
-            interpreter,
-            index_locations,
-            &flat_index,
-            &index,
-            &git,
-            &in_flight,
-            setup_py,
-            &config_settings,
-            build_isolation,
-            link_mode,
-            &build_options,
-            concurrency,
-            preview,
-        )
-    };
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        interpreter,
+        index_locations,
+        &flat_index,
+        &index,
+        &git,
+        &in_flight,
+        setup_py,
+        &config_settings,
+        build_isolation,
+        link_mode,
+        &build_options,
+        concurrency,
+        preview,
+    );
 
     // Sync the environment.
     pip::operations::install(
-        &resolution,
+        resolution,
         site_packages,
         pip::operations::Modifications::Sufficient,
         &reinstall,
         &build_options,
         link_mode,
+        on_conflict,
         compile,
+        &compile_optimization,
         index_locations,
         &hasher,
         tags,
         &client,
         &in_flight,
         concurrency,
-        &install_dispatch,
+        &build_dispatch,
         cache,
         &venv,
         dry_run,
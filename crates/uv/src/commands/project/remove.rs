@@ -4,10 +4,13 @@ use uv_distribution::pyproject_mut::PyProjectTomlMut;
 
 use distribution_types::IndexLocations;
 use uv_cache::Cache;
-use uv_configuration::{ExtrasSpecification, PreviewMode, Upgrade};
-use uv_distribution::ProjectWorkspace;
+use uv_client::Connectivity;
+use uv_configuration::{DevSpecification, PreviewMode, Upgrade};
+use uv_distribution::{ProjectWorkspace, DEV_DEPENDENCIES};
+use uv_resolver::LockOptions;
 use uv_warnings::warn_user;
 
+use crate::commands::pip::operations::Modifications;
 use crate::commands::{project, ExitStatus};
 use crate::printer::Printer;
 
@@ -16,6 +19,8 @@ use crate::printer::Printer;
 pub(crate) async fn remove(
     requirements: Vec<PackageName>,
     python: Option<String>,
+    lock_options: LockOptions,
+    connectivity: Connectivity,
     preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
@@ -58,32 +63,40 @@ pub(crate) async fn remove(
         .as_ref()
         .map(|project| project.name.clone());
 
+    let existing = project::lock::read(project.workspace()).await?;
     let lock = project::lock::do_lock(
         root_project_name,
         project.workspace(),
         venv.interpreter(),
         &index_locations,
         upgrade,
+        vec![],
+        existing.as_ref(),
         exclude_newer,
+        lock_options,
+        false,
         preview,
         cache,
         printer,
     )
     .await?;
 
-    // Perform a full sync, because we don't know what exactly is affected by the removal.
+    // Perform a full, exact sync, because we don't know what exactly is affected by the removal,
+    // and any packages that are no longer required should be uninstalled.
     // TODO(ibraheem): Should we accept CLI overrides for this? Should we even sync here?
-    let extras = ExtrasSpecification::All;
-    let dev = true;
+    let extras = project::sync_extras(project.workspace(), project.project_name());
+    let dev = DevSpecification::Include(vec![DEV_DEPENDENCIES.clone()]);
 
     project::sync::do_sync(
-        project.project_name(),
-        project.workspace().root(),
+        project.workspace(),
+        std::slice::from_ref(project.project_name()),
         &venv,
         &lock,
         &index_locations,
         extras,
         dev,
+        Modifications::Exact,
+        connectivity,
         preview,
         cache,
         printer,
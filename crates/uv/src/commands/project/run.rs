@@ -1,23 +1,29 @@
-use std::ffi::OsString;
-use std::path::PathBuf;
+use std::ffi::{OsStr, OsString};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use distribution_types::IndexLocations;
 use itertools::Itertools;
+use owo_colors::OwoColorize;
 use tokio::process::Command;
 use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{ExtrasSpecification, PreviewMode, Upgrade};
+use uv_configuration::{DevSpecification, ExtrasSpecification, PreviewMode, Upgrade};
+use uv_distribution::script::Pep723Script;
 use uv_distribution::{ProjectWorkspace, Workspace};
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_requirements::RequirementsSource;
-use uv_resolver::ExcludeNewer;
+use uv_resolver::{ExcludeNewer, LockOptions};
 use uv_toolchain::{PythonEnvironment, SystemPython, Toolchain};
 use uv_warnings::warn_user;
 
-use crate::commands::{project, ExitStatus};
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::environment::CachedEnvironment;
+use crate::commands::{project, report_resolution_failure, ErrorFormat, ExitStatus};
 use crate::printer::Printer;
 
 /// Run a command.
@@ -25,15 +31,18 @@ use crate::printer::Printer;
 pub(crate) async fn run(
     index_locations: IndexLocations,
     extras: ExtrasSpecification,
-    dev: bool,
+    dev: DevSpecification,
     target: Option<String>,
     mut args: Vec<OsString>,
     requirements: Vec<RequirementsSource>,
     python: Option<String>,
     upgrade: Upgrade,
     exclude_newer: Option<ExcludeNewer>,
+    lock_options: LockOptions,
     package: Option<PackageName>,
+    all_packages: bool,
     isolated: bool,
+    error_format: ErrorFormat,
     preview: PreviewMode,
     connectivity: Connectivity,
     cache: &Cache,
@@ -45,65 +54,181 @@ pub(crate) async fn run(
         warn_user!("`uv run` is experimental and may change without warning.");
     }
 
+    // Determine whether the command to execute is a PEP 723 script.
+    let script = match target.as_deref().map(Path::new) {
+        Some(target_path) if is_python_script(target_path) => {
+            Pep723Script::read(target_path).await?
+        }
+        _ => None,
+    };
+    if let Some(script) = script.as_ref() {
+        writeln!(
+            printer.stderr(),
+            "Reading inline script metadata from: {}",
+            script.path.user_display().cyan()
+        )?;
+    }
+
+    if all_packages && script.is_some() {
+        anyhow::bail!("`--all-packages` cannot be used to run a script with inline metadata");
+    }
+
     // Discover and sync the project.
+    let mut members = Vec::new();
     let project_env = if isolated {
         // package is `None`, isolated and package are marked as conflicting in clap.
         None
+    } else if script.is_some() {
+        // Scripts with inline metadata run in their own environment, independent of any project.
+        None
     } else {
-        debug!("Syncing project environment.");
+        let (workspace, packages, root_project_name) = if all_packages {
+            debug!("Syncing workspace environment.");
 
-        let project = if let Some(package) = package {
             // We need a workspace, but we don't need to have a current package, we can be e.g. in
-            // the root of a virtual workspace and then switch into the selected package.
-            Workspace::discover(&std::env::current_dir()?, None)
-                .await?
-                .with_current_project(package.clone())
-                .with_context(|| format!("Package `{package}` not found in workspace"))?
+            // the root of a virtual workspace.
+            let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+            let packages = project::select_packages(&workspace, vec![], true)?;
+            let root_project_name = workspace.root_member().and_then(|member| {
+                member
+                    .pyproject_toml()
+                    .project
+                    .as_ref()
+                    .map(|project| project.name.clone())
+            });
+
+            // Run the command in each member of the workspace.
+            members = workspace
+                .packages()
+                .iter()
+                .map(|(name, member)| (name.clone(), member.root().clone()))
+                .collect();
+
+            (workspace, packages, root_project_name)
         } else {
-            ProjectWorkspace::discover(&std::env::current_dir()?, None).await?
+            debug!("Syncing project environment.");
+
+            let project = if let Some(package) = package {
+                // We need a workspace, but we don't need to have a current package, we can be e.g.
+                // in the root of a virtual workspace and then switch into the selected package.
+                Workspace::discover(&std::env::current_dir()?, None)
+                    .await?
+                    .with_current_project(package.clone())
+                    .with_context(|| format!("Package `{package}` not found in workspace"))?
+            } else {
+                ProjectWorkspace::discover(&std::env::current_dir()?, None).await?
+            };
+            let root_project_name = project
+                .current_project()
+                .pyproject_toml()
+                .project
+                .as_ref()
+                .map(|project| project.name.clone());
+
+            (
+                project.workspace().clone(),
+                vec![project.project_name().clone()],
+                root_project_name,
+            )
         };
-        let venv =
-            project::init_environment(project.workspace(), python.as_deref(), cache, printer)?;
+
+        // Validate that the requested dependency groups exist.
+        project::validate_dependency_groups(&workspace, &packages, &dev)?;
+
+        let venv = project::init_environment(&workspace, python.as_deref(), cache, printer)?;
 
         // Lock and sync the environment.
-        let root_project_name = project
-            .current_project()
-            .pyproject_toml()
-            .project
-            .as_ref()
-            .map(|project| project.name.clone());
-        let lock = project::lock::do_lock(
+        let existing = project::lock::read(&workspace).await?;
+        let lock = match project::lock::do_lock(
             root_project_name,
-            project.workspace(),
+            &workspace,
             venv.interpreter(),
             &index_locations,
             upgrade,
+            vec![],
+            existing.as_ref(),
             exclude_newer,
+            lock_options,
+            false,
             preview,
             cache,
             printer,
         )
-        .await?;
-        project::sync::do_sync(
-            project.project_name(),
-            project.workspace().root(),
+        .await
+        {
+            Ok(lock) => lock,
+            Err(err) => return report_resolution_failure(err.into(), error_format),
+        };
+        if let Err(err) = project::sync::do_sync(
+            &workspace,
+            &packages,
             &venv,
             &lock,
             &index_locations,
             extras,
             dev,
+            Modifications::Sufficient,
+            connectivity,
             preview,
             cache,
             printer,
         )
-        .await?;
+        .await
+        {
+            return report_resolution_failure(err.into(), error_format);
+        }
 
         Some(venv)
     };
 
     // If necessary, create an environment for the ephemeral requirements.
-    let temp_dir;
-    let ephemeral_env = if requirements.is_empty() {
+    let ephemeral_env = if let Some(script) = script.as_ref() {
+        debug!("Syncing script environment.");
+
+        // Discover an interpreter that satisfies the script's `requires-python`, unless an
+        // interpreter was requested explicitly.
+        let python_request = python.clone().or_else(|| {
+            script
+                .metadata
+                .requires_python
+                .as_ref()
+                .map(ToString::to_string)
+        });
+        let interpreter = Toolchain::find_or_fetch(
+            python_request.as_deref(),
+            SystemPython::Allowed,
+            PreviewMode::Enabled,
+            client_builder,
+            cache,
+        )
+        .await?
+        .into_interpreter();
+
+        // Install the script's dependencies, along with any ephemeral requirements.
+        let requirements = script
+            .metadata
+            .dependencies
+            .iter()
+            .flatten()
+            .map(|requirement| RequirementsSource::Package(requirement.to_string()))
+            .chain(requirements)
+            .collect::<Vec<_>>();
+
+        match CachedEnvironment::get_or_create(
+            &requirements,
+            interpreter,
+            &index_locations,
+            connectivity,
+            cache,
+            printer,
+            preview,
+        )
+        .await
+        {
+            Ok(environment) => Some(environment),
+            Err(err) => return report_resolution_failure(err, error_format),
+        }
+    } else if requirements.is_empty() {
         None
     } else {
         debug!("Syncing ephemeral environment.");
@@ -124,44 +249,38 @@ pub(crate) async fn run(
             .into_interpreter()
         };
 
-        // TODO(charlie): If the environment satisfies the requirements, skip creation.
         // TODO(charlie): Pass the already-installed versions as preferences, or even as the
         // "installed" packages, so that we can skip re-installing them in the ephemeral
         // environment.
 
-        // Create a virtual environment
-        temp_dir = cache.environment()?;
-        let venv = uv_virtualenv::create_venv(
-            temp_dir.path(),
+        // Install the ephemeral requirements into a cached environment.
+        match CachedEnvironment::get_or_create(
+            &requirements,
             interpreter,
-            uv_virtualenv::Prompt::None,
-            false,
-            false,
-        )?;
-
-        // Install the ephemeral requirements.
-        Some(
-            project::update_environment(
-                venv,
-                &requirements,
-                &index_locations,
-                connectivity,
-                cache,
-                printer,
-                preview,
-            )
-            .await?,
+            &index_locations,
+            connectivity,
+            cache,
+            printer,
+            preview,
         )
+        .await
+        {
+            Ok(environment) => Some(environment),
+            Err(err) => return report_resolution_failure(err, error_format),
+        }
     };
 
     // Construct the command
     let command = if let Some(target) = target {
         let target_path = PathBuf::from(&target);
-        if target_path
-            .extension()
-            .map_or(false, |ext| ext.eq_ignore_ascii_case("py"))
-            && target_path.exists()
-        {
+        if is_python_script(&target_path) {
+            // With `--all-packages`, the command runs from each member's directory, so a relative
+            // script path must be resolved against the current directory up-front.
+            let target_path = if members.is_empty() || target_path.is_absolute() {
+                target_path
+            } else {
+                std::env::current_dir()?.join(target_path)
+            };
             args.insert(0, target_path.as_os_str().into());
             "python".to_string()
         } else {
@@ -171,13 +290,11 @@ pub(crate) async fn run(
         "python".to_string()
     };
 
-    let mut process = Command::new(&command);
-    process.args(&args);
-
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
             .as_ref()
+            .map(CachedEnvironment::environment)
             .map(PythonEnvironment::scripts)
             .into_iter()
             .chain(
@@ -194,12 +311,12 @@ pub(crate) async fn run(
                     .flat_map(std::env::split_paths),
             ),
     )?;
-    process.env("PATH", new_path);
 
     // Construct the `PYTHONPATH` environment variable.
     let new_python_path = std::env::join_paths(
         ephemeral_env
             .as_ref()
+            .map(CachedEnvironment::environment)
             .map(PythonEnvironment::site_packages)
             .into_iter()
             .flatten()
@@ -218,7 +335,73 @@ pub(crate) async fn run(
                     .flat_map(std::env::split_paths),
             ),
     )?;
-    process.env("PYTHONPATH", new_python_path);
+
+    if members.is_empty() {
+        let status = run_command(&command, &args, &new_path, &new_python_path, None).await?;
+
+        // Exit based on the result of the command
+        // TODO(zanieb): Do we want to exit with the code of the child process? Probably.
+        return if status.success() {
+            Ok(ExitStatus::Success)
+        } else {
+            Ok(ExitStatus::Failure)
+        };
+    }
+
+    // Run the command in each workspace member, from the member's directory.
+    let mut statuses = Vec::with_capacity(members.len());
+    for (name, root) in &members {
+        writeln!(
+            printer.stderr(),
+            "Running in {} ({})",
+            name.cyan(),
+            root.simplified_display().cyan()
+        )?;
+        let status = run_command(
+            &command,
+            &args,
+            &new_path,
+            &new_python_path,
+            Some(root.as_path()),
+        )
+        .await?;
+        statuses.push((name, status));
+    }
+
+    // Report the result of the command for each member.
+    for (name, status) in &statuses {
+        match status.code() {
+            Some(code) => writeln!(printer.stderr(), "{} exited with code {code}", name.bold())?,
+            None => writeln!(
+                printer.stderr(),
+                "{} was terminated by a signal",
+                name.bold()
+            )?,
+        }
+    }
+
+    if statuses.iter().all(|(_, status)| status.success()) {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Run the command with the given `PATH` and `PYTHONPATH`, optionally from the given directory.
+async fn run_command(
+    command: &str,
+    args: &[OsString],
+    path: &OsStr,
+    python_path: &OsStr,
+    cwd: Option<&Path>,
+) -> Result<std::process::ExitStatus> {
+    let mut process = Command::new(command);
+    process.args(args);
+    process.env("PATH", path);
+    process.env("PYTHONPATH", python_path);
+    if let Some(cwd) = cwd {
+        process.current_dir(cwd);
+    }
 
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
@@ -231,13 +414,13 @@ pub(crate) async fn run(
     let mut handle = process
         .spawn()
         .with_context(|| format!("Failed to spawn: `{command}`"))?;
-    let status = handle.wait().await.context("Child process disappeared")?;
+    handle.wait().await.context("Child process disappeared")
+}
 
-    // Exit based on the result of the command
-    // TODO(zanieb): Do we want to exit with the code of the child process? Probably.
-    if status.success() {
-        Ok(ExitStatus::Success)
-    } else {
-        Ok(ExitStatus::Failure)
-    }
+/// Returns `true` if the target is an existing Python script (i.e., a `.py` file).
+fn is_python_script(target: &Path) -> bool {
+    target
+        .extension()
+        .map_or(false, |ext| ext.eq_ignore_ascii_case("py"))
+        && target.exists()
 }
@@ -1,27 +1,26 @@
 use anyhow::Result;
-use std::path::Path;
 
 use distribution_types::IndexLocations;
 use install_wheel_rs::linker::LinkMode;
 use uv_cache::Cache;
-use uv_client::{FlatIndexClient, RegistryClientBuilder};
+use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, PreviewMode, Reinstall,
-    SetupPyStrategy,
+    BuildOptions, Concurrency, ConfigSettings, DevSpecification, ExtrasSpecification,
+    FileConflictStrategy, PreviewMode, Reinstall, SetupPyStrategy,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution::{ProjectWorkspace, DEV_DEPENDENCIES};
+use uv_distribution::{CachedFlatIndex, ProjectWorkspace, Workspace};
 use uv_git::GitResolver;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
-use uv_resolver::{FlatIndex, InMemoryIndex, Lock};
+use uv_resolver::{FlatIndex, InMemoryIndex, Lock, LockOptions};
 use uv_toolchain::PythonEnvironment;
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::ProjectError;
-use crate::commands::{pip, project, ExitStatus};
+use crate::commands::{pip, project, report_resolution_failure, ErrorFormat, ExitStatus};
 use crate::printer::Printer;
 
 /// Sync the project environment.
@@ -29,8 +28,14 @@ use crate::printer::Printer;
 pub(crate) async fn sync(
     index_locations: IndexLocations,
     extras: ExtrasSpecification,
-    dev: bool,
+    dev: DevSpecification,
+    modifications: Modifications,
     python: Option<String>,
+    lock_options: LockOptions,
+    packages: Vec<PackageName>,
+    all_packages: bool,
+    connectivity: Connectivity,
+    error_format: ErrorFormat,
     preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
@@ -39,47 +44,77 @@ pub(crate) async fn sync(
         warn_user!("`uv sync` is experimental and may change without warning.");
     }
 
-    // Find the project requirements.
-    let project = ProjectWorkspace::discover(&std::env::current_dir()?, None).await?;
+    // Find the workspace members to sync: either the current project, or the requested members.
+    let (workspace, packages) = if all_packages || !packages.is_empty() {
+        // We need a workspace, but we don't need to have a current package, we can be e.g. in the
+        // root of a virtual workspace.
+        let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+        let packages = project::select_packages(&workspace, packages, all_packages)?;
+        (workspace, packages)
+    } else {
+        let project = ProjectWorkspace::discover(&std::env::current_dir()?, None).await?;
+        let packages = vec![project.project_name().clone()];
+        (project.workspace().clone(), packages)
+    };
+
+    // Validate that the requested dependency groups exist.
+    project::validate_dependency_groups(&workspace, &packages, &dev)?;
 
     // Discover or create the virtual environment.
-    let venv = project::init_environment(project.workspace(), python.as_deref(), cache, printer)?;
+    let venv = project::init_environment(&workspace, python.as_deref(), cache, printer)?;
 
     // Read the lockfile.
     let lock: Lock = {
-        let encoded =
-            fs_err::tokio::read_to_string(project.workspace().root().join("uv.lock")).await?;
-        toml::from_str(&encoded)?
+        let encoded = fs_err::tokio::read_to_string(workspace.root().join("uv.lock")).await?;
+        Lock::from_toml(&encoded)?
     };
 
+    // Warn if the lockfile was generated with different resolver settings than those configured
+    // for the workspace, since the environment won't reflect them until the project is re-locked.
+    if lock
+        .options()
+        .is_some_and(|options| *options != lock_options)
+    {
+        warn_user!(
+            "The lockfile was generated with different resolver settings than those in the workspace configuration; run `uv lock` to update it."
+        );
+    }
+
     // Perform the sync operation.
-    do_sync(
-        project.project_name(),
-        project.workspace().root(),
+    if let Err(err) = do_sync(
+        &workspace,
+        &packages,
         &venv,
         &lock,
         &index_locations,
         extras,
         dev,
+        modifications,
+        connectivity,
         preview,
         cache,
         printer,
     )
-    .await?;
+    .await
+    {
+        return report_resolution_failure(err.into(), error_format);
+    }
 
     Ok(ExitStatus::Success)
 }
 
-/// Sync a lockfile with an environment.
+/// Sync a lockfile with an environment, installing the given workspace members.
 #[allow(clippy::too_many_arguments)]
 pub(super) async fn do_sync(
-    project_name: &PackageName,
-    workspace_root: &Path,
+    workspace: &Workspace,
+    packages: &[PackageName],
     venv: &PythonEnvironment,
     lock: &Lock,
     index_locations: &IndexLocations,
     extras: ExtrasSpecification,
-    dev: bool,
+    dev: DevSpecification,
+    modifications: Modifications,
+    connectivity: Connectivity,
     preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
@@ -94,23 +129,36 @@ pub(super) async fn do_sync(
         }
     }
 
-    // Include development dependencies, if requested.
-    let dev = if dev {
-        vec![DEV_DEPENDENCIES.clone()]
-    } else {
-        vec![]
-    };
-
     let markers = venv.interpreter().markers();
     let tags = venv.interpreter().tags()?;
 
+    // Validate that the current environment is supported by the lockfile.
+    if !lock.environments().is_empty()
+        && !lock
+            .environments()
+            .iter()
+            .any(|environment| environment.evaluate(markers, &[]))
+    {
+        return Err(ProjectError::UnsupportedEnvironment(
+            lock.environments().to_vec(),
+        ));
+    }
+
     // Read the lockfile.
-    let resolution =
-        lock.to_resolution(workspace_root, markers, tags, project_name, &extras, &dev)?;
+    let resolution = lock.to_resolution(
+        workspace.root(),
+        markers,
+        tags,
+        packages,
+        &extras,
+        &dev,
+        &workspace.conflicts(),
+    )?;
 
     // Initialize the registry client.
-    // TODO(zanieb): Support client options e.g. offline, tls, etc.
+    // TODO(zanieb): Support client options e.g. tls, etc.
     let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .markers(markers)
         .platform(venv.interpreter().platform())
@@ -119,6 +167,7 @@ pub(super) async fn do_sync(
     // TODO(charlie): Respect project configuration.
     let build_isolation = BuildIsolation::default();
     let compile = false;
+    let compile_optimization = Vec::default();
     let concurrency = Concurrency::default();
     let config_settings = ConfigSettings::default();
     let dry_run = false;
@@ -127,6 +176,7 @@ pub(super) async fn do_sync(
     let in_flight = InFlight::default();
     let index = InMemoryIndex::default();
     let link_mode = LinkMode::default();
+    let on_conflict = FileConflictStrategy::default();
     let build_options = BuildOptions::default();
     let reinstall = Reinstall::default();
     let setup_py = SetupPyStrategy::default();
@@ -134,7 +184,13 @@ pub(super) async fn do_sync(
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, cache);
-        let entries = client.fetch(index_locations.flat_index()).await?;
+        let mut entries = client.fetch(index_locations.flat_index()).await?;
+
+        // When offline, treat any wheels in the cache as available.
+        if matches!(connectivity, Connectivity::Offline) {
+            entries.extend(CachedFlatIndex::entries(cache, index_locations));
+        }
+
         FlatIndex::from_entries(entries, Some(tags), &hasher, &build_options)
     };
 
@@ -163,11 +219,13 @@ pub(super) async fn do_sync(
     pip::operations::install(
         &resolution,
         site_packages,
-        Modifications::Sufficient,
+        modifications,
         &reinstall,
         &build_options,
         link_mode,
+        on_conflict,
         compile,
+        &compile_optimization,
         index_locations,
         &hasher,
         tags,
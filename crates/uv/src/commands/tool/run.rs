@@ -14,8 +14,8 @@ use uv_requirements::RequirementsSource;
 use uv_toolchain::{PythonEnvironment, SystemPython, Toolchain};
 use uv_warnings::warn_user;
 
-use crate::commands::project::update_environment;
-use crate::commands::ExitStatus;
+use crate::commands::project::environment::CachedEnvironment;
+use crate::commands::{report_resolution_failure, ErrorFormat, ExitStatus};
 use crate::printer::Printer;
 
 /// Run a command.
@@ -27,6 +27,7 @@ pub(crate) async fn run(
     from: Option<String>,
     with: Vec<String>,
     _isolated: bool,
+    error_format: ErrorFormat,
     preview: PreviewMode,
     index_locations: IndexLocations,
     connectivity: Connectivity,
@@ -60,29 +61,21 @@ pub(crate) async fn run(
     )?
     .into_interpreter();
 
-    // Create a virtual environment.
-    let temp_dir = cache.environment()?;
-    let venv = uv_virtualenv::create_venv(
-        temp_dir.path(),
+    // Install the ephemeral requirements into a cached environment.
+    let ephemeral_env = match CachedEnvironment::get_or_create(
+        &requirements,
         interpreter,
-        uv_virtualenv::Prompt::None,
-        false,
-        false,
-    )?;
-
-    // Install the ephemeral requirements.
-    let ephemeral_env = Some(
-        update_environment(
-            venv,
-            &requirements,
-            &index_locations,
-            connectivity,
-            cache,
-            printer,
-            preview,
-        )
-        .await?,
-    );
+        &index_locations,
+        connectivity,
+        cache,
+        printer,
+        preview,
+    )
+    .await
+    {
+        Ok(environment) => Some(environment),
+        Err(err) => return report_resolution_failure(err, error_format),
+    };
 
     // TODO(zanieb): Determine the command via the package entry points
     let command = target;
@@ -95,6 +88,7 @@ pub(crate) async fn run(
     let new_path = std::env::join_paths(
         ephemeral_env
             .as_ref()
+            .map(CachedEnvironment::environment)
             .map(PythonEnvironment::scripts)
             .into_iter()
             .map(PathBuf::from)
@@ -111,6 +105,7 @@ pub(crate) async fn run(
     let new_python_path = std::env::join_paths(
         ephemeral_env
             .as_ref()
+            .map(CachedEnvironment::environment)
             .map(PythonEnvironment::site_packages)
             .into_iter()
             .flatten()
@@ -1,5 +1,6 @@
+use std::borrow::Cow;
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::vec;
 
@@ -8,8 +9,9 @@ use anyhow::Result;
 use miette::{Diagnostic, IntoDiagnostic};
 use owo_colors::OwoColorize;
 use thiserror::Error;
+use tracing::debug;
 
-use distribution_types::IndexLocations;
+use distribution_types::{Diagnostic, IndexLocations};
 use install_wheel_rs::linker::LinkMode;
 use pypi_types::Requirement;
 use uv_auth::store_credentials_from_url;
@@ -22,9 +24,11 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
 use uv_git::GitResolver;
+use uv_installer::{SitePackages, SitePackagesDiagnostic};
 use uv_resolver::{ExcludeNewer, FlatIndex, InMemoryIndex, OptionsBuilder};
-use uv_toolchain::{SystemPython, Toolchain};
+use uv_toolchain::{Interpreter, PyVenvConfiguration, PythonEnvironment, SystemPython, Toolchain};
 use uv_types::{BuildContext, BuildIsolation, HashStrategy, InFlight};
+use uv_warnings::warn_user;
 
 use crate::commands::{pip, ExitStatus};
 use crate::printer::Printer;
@@ -48,6 +52,8 @@ pub(crate) async fn venv(
     connectivity: Connectivity,
     seed: bool,
     allow_existing: bool,
+    clear: bool,
+    upgrade: bool,
     exclude_newer: Option<ExcludeNewer>,
     native_tls: bool,
     preview: PreviewMode,
@@ -67,6 +73,8 @@ pub(crate) async fn venv(
         seed,
         preview,
         allow_existing,
+        clear,
+        upgrade,
         exclude_newer,
         native_tls,
         cache,
@@ -88,6 +96,10 @@ enum VenvError {
     #[diagnostic(code(uv::venv::creation))]
     Creation(#[source] uv_virtualenv::Error),
 
+    #[error("The directory `{}` exists, but it's not a virtualenv; refusing to clear it", _0.user_display())]
+    #[diagnostic(code(uv::venv::clear))]
+    Clear(PathBuf),
+
     #[error("Failed to install seed packages")]
     #[diagnostic(code(uv::venv::seed))]
     Seed(#[source] anyhow::Error),
@@ -99,6 +111,18 @@ enum VenvError {
     #[error("Failed to resolve `--find-links` entry")]
     #[diagnostic(code(uv::venv::flat_index))]
     FlatIndex(#[source] uv_client::FlatIndexError),
+
+    #[error("Failed to upgrade virtualenv")]
+    #[diagnostic(code(uv::venv::upgrade))]
+    Upgrade(#[source] uv_virtualenv::Error),
+
+    #[error("Failed to query the upgraded virtualenv")]
+    #[diagnostic(code(uv::venv::query))]
+    Query(#[source] uv_toolchain::Error),
+
+    #[error("Failed to validate the installed packages")]
+    #[diagnostic(code(uv::venv::validate))]
+    Validate(#[source] anyhow::Error),
 }
 
 /// Create a virtual environment.
@@ -116,6 +140,8 @@ async fn venv_impl(
     seed: bool,
     preview: PreviewMode,
     allow_existing: bool,
+    clear: bool,
+    upgrade: bool,
     exclude_newer: Option<ExcludeNewer>,
     native_tls: bool,
     cache: &Cache,
@@ -125,9 +151,19 @@ async fn venv_impl(
         .connectivity(connectivity)
         .native_tls(native_tls);
 
+    // When upgrading, default to the minor Python version of the existing environment.
+    let python_request = match python_request {
+        Some(request) => Some(Cow::Borrowed(request)),
+        None if upgrade => PyVenvConfiguration::parse(path.join("pyvenv.cfg"))
+            .ok()
+            .and_then(|cfg| cfg.python_tuple())
+            .map(|(major, minor)| Cow::Owned(format!("{major}.{minor}"))),
+        None => None,
+    };
+
     // Locate the Python interpreter to use in the environment
     let interpreter = Toolchain::find_or_fetch(
-        python_request,
+        python_request.as_deref(),
         SystemPython::Required,
         preview,
         client_builder,
@@ -150,6 +186,16 @@ async fn venv_impl(
     )
     .into_diagnostic()?;
 
+    if upgrade {
+        return venv_upgrade(path, interpreter, cache, printer);
+    }
+
+    // If requested, clear any existing virtual environment at the target path, preserving the
+    // directory itself.
+    if clear {
+        clear_venv(path)?;
+    }
+
     writeln!(
         printer.stderr(),
         "Creating virtualenv at: {}",
@@ -296,6 +342,84 @@ async fn venv_impl(
     Ok(ExitStatus::Success)
 }
 
+/// Upgrade the virtual environment at the given path in-place to use the given interpreter.
+fn venv_upgrade(
+    path: &Path,
+    interpreter: Interpreter,
+    cache: &Cache,
+    printer: Printer,
+) -> miette::Result<ExitStatus> {
+    writeln!(
+        printer.stderr(),
+        "Upgrading virtualenv at: {}",
+        path.user_display().cyan()
+    )
+    .into_diagnostic()?;
+
+    // Repoint the environment at the new interpreter.
+    uv_virtualenv::upgrade_venv(path, interpreter).map_err(VenvError::Upgrade)?;
+
+    // Re-query the interpreter from within the environment, to pick up any changes to its paths
+    // and markers.
+    let venv = PythonEnvironment::from_root(path, cache).map_err(VenvError::Query)?;
+
+    // Revalidate the installed packages against the new interpreter.
+    let site_packages = SitePackages::from_executable(&venv).map_err(VenvError::Validate)?;
+    let diagnostics = site_packages
+        .diagnostics()
+        .map_err(VenvError::Validate)?
+        .into_iter()
+        .filter(|diagnostic| {
+            matches!(
+                diagnostic,
+                SitePackagesDiagnostic::IncompatiblePythonVersion { .. }
+            )
+        })
+        .chain(
+            site_packages
+                .platform_diagnostics()
+                .map_err(VenvError::Validate)?,
+        );
+    for diagnostic in diagnostics {
+        warn_user!("{}", diagnostic.message());
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove the contents of the virtual environment at the given path, if any, leaving an empty
+/// directory in its place.
+///
+/// Unlike removing the directory, this preserves the directory itself, e.g., if it's a mount point
+/// or a symlink to a virtual environment elsewhere.
+///
+/// To avoid deleting arbitrary directories (e.g., `uv venv --clear .`), the contents are only
+/// removed if it's an existing virtual environment (i.e., it contains a `pyvenv.cfg`).
+fn clear_venv(path: &Path) -> miette::Result<()> {
+    let entries = match fs_err::read_dir(path) {
+        Ok(entries) => entries.collect::<Result<Vec<_>, _>>().into_diagnostic()?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err).into_diagnostic(),
+    };
+    if entries.is_empty() {
+        return Ok(());
+    }
+    if !path.join("pyvenv.cfg").is_file() {
+        return Err(VenvError::Clear(path.to_path_buf()).into());
+    }
+    for entry in entries {
+        let entry_path = entry.path();
+        // Remove symlinks themselves, rather than following them.
+        if entry.file_type().into_diagnostic()?.is_dir() {
+            fs_err::remove_dir_all(&entry_path).into_diagnostic()?;
+        } else {
+            fs_err::remove_file(&entry_path).into_diagnostic()?;
+        }
+    }
+    debug!("Cleared existing virtualenv at: {}", path.user_display());
+    Ok(())
+}
+
 /// Quote a path, if necessary, for safe use in a POSIX-compatible shell command.
 fn shlex_posix(executable: impl AsRef<Path>) -> String {
     // Convert to a display path.
@@ -300,9 +300,6 @@ enum Resolver {
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub(crate) struct VenvCompatArgs {
-    #[clap(long, hide = true)]
-    clear: bool,
-
     #[clap(long, hide = true)]
     no_seed: bool,
 
@@ -323,12 +320,6 @@ impl CompatArgs for VenvCompatArgs {
     /// behavior. If an argument is passed that does _not_ match uv's behavior, this method will
     /// return an error.
     fn validate(&self) -> Result<()> {
-        if self.clear {
-            warn_user!(
-                "virtualenv's `--clear` has no effect (uv always clears the virtual environment)."
-            );
-        }
-
         if self.no_seed {
             warn_user!(
                 "virtualenv's `--no-seed` has no effect (uv omits seed packages by default)."
@@ -352,29 +343,3 @@ impl CompatArgs for VenvCompatArgs {
         Ok(())
     }
 }
-
-/// Arguments for `pip install` compatibility.
-///
-/// These represent a subset of the `pip install` interface that uv supports by default.
-#[derive(Args)]
-#[allow(clippy::struct_excessive_bools)]
-pub(crate) struct PipInstallCompatArgs {
-    #[clap(long, hide = false)]
-    user: bool,
-}
-
-impl CompatArgs for PipInstallCompatArgs {
-    /// Validate the arguments passed for `pip install` compatibility.
-    ///
-    /// This method will warn when an argument is passed that has no effect but matches uv's
-    /// behavior. If an argument is passed that does _not_ match uv's behavior, this method will
-    /// return an error.
-    fn validate(&self) -> Result<()> {
-        if self.user {
-            return Err(anyhow!(
-                "pip install's `--user` is unsupported (use a virtual environment instead)."
-            ));
-        }
-        Ok(())
-    }
-}
@@ -12,6 +12,7 @@ use tracing::{debug, instrument};
 
 use cli::{ToolCommand, ToolNamespace, ToolchainCommand, ToolchainNamespace};
 use uv_cache::Cache;
+use uv_normalize::PackageName;
 use uv_requirements::RequirementsSource;
 use uv_workspace::Combine;
 
@@ -22,6 +23,7 @@ use crate::cli::{
 use crate::cli::{SelfCommand, SelfNamespace};
 use crate::commands::ExitStatus;
 use crate::compat::CompatArgs;
+use crate::printer::Printer;
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
     PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
@@ -188,75 +190,19 @@ async fn run() -> Result<ExitStatus> {
 
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = PipCompileSettings::resolve(args, workspace);
-            rayon::ThreadPoolBuilder::new()
-                .num_threads(args.pip.concurrency.installs)
-                .build_global()
-                .expect("failed to initialize global rayon pool");
 
-            // Initialize the cache.
-            let cache = cache.init()?.with_refresh(args.refresh);
+            pip_compile(args, None, &globals, cache, printer).await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Why(args),
+        }) => {
+            args.compile.compat_args.validate()?;
 
-            let requirements = args
-                .src_file
-                .into_iter()
-                .map(RequirementsSource::from_requirements_file)
-                .collect::<Vec<_>>();
-            let constraints = args
-                .constraint
-                .into_iter()
-                .map(RequirementsSource::from_constraints_txt)
-                .collect::<Vec<_>>();
-            let overrides = args
-                .r#override
-                .into_iter()
-                .map(RequirementsSource::from_overrides_txt)
-                .collect::<Vec<_>>();
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let package = args.package;
+            let args = PipCompileSettings::resolve(args.compile, workspace);
 
-            commands::pip_compile(
-                &requirements,
-                &constraints,
-                &overrides,
-                args.overrides_from_workspace,
-                args.pip.extras,
-                args.pip.output_file.as_deref(),
-                args.pip.resolution,
-                args.pip.prerelease,
-                args.pip.dependency_mode,
-                args.upgrade,
-                args.pip.generate_hashes,
-                args.pip.no_emit_package,
-                args.pip.no_strip_extras,
-                !args.pip.no_annotate,
-                !args.pip.no_header,
-                args.pip.custom_compile_command,
-                args.pip.emit_index_url,
-                args.pip.emit_find_links,
-                args.pip.emit_marker_expression,
-                args.pip.emit_index_annotation,
-                args.pip.index_locations,
-                args.pip.index_strategy,
-                args.pip.keyring_provider,
-                args.pip.setup_py,
-                args.pip.config_setting,
-                globals.connectivity,
-                args.pip.no_build_isolation,
-                args.pip.no_build,
-                args.pip.no_binary,
-                args.pip.python_version,
-                args.pip.python_platform,
-                args.pip.exclude_newer,
-                args.pip.annotation_style,
-                args.pip.link_mode,
-                args.pip.python,
-                args.pip.system,
-                args.pip.concurrency,
-                globals.native_tls,
-                globals.quiet,
-                globals.preview,
-                cache,
-                printer,
-            )
-            .await
+            pip_compile(args, Some(&package), &globals, cache, printer).await
         }
         Commands::Pip(PipNamespace {
             command: PipCommand::Sync(args),
@@ -289,7 +235,9 @@ async fn run() -> Result<ExitStatus> {
                 &constraints,
                 &args.reinstall,
                 args.pip.link_mode,
+                args.pip.on_conflict,
                 args.pip.compile_bytecode,
+                &args.pip.compile_optimization,
                 args.pip.require_hashes,
                 args.pip.index_locations,
                 args.pip.index_strategy,
@@ -311,6 +259,7 @@ async fn run() -> Result<ExitStatus> {
                 args.pip.prefix,
                 args.pip.concurrency,
                 globals.native_tls,
+                globals.error_format,
                 globals.preview,
                 cache,
                 args.dry_run,
@@ -321,8 +270,6 @@ async fn run() -> Result<ExitStatus> {
         Commands::Pip(PipNamespace {
             command: PipCommand::Install(args),
         }) => {
-            args.compat_args.validate()?;
-
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = PipInstallSettings::resolve(args, workspace);
             rayon::ThreadPoolBuilder::new()
@@ -360,8 +307,11 @@ async fn run() -> Result<ExitStatus> {
                 &overrides,
                 args.overrides_from_workspace,
                 &args.pip.extras,
+                &args.groups,
                 args.pip.resolution,
+                args.pip.resolution_package,
                 args.pip.prerelease,
+                args.pip.prerelease_package,
                 args.pip.dependency_mode,
                 args.upgrade,
                 args.pip.index_locations,
@@ -369,7 +319,9 @@ async fn run() -> Result<ExitStatus> {
                 args.pip.keyring_provider,
                 args.reinstall,
                 args.pip.link_mode,
+                args.pip.on_conflict,
                 args.pip.compile_bytecode,
+                &args.pip.compile_optimization,
                 args.pip.require_hashes,
                 args.pip.setup_py,
                 globals.connectivity,
@@ -386,8 +338,11 @@ async fn run() -> Result<ExitStatus> {
                 args.pip.break_system_packages,
                 args.pip.target,
                 args.pip.prefix,
+                args.pip.user,
+                args.pip.root,
                 args.pip.concurrency,
                 globals.native_tls,
+                globals.error_format,
                 globals.preview,
                 cache,
                 args.dry_run,
@@ -552,6 +507,8 @@ async fn run() -> Result<ExitStatus> {
                 globals.connectivity,
                 args.seed,
                 args.allow_existing,
+                args.clear,
+                args.upgrade,
                 args.pip.exclude_newer,
                 globals.native_tls,
                 globals.preview,
@@ -597,8 +554,11 @@ async fn run() -> Result<ExitStatus> {
                 args.python,
                 args.upgrade,
                 args.exclude_newer,
+                args.lock_options,
                 args.package,
+                args.all_packages,
                 globals.isolated,
+                globals.error_format,
                 globals.preview,
                 globals.connectivity,
                 &cache,
@@ -617,7 +577,13 @@ async fn run() -> Result<ExitStatus> {
                 args.index_locations,
                 args.extras,
                 args.dev,
+                args.modifications,
                 args.python,
+                args.lock_options,
+                args.package,
+                args.all_packages,
+                globals.connectivity,
+                globals.error_format,
                 globals.preview,
                 &cache,
                 printer,
@@ -634,8 +600,13 @@ async fn run() -> Result<ExitStatus> {
             commands::lock(
                 args.index_locations,
                 args.upgrade,
+                args.from,
                 args.exclude_newer,
+                args.lock_options,
                 args.python,
+                args.dry_run,
+                args.check_format,
+                globals.error_format,
                 globals.preview,
                 &cache,
                 printer,
@@ -652,6 +623,9 @@ async fn run() -> Result<ExitStatus> {
             commands::add(
                 args.requirements,
                 args.python,
+                args.script,
+                args.lock_options,
+                globals.connectivity,
                 globals.preview,
                 &cache,
                 printer,
@@ -668,6 +642,8 @@ async fn run() -> Result<ExitStatus> {
             commands::remove(
                 args.requirements,
                 args.python,
+                args.lock_options,
+                globals.connectivity,
                 globals.preview,
                 &cache,
                 printer,
@@ -702,6 +678,7 @@ async fn run() -> Result<ExitStatus> {
                 args.from,
                 args.with,
                 globals.isolated,
+                globals.error_format,
                 globals.preview,
                 args.index_locations,
                 globals.connectivity,
@@ -751,6 +728,90 @@ async fn run() -> Result<ExitStatus> {
     }
 }
 
+/// Run `uv pip compile` with the given settings; or, if a package is provided, explain why that
+/// package is included in the resolution (as in `uv pip why`).
+async fn pip_compile(
+    args: PipCompileSettings,
+    why: Option<&PackageName>,
+    globals: &GlobalSettings,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(args.pip.concurrency.installs)
+        .build_global()
+        .expect("failed to initialize global rayon pool");
+
+    // Initialize the cache.
+    let cache = cache.init()?.with_refresh(args.refresh);
+
+    let requirements = args
+        .src_file
+        .into_iter()
+        .map(RequirementsSource::from_requirements_file)
+        .collect::<Vec<_>>();
+    let constraints = args
+        .constraint
+        .into_iter()
+        .map(RequirementsSource::from_constraints_txt)
+        .collect::<Vec<_>>();
+    let overrides = args
+        .r#override
+        .into_iter()
+        .map(RequirementsSource::from_overrides_txt)
+        .collect::<Vec<_>>();
+
+    commands::pip_compile(
+        &requirements,
+        &constraints,
+        &overrides,
+        args.overrides_from_workspace,
+        args.pip.extras,
+        args.pip.output_file.as_deref(),
+        why,
+        args.pip.resolution,
+        args.pip.resolution_package,
+        args.pip.prerelease,
+        args.pip.prerelease_package,
+        args.pip.dependency_mode,
+        args.upgrade,
+        args.pip.generate_hashes,
+        args.pip.no_emit_package,
+        args.pip.no_strip_extras,
+        !args.pip.no_annotate,
+        !args.pip.no_header,
+        args.pip.custom_compile_command,
+        args.pip.emit_index_url,
+        args.pip.emit_find_links,
+        args.pip.emit_marker_expression,
+        args.pip.emit_index_annotation,
+        args.pip.index_locations,
+        args.pip.index_strategy,
+        args.pip.keyring_provider,
+        args.pip.setup_py,
+        args.pip.config_setting,
+        globals.connectivity,
+        args.pip.no_build_isolation,
+        args.pip.no_build,
+        args.pip.no_binary,
+        args.pip.python_version,
+        args.pip.python_platform,
+        args.pip.exclude_newer,
+        args.pip.annotation_style,
+        args.pip.link_mode,
+        args.pip.python,
+        args.pip.system,
+        args.pip.concurrency,
+        globals.native_tls,
+        globals.quiet,
+        globals.error_format,
+        globals.preview,
+        cache,
+        printer,
+    )
+    .await
+}
+
 fn main() -> ExitCode {
     let result = if let Ok(stack_size) = env::var("UV_STACK_SIZE") {
         // Artificially limit the stack size to test for stack overflows. Windows has a default stack size of 1MB,
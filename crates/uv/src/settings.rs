@@ -12,20 +12,25 @@ use pypi_types::Requirement;
 use uv_cache::{CacheArgs, Refresh};
 use uv_client::Connectivity;
 use uv_configuration::{
-    Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy, KeyringProviderType, NoBinary,
-    NoBuild, PreviewMode, Reinstall, SetupPyStrategy, TargetTriple, Upgrade,
+    BytecodeOptimization, Concurrency, ConfigSettings, DevSpecification, ExtrasSpecification,
+    FileConflictStrategy, IndexStrategy, KeyringProviderType, NoBinary, NoBuild, PreviewMode,
+    Reinstall, SetupPyStrategy, TargetTriple, Upgrade,
 };
-use uv_normalize::PackageName;
-use uv_resolver::{AnnotationStyle, DependencyMode, ExcludeNewer, PreReleaseMode, ResolutionMode};
-use uv_toolchain::{Prefix, PythonVersion, Target};
-use uv_workspace::{Combine, PipOptions, Workspace};
+use uv_distribution::DEV_DEPENDENCIES;
+use uv_normalize::{GroupName, PackageName};
+use uv_resolver::{
+    AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, LockOptions,
+    PackageOverrides, PreReleaseMode, ResolutionMode,
+};
+use uv_toolchain::{Prefix, PythonVersion, Root, Target};
+use uv_workspace::{Combine, Options, PipOptions, Workspace};
 
 use crate::cli::{
     AddArgs, ColorChoice, GlobalArgs, IndexArgs, LockArgs, Maybe, PipCheckArgs, PipCompileArgs,
     PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipUninstallArgs,
     RemoveArgs, RunArgs, SyncArgs, ToolRunArgs, ToolchainInstallArgs, ToolchainListArgs, VenvArgs,
 };
-use crate::commands::ListFormat;
+use crate::commands::{ErrorFormat, ListFormat, Modifications};
 
 /// The resolved global settings to use for any invocation of the CLI.
 #[allow(clippy::struct_excessive_bools)]
@@ -34,6 +39,7 @@ pub(crate) struct GlobalSettings {
     pub(crate) quiet: bool,
     pub(crate) verbose: u8,
     pub(crate) color: ColorChoice,
+    pub(crate) error_format: ErrorFormat,
     pub(crate) native_tls: bool,
     pub(crate) connectivity: Connectivity,
     pub(crate) isolated: bool,
@@ -63,6 +69,7 @@ impl GlobalSettings {
             } else {
                 args.color
             },
+            error_format: args.error_format,
             native_tls: flag(args.native_tls, args.no_native_tls)
                 .combine(workspace.and_then(|workspace| workspace.options.native_tls))
                 .unwrap_or(false),
@@ -113,7 +120,7 @@ impl CacheSettings {
 pub(crate) struct RunSettings {
     pub(crate) index_locations: IndexLocations,
     pub(crate) extras: ExtrasSpecification,
-    pub(crate) dev: bool,
+    pub(crate) dev: DevSpecification,
     pub(crate) target: Option<String>,
     pub(crate) args: Vec<OsString>,
     pub(crate) with: Vec<String>,
@@ -121,19 +128,23 @@ pub(crate) struct RunSettings {
     pub(crate) refresh: Refresh,
     pub(crate) upgrade: Upgrade,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) lock_options: LockOptions,
     pub(crate) package: Option<PackageName>,
+    pub(crate) all_packages: bool,
 }
 
 impl RunSettings {
     /// Resolve the [`RunSettings`] from the CLI and workspace configuration.
-    #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: RunArgs, _workspace: Option<Workspace>) -> Self {
+    pub(crate) fn resolve(args: RunArgs, workspace: Option<Workspace>) -> Self {
         let RunArgs {
             extra,
             all_extras,
             no_all_extras,
             dev,
             no_dev,
+            group,
+            no_group,
+            only_group,
             target,
             args,
             with,
@@ -147,6 +158,7 @@ impl RunSettings {
             python,
             exclude_newer,
             package,
+            all_packages,
         } = args;
 
         Self {
@@ -157,13 +169,32 @@ impl RunSettings {
                 flag(all_extras, no_all_extras).unwrap_or_default(),
                 extra.unwrap_or_default(),
             ),
-            dev: flag(dev, no_dev).unwrap_or(true),
+            dev: DevSpecification::from_args(
+                if flag(dev, no_dev).unwrap_or(true) {
+                    vec![DEV_DEPENDENCIES.clone()]
+                } else {
+                    vec![]
+                },
+                group.unwrap_or_default(),
+                no_group.unwrap_or_default(),
+                only_group.unwrap_or_default(),
+            ),
             target,
             args,
             with,
             python,
-            exclude_newer,
+            exclude_newer: exclude_newer.map(ExcludeNewer::from),
+            lock_options: lock_options(
+                None,
+                None,
+                None,
+                None,
+                &workspace
+                    .map(|workspace| workspace.options)
+                    .unwrap_or_default(),
+            ),
             package,
+            all_packages,
         }
     }
 }
@@ -268,27 +299,43 @@ pub(crate) struct SyncSettings {
     pub(crate) index_locations: IndexLocations,
     pub(crate) refresh: Refresh,
     pub(crate) extras: ExtrasSpecification,
-    pub(crate) dev: bool,
+    pub(crate) dev: DevSpecification,
+    pub(crate) modifications: Modifications,
     pub(crate) python: Option<String>,
+    pub(crate) lock_options: LockOptions,
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) all_packages: bool,
 }
 
 impl SyncSettings {
     /// Resolve the [`SyncSettings`] from the CLI and workspace configuration.
-    #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: SyncArgs, _workspace: Option<Workspace>) -> Self {
+    pub(crate) fn resolve(args: SyncArgs, workspace: Option<Workspace>) -> Self {
         let SyncArgs {
             extra,
             all_extras,
             no_all_extras,
             dev,
             no_dev,
+            group,
+            no_group,
+            only_group,
+            inexact,
+            exact,
             refresh,
             no_refresh,
             refresh_package,
             index_args,
             python,
+            package,
+            all_packages,
         } = args;
 
+        let modifications = if flag(exact, inexact).unwrap_or(true) {
+            Modifications::Exact
+        } else {
+            Modifications::Sufficient
+        };
+
         Self {
             index_locations: IndexLocations::from(index_args),
             refresh: Refresh::from_args(flag(refresh, no_refresh), refresh_package),
@@ -296,8 +343,29 @@ impl SyncSettings {
                 flag(all_extras, no_all_extras).unwrap_or_default(),
                 extra.unwrap_or_default(),
             ),
-            dev: flag(dev, no_dev).unwrap_or(true),
+            dev: DevSpecification::from_args(
+                if flag(dev, no_dev).unwrap_or(true) {
+                    vec![DEV_DEPENDENCIES.clone()]
+                } else {
+                    vec![]
+                },
+                group.unwrap_or_default(),
+                no_group.unwrap_or_default(),
+                only_group.unwrap_or_default(),
+            ),
+            modifications,
             python,
+            lock_options: lock_options(
+                None,
+                None,
+                None,
+                None,
+                &workspace
+                    .map(|workspace| workspace.options)
+                    .unwrap_or_default(),
+            ),
+            package,
+            all_packages,
         }
     }
 }
@@ -309,14 +377,17 @@ pub(crate) struct LockSettings {
     pub(crate) index_locations: IndexLocations,
     pub(crate) refresh: Refresh,
     pub(crate) upgrade: Upgrade,
+    pub(crate) from: Option<PathBuf>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) lock_options: LockOptions,
     pub(crate) python: Option<String>,
+    pub(crate) dry_run: bool,
+    pub(crate) check_format: bool,
 }
 
 impl LockSettings {
     /// Resolve the [`LockSettings`] from the CLI and workspace configuration.
-    #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: LockArgs, _workspace: Option<Workspace>) -> Self {
+    pub(crate) fn resolve(args: LockArgs, workspace: Option<Workspace>) -> Self {
         let LockArgs {
             refresh,
             no_refresh,
@@ -324,17 +395,52 @@ impl LockSettings {
             upgrade,
             no_upgrade,
             upgrade_package,
+            from,
             index_args,
             exclude_newer,
+            exclude_newer_cooldown,
+            exclude_newer_package,
+            resolution,
+            resolution_package,
+            prerelease,
+            prerelease_package,
             python,
+            dry_run,
+            check_format,
         } = args;
 
+        let options = workspace
+            .map(|workspace| workspace.options)
+            .unwrap_or_default();
+        let lock_options = lock_options(
+            resolution,
+            resolution_package,
+            prerelease,
+            prerelease_package,
+            &options,
+        );
+        let pip = options.pip.unwrap_or_default();
+
         Self {
             index_locations: IndexLocations::from(index_args),
             refresh: Refresh::from_args(flag(refresh, no_refresh), refresh_package),
             upgrade: Upgrade::from_args(flag(upgrade, no_upgrade), upgrade_package),
-            exclude_newer,
+            from,
+            exclude_newer: ExcludeNewer::from_args(
+                exclude_newer.combine(pip.exclude_newer),
+                exclude_newer_cooldown.combine(pip.exclude_newer_cooldown),
+                exclude_newer_package
+                    .map(|exclude_newer_package| {
+                        exclude_newer_package
+                            .into_iter()
+                            .collect::<ExcludeNewerPackage>()
+                    })
+                    .combine(pip.exclude_newer_package),
+            ),
+            lock_options,
             python,
+            dry_run,
+            check_format,
         }
     }
 }
@@ -345,20 +451,32 @@ impl LockSettings {
 pub(crate) struct AddSettings {
     pub(crate) requirements: Vec<String>,
     pub(crate) python: Option<String>,
+    pub(crate) script: Option<PathBuf>,
+    pub(crate) lock_options: LockOptions,
 }
 
 impl AddSettings {
     /// Resolve the [`AddSettings`] from the CLI and workspace configuration.
-    #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: AddArgs, _workspace: Option<Workspace>) -> Self {
+    pub(crate) fn resolve(args: AddArgs, workspace: Option<Workspace>) -> Self {
         let AddArgs {
             requirements,
             python,
+            script,
         } = args;
 
         Self {
             requirements,
             python,
+            script,
+            lock_options: lock_options(
+                None,
+                None,
+                None,
+                None,
+                &workspace
+                    .map(|workspace| workspace.options)
+                    .unwrap_or_default(),
+            ),
         }
     }
 }
@@ -369,12 +487,12 @@ impl AddSettings {
 pub(crate) struct RemoveSettings {
     pub(crate) requirements: Vec<PackageName>,
     pub(crate) python: Option<String>,
+    pub(crate) lock_options: LockOptions,
 }
 
 impl RemoveSettings {
     /// Resolve the [`RemoveSettings`] from the CLI and workspace configuration.
-    #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: RemoveArgs, _workspace: Option<Workspace>) -> Self {
+    pub(crate) fn resolve(args: RemoveArgs, workspace: Option<Workspace>) -> Self {
         let RemoveArgs {
             requirements,
             python,
@@ -383,6 +501,15 @@ impl RemoveSettings {
         Self {
             requirements,
             python,
+            lock_options: lock_options(
+                None,
+                None,
+                None,
+                None,
+                &workspace
+                    .map(|workspace| workspace.options)
+                    .unwrap_or_default(),
+            ),
         }
     }
 }
@@ -413,7 +540,9 @@ impl PipCompileSettings {
             no_deps,
             deps,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
             pre,
             output_file,
             no_strip_extras,
@@ -451,6 +580,8 @@ impl PipCompileSettings {
             python_version,
             python_platform,
             exclude_newer,
+            exclude_newer_cooldown,
+            exclude_newer_package,
             no_emit_package,
             emit_index_url,
             no_emit_index_url,
@@ -511,11 +642,21 @@ impl PipCompileSettings {
                     all_extras: flag(all_extras, no_all_extras),
                     no_deps: flag(no_deps, deps),
                     resolution,
+                    resolution_package: resolution_package.map(|resolution_package| {
+                        resolution_package
+                            .into_iter()
+                            .collect::<PackageOverrides<ResolutionMode>>()
+                    }),
                     prerelease: if pre {
                         Some(PreReleaseMode::Allow)
                     } else {
                         prerelease
                     },
+                    prerelease_package: prerelease_package.map(|prerelease_package| {
+                        prerelease_package
+                            .into_iter()
+                            .collect::<PackageOverrides<PreReleaseMode>>()
+                    }),
                     output_file,
                     no_strip_extras: flag(no_strip_extras, strip_extras),
                     no_annotate: flag(no_annotate, annotate),
@@ -529,6 +670,12 @@ impl PipCompileSettings {
                     python_version,
                     python_platform,
                     exclude_newer,
+                    exclude_newer_cooldown,
+                    exclude_newer_package: exclude_newer_package.map(|exclude_newer_package| {
+                        exclude_newer_package
+                            .into_iter()
+                            .collect::<ExcludeNewerPackage>()
+                    }),
                     no_emit_package,
                     emit_index_url: flag(emit_index_url, no_emit_index_url),
                     emit_find_links: flag(emit_find_links, no_emit_find_links),
@@ -572,6 +719,7 @@ impl PipSyncSettings {
             no_refresh,
             refresh_package,
             link_mode,
+            on_conflict,
             index_args,
             index_strategy,
             require_hashes,
@@ -594,12 +742,15 @@ impl PipSyncSettings {
             only_binary,
             compile_bytecode,
             no_compile_bytecode,
+            compile_optimization,
             config_setting,
             python_version,
             python_platform,
             strict,
             no_strict,
             exclude_newer,
+            exclude_newer_cooldown,
+            exclude_newer_package,
             dry_run,
             compat_args: _,
         } = args;
@@ -643,8 +794,16 @@ impl PipSyncSettings {
                     python_version,
                     python_platform,
                     exclude_newer,
+                    exclude_newer_cooldown,
+                    exclude_newer_package: exclude_newer_package.map(|exclude_newer_package| {
+                        exclude_newer_package
+                            .into_iter()
+                            .collect::<ExcludeNewerPackage>()
+                    }),
                     link_mode,
+                    on_conflict,
                     compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+                    compile_optimization,
                     require_hashes: flag(require_hashes, no_require_hashes),
                     concurrent_builds: env(env::CONCURRENT_BUILDS),
                     concurrent_downloads: env(env::CONCURRENT_DOWNLOADS),
@@ -669,6 +828,7 @@ pub(crate) struct PipInstallSettings {
     pub(crate) upgrade: Upgrade,
     pub(crate) reinstall: Reinstall,
     pub(crate) refresh: Refresh,
+    pub(crate) groups: Vec<GroupName>,
     pub(crate) dry_run: bool,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) pip: PipSettings,
@@ -686,6 +846,7 @@ impl PipInstallSettings {
             extra,
             all_extras,
             no_all_extras,
+            group,
             upgrade,
             no_upgrade,
             upgrade_package,
@@ -698,8 +859,11 @@ impl PipInstallSettings {
             no_deps,
             deps,
             link_mode,
+            on_conflict,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
             pre,
             index_args,
             index_strategy,
@@ -713,6 +877,9 @@ impl PipInstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            user,
+            no_user,
+            root,
             legacy_setup_py,
             no_legacy_setup_py,
             no_build_isolation,
@@ -723,14 +890,16 @@ impl PipInstallSettings {
             only_binary,
             compile_bytecode,
             no_compile_bytecode,
+            compile_optimization,
             config_setting,
             python_version,
             python_platform,
             strict,
             no_strict,
             exclude_newer,
+            exclude_newer_cooldown,
+            exclude_newer_package,
             dry_run,
-            compat_args: _,
         } = args;
 
         let overrides_from_workspace = if let Some(workspace) = &workspace {
@@ -760,6 +929,7 @@ impl PipInstallSettings {
             upgrade: Upgrade::from_args(flag(upgrade, no_upgrade), upgrade_package),
             reinstall: Reinstall::from_args(flag(reinstall, no_reinstall), reinstall_package),
             refresh: Refresh::from_args(flag(refresh, no_refresh), refresh_package),
+            groups: group.unwrap_or_default(),
             dry_run,
             overrides_from_workspace,
             pip: PipSettings::combine(
@@ -769,6 +939,8 @@ impl PipInstallSettings {
                     break_system_packages: flag(break_system_packages, no_break_system_packages),
                     target,
                     prefix,
+                    user: flag(user, no_user),
+                    root,
                     index_url: index_args.index_url.and_then(Maybe::into_option),
                     extra_index_url: index_args.extra_index_url.map(|extra_index_urls| {
                         extra_index_urls
@@ -789,11 +961,21 @@ impl PipInstallSettings {
                     all_extras: flag(all_extras, no_all_extras),
                     no_deps: flag(no_deps, deps),
                     resolution,
+                    resolution_package: resolution_package.map(|resolution_package| {
+                        resolution_package
+                            .into_iter()
+                            .collect::<PackageOverrides<ResolutionMode>>()
+                    }),
                     prerelease: if pre {
                         Some(PreReleaseMode::Allow)
                     } else {
                         prerelease
                     },
+                    prerelease_package: prerelease_package.map(|prerelease_package| {
+                        prerelease_package
+                            .into_iter()
+                            .collect::<PackageOverrides<PreReleaseMode>>()
+                    }),
                     legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
                     config_settings: config_setting.map(|config_settings| {
                         config_settings.into_iter().collect::<ConfigSettings>()
@@ -801,8 +983,16 @@ impl PipInstallSettings {
                     python_version,
                     python_platform,
                     exclude_newer,
+                    exclude_newer_cooldown,
+                    exclude_newer_package: exclude_newer_package.map(|exclude_newer_package| {
+                        exclude_newer_package
+                            .into_iter()
+                            .collect::<ExcludeNewerPackage>()
+                    }),
                     link_mode,
+                    on_conflict,
                     compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+                    compile_optimization,
                     require_hashes: flag(require_hashes, no_require_hashes),
                     concurrent_builds: env(env::CONCURRENT_BUILDS),
                     concurrent_downloads: env(env::CONCURRENT_DOWNLOADS),
@@ -1009,6 +1199,8 @@ impl PipCheckSettings {
 pub(crate) struct VenvSettings {
     pub(crate) seed: bool,
     pub(crate) allow_existing: bool,
+    pub(crate) clear: bool,
+    pub(crate) upgrade: bool,
     pub(crate) name: PathBuf,
     pub(crate) prompt: Option<String>,
     pub(crate) system_site_packages: bool,
@@ -1024,6 +1216,8 @@ impl VenvSettings {
             no_system,
             seed,
             allow_existing,
+            clear,
+            upgrade,
             name,
             prompt,
             system_site_packages,
@@ -1040,6 +1234,8 @@ impl VenvSettings {
         Self {
             seed,
             allow_existing,
+            clear,
+            upgrade,
             name,
             prompt,
             system_site_packages,
@@ -1080,6 +1276,8 @@ pub(crate) struct PipSettings {
     pub(crate) break_system_packages: bool,
     pub(crate) target: Option<Target>,
     pub(crate) prefix: Option<Prefix>,
+    pub(crate) user: bool,
+    pub(crate) root: Option<Root>,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) no_binary: NoBinary,
@@ -1088,7 +1286,9 @@ pub(crate) struct PipSettings {
     pub(crate) strict: bool,
     pub(crate) dependency_mode: DependencyMode,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_package: PackageOverrides<ResolutionMode>,
     pub(crate) prerelease: PreReleaseMode,
+    pub(crate) prerelease_package: PackageOverrides<PreReleaseMode>,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
     pub(crate) no_annotate: bool,
@@ -1107,7 +1307,9 @@ pub(crate) struct PipSettings {
     pub(crate) emit_index_annotation: bool,
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
+    pub(crate) on_conflict: FileConflictStrategy,
     pub(crate) compile_bytecode: bool,
+    pub(crate) compile_optimization: Vec<BytecodeOptimization>,
     pub(crate) require_hashes: bool,
     pub(crate) concurrency: Concurrency,
 }
@@ -1121,6 +1323,8 @@ impl PipSettings {
             break_system_packages,
             target,
             prefix,
+            user,
+            root,
             index_url,
             extra_index_url,
             no_index,
@@ -1136,7 +1340,9 @@ impl PipSettings {
             all_extras,
             no_deps,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
             output_file,
             no_strip_extras,
             no_annotate,
@@ -1148,6 +1354,8 @@ impl PipSettings {
             python_version,
             python_platform,
             exclude_newer,
+            exclude_newer_cooldown,
+            exclude_newer_package,
             no_emit_package,
             emit_index_url,
             emit_find_links,
@@ -1155,7 +1363,9 @@ impl PipSettings {
             emit_index_annotation,
             annotation_style,
             link_mode,
+            on_conflict,
             compile_bytecode,
+            compile_optimization,
             require_hashes,
             concurrent_builds,
             concurrent_downloads,
@@ -1183,7 +1393,15 @@ impl PipSettings {
                 DependencyMode::Transitive
             },
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
+            resolution_package: args
+                .resolution_package
+                .combine(resolution_package)
+                .unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            prerelease_package: args
+                .prerelease_package
+                .combine(prerelease_package)
+                .unwrap_or_default(),
             output_file: args.output_file.combine(output_file),
             no_strip_extras: args
                 .no_strip_extras
@@ -1231,7 +1449,11 @@ impl PipSettings {
                 .unwrap_or_default(),
             python_version: args.python_version.combine(python_version),
             python_platform: args.python_platform.combine(python_platform),
-            exclude_newer: args.exclude_newer.combine(exclude_newer),
+            exclude_newer: ExcludeNewer::from_args(
+                args.exclude_newer.combine(exclude_newer),
+                args.exclude_newer_cooldown.combine(exclude_newer_cooldown),
+                args.exclude_newer_package.combine(exclude_newer_package),
+            ),
             no_emit_package: args
                 .no_emit_package
                 .combine(no_emit_package)
@@ -1253,6 +1475,7 @@ impl PipSettings {
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
+            on_conflict: args.on_conflict.combine(on_conflict).unwrap_or_default(),
             require_hashes: args
                 .require_hashes
                 .combine(require_hashes)
@@ -1265,11 +1488,22 @@ impl PipSettings {
                 .unwrap_or_default(),
             target: args.target.combine(target).map(Target::from),
             prefix: args.prefix.combine(prefix).map(Prefix::from),
+            user: args.user.combine(user).unwrap_or_default(),
+            root: args.root.combine(root).map(Root::from),
             no_binary: NoBinary::from_args(args.no_binary.combine(no_binary).unwrap_or_default()),
             compile_bytecode: args
                 .compile_bytecode
                 .combine(compile_bytecode)
                 .unwrap_or_default(),
+            compile_optimization: {
+                let mut compile_optimization = args
+                    .compile_optimization
+                    .combine(compile_optimization)
+                    .unwrap_or_else(|| vec![BytecodeOptimization::default()]);
+                compile_optimization.sort_unstable();
+                compile_optimization.dedup();
+                compile_optimization
+            },
             strict: args.strict.combine(strict).unwrap_or_default(),
             concurrency: Concurrency {
                 downloads: args
@@ -1357,3 +1591,37 @@ impl From<IndexArgs> for IndexLocations {
         )
     }
 }
+
+/// Resolve the [`LockOptions`] for a project command from the CLI and the `[tool.uv]`
+/// configuration, such that every project command that locks agrees on the resolver settings.
+fn lock_options(
+    resolution: Option<ResolutionMode>,
+    resolution_package: Option<Vec<(PackageName, ResolutionMode)>>,
+    prerelease: Option<PreReleaseMode>,
+    prerelease_package: Option<Vec<(PackageName, PreReleaseMode)>>,
+    options: &Options,
+) -> LockOptions {
+    let pip = options.pip.clone().unwrap_or_default();
+    LockOptions {
+        resolution_mode: resolution.combine(pip.resolution).unwrap_or_default(),
+        resolution_package: resolution_package
+            .map(|resolution_package| {
+                resolution_package
+                    .into_iter()
+                    .collect::<PackageOverrides<_>>()
+            })
+            .combine(pip.resolution_package)
+            .unwrap_or_default(),
+        prerelease_mode: prerelease.combine(pip.prerelease).unwrap_or_default(),
+        prerelease_package: prerelease_package
+            .map(|prerelease_package| {
+                prerelease_package
+                    .into_iter()
+                    .collect::<PackageOverrides<_>>()
+            })
+            .combine(pip.prerelease_package)
+            .unwrap_or_default(),
+        constraints: options.constraint_dependencies.clone().unwrap_or_default(),
+        overrides: options.override_dependencies.clone().unwrap_or_default(),
+    }
+}
@@ -127,6 +127,57 @@ fn prune_stale_directory() -> Result<()> {
     Ok(())
 }
 
+/// `cache prune` should remove any cached environments.
+#[test]
+fn prune_cached_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio")?;
+
+    // Install a requirement, to populate the cache.
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Add a cached environment to the cache.
+    let environment = context
+        .cache_dir
+        .child("cached-environments-v0")
+        .child("abc");
+    environment.create_dir_all()?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"uv \d+\.\d+\.\d+ \(.*\)", r"uv [VERSION] ([COMMIT] DATE)")])
+        .collect();
+
+    uv_snapshot!(filters, prune_command(&context).arg("--verbose"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/cached-environments-v0/abc`
+    DEBUG Removing cached environment: [CACHE_DIR]/cached-environments-v0/abc
+    Removed 1 directory
+    "###);
+
+    // The environment should be removed, along with its lockfile.
+    environment.assert(predicates::path::missing());
+    context
+        .cache_dir
+        .child("cached-environments-v0")
+        .child("abc.lock")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
 /// `cache prune` should remove any stale symlink from the cache.
 #[test]
 fn prune_stale_symlink() -> Result<()> {
@@ -137,6 +137,12 @@ impl TestContext {
         // Destroy any remaining UNC prefixes (Windows only)
         filters.push((r"\\\\\?\\".to_string(), String::new()));
 
+        // Filter the upload times recorded in lockfiles, which are reported by the index
+        filters.push((
+            r#"upload-time = "[^"]+""#.to_string(),
+            r#"upload-time = "[UPLOAD_TIME]""#.to_string(),
+        ));
+
         // Add Python patch version filtering unless explicitly requested to ensure
         // snapshots are patch version agnostic when it is not a part of the test.
         if python_version.patch().is_none() {
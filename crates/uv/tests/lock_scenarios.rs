@@ -72,7 +72,7 @@ fn fork_basic() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.8"
 
         [[distribution]]
@@ -106,6 +106,12 @@ fn fork_basic() -> Result<()> {
         version = "2.0.0"
         source = "registry+https://astral-sh.github.io/packse/0.3.24/simple-html/"
         marker = "sys_platform == 'linux'"
+
+        [distribution.metadata]
+        requires-dist = [
+        	{ name = "package-a", specifier = "<2", marker = "sys_platform == 'darwin'" },
+        	{ name = "package-a", specifier = ">=2", marker = "sys_platform == 'linux'" }
+        ]
         "###
         );
     });
@@ -181,7 +187,7 @@ fn fork_marker_accrue() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.8"
 
         [[distribution]]
@@ -234,6 +240,12 @@ fn fork_marker_accrue() -> Result<()> {
         version = "1.0.0"
         source = "registry+https://astral-sh.github.io/packse/0.3.24/simple-html/"
         marker = "implementation_name == 'pypy'"
+
+        [distribution.metadata]
+        requires-dist = [
+        	{ name = "package-a", specifier = "==1.0.0", marker = "implementation_name == 'cpython'" },
+        	{ name = "package-b", specifier = "==1.0.0", marker = "implementation_name == 'pypy'" }
+        ]
         "###
         );
     });
@@ -375,7 +387,7 @@ fn fork_marker_selection() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.8"
 
         [[distribution]]
@@ -438,6 +450,13 @@ fn fork_marker_selection() -> Result<()> {
         version = "2.0.0"
         source = "registry+https://astral-sh.github.io/packse/0.3.24/simple-html/"
         marker = "sys_platform == 'linux'"
+
+        [distribution.metadata]
+        requires-dist = [
+        	{ name = "package-a" },
+        	{ name = "package-b", specifier = "<2", marker = "sys_platform == 'darwin'" },
+        	{ name = "package-b", specifier = ">=2", marker = "sys_platform == 'linux'" }
+        ]
         "###
         );
     });
@@ -526,7 +545,7 @@ fn fork_marker_track() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.8"
 
         [[distribution]]
@@ -602,6 +621,13 @@ fn fork_marker_track() -> Result<()> {
         version = "2.8"
         source = "registry+https://astral-sh.github.io/packse/0.3.24/simple-html/"
         marker = "sys_platform == 'linux'"
+
+        [distribution.metadata]
+        requires-dist = [
+        	{ name = "package-a" },
+        	{ name = "package-b", specifier = "<2.8", marker = "sys_platform == 'darwin'" },
+        	{ name = "package-b", specifier = ">=2.8", marker = "sys_platform == 'linux'" }
+        ]
         "###
         );
     });
@@ -676,7 +702,7 @@ fn fork_non_fork_marker_transitive() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.8"
 
         [[distribution]]
@@ -727,6 +753,12 @@ fn fork_non_fork_marker_transitive() -> Result<()> {
         name = "package-b"
         version = "1.0.0"
         source = "registry+https://astral-sh.github.io/packse/0.3.24/simple-html/"
+
+        [distribution.metadata]
+        requires-dist = [
+        	{ name = "package-a", specifier = "==1.0.0" },
+        	{ name = "package-b", specifier = "==1.0.0" }
+        ]
         "###
         );
     });
@@ -936,7 +968,7 @@ fn fork_requires_python_full_prerelease() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.10"
 
         [[distribution]]
@@ -944,6 +976,9 @@ fn fork_requires_python_full_prerelease() -> Result<()> {
         version = "0.1.0"
         source = "editable+."
         sdist = { path = "." }
+
+        [distribution.metadata]
+        requires-dist = [{ name = "package-a", specifier = "==1.0.0", marker = "python_full_version == '3.9b1'" }]
         "###
         );
     });
@@ -1008,7 +1043,7 @@ fn fork_requires_python_full() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.10"
 
         [[distribution]]
@@ -1016,6 +1051,9 @@ fn fork_requires_python_full() -> Result<()> {
         version = "0.1.0"
         source = "editable+."
         sdist = { path = "." }
+
+        [distribution.metadata]
+        requires-dist = [{ name = "package-a", specifier = "==1.0.0", marker = "python_full_version == '3.9'" }]
         "###
         );
     });
@@ -1083,7 +1121,7 @@ fn fork_requires_python_patch_overlap() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.10.1"
 
         [[distribution]]
@@ -1104,6 +1142,9 @@ fn fork_requires_python_patch_overlap() -> Result<()> {
         version = "1.0.0"
         source = "registry+https://astral-sh.github.io/packse/0.3.24/simple-html/"
         marker = "python_version == '3.10'"
+
+        [distribution.metadata]
+        requires-dist = [{ name = "package-a", specifier = "==1.0.0", marker = "python_version == '3.10'" }]
         "###
         );
     });
@@ -1166,7 +1207,7 @@ fn fork_requires_python() -> Result<()> {
     }, {
         assert_snapshot!(
             lock, @r###"
-        version = 1
+        version = 2
         requires-python = ">=3.10"
 
         [[distribution]]
@@ -1174,6 +1215,9 @@ fn fork_requires_python() -> Result<()> {
         version = "0.1.0"
         source = "editable+."
         sdist = { path = "." }
+
+        [distribution.metadata]
+        requires-dist = [{ name = "package-a", specifier = "==1.0.0", marker = "python_version == '3.9'" }]
         "###
         );
     });
@@ -2585,6 +2585,67 @@ fn compile_exclude_newer() -> Result<()> {
     Ok(())
 }
 
+/// Override the `--exclude-newer` cutoff for a specific package.
+#[test]
+fn compile_exclude_newer_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm")?;
+
+    // 4.64.0: 2022-04-04T01:48:46.194635Z1
+    // 4.64.1: 2022-09-03T11:10:27.148080Z
+    uv_snapshot!(context.filters(), Command::new(get_bin())
+            .arg("pip")
+            .arg("compile")
+            .arg("requirements.in")
+            .arg("--exclude-newer")
+            .arg("2022-04-04T12:00:00Z")
+            .arg("--exclude-newer-package")
+            .arg("tqdm=2022-09-04")
+            .arg("--cache-dir")
+            .arg(context.cache_dir.path())
+            .env("VIRTUAL_ENV", context.venv.as_os_str())
+            .env("UV_NO_WRAP", "1")
+            .current_dir(context.temp_dir.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile requirements.in --exclude-newer 2022-04-04T12:00:00Z --exclude-newer-package tqdm=2022-09-04 --cache-dir [CACHE_DIR]
+    tqdm==4.64.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Check the error message for an entry without a value.
+    uv_snapshot!(context.filters(), Command::new(get_bin())
+            .arg("pip")
+            .arg("compile")
+            .arg("requirements.in")
+            .arg("--exclude-newer-package")
+            .arg("tqdm")
+            .arg("--cache-dir")
+            .arg(context.cache_dir.path())
+            .env("VIRTUAL_ENV", context.venv.as_os_str())
+            .env("UV_NO_WRAP", "1")
+            .current_dir(context.temp_dir.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'tqdm' for '--exclude-newer-package <EXCLUDE_NEWER_PACKAGE>': Invalid `exclude-newer-package` entry: tqdm (expected `PACKAGE=DATE` or `PACKAGE=false`)
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a local path dependency on a specific wheel.
 #[test]
 fn compile_wheel_path_dependency() -> Result<()> {
@@ -2962,6 +3023,41 @@ fn compile_yanked_version_indirect() -> Result<()> {
     Ok(())
 }
 
+/// Report a resolution failure involving a yanked version in a machine-readable format.
+#[test]
+fn compile_yanked_version_indirect_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("attrs>20.3.0,<21.2.0")?;
+
+    let output = context
+        .compile()
+        .arg("requirements.in")
+        .arg("--error-format")
+        .arg("json")
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let incompatibilities = report["incompatibilities"].as_array().unwrap();
+    assert!(incompatibilities.iter().any(|incompatibility| {
+        incompatibility["kind"] == "unavailable"
+            && incompatibility["package"] == "attrs"
+            && incompatibility["reason"] == "yanked"
+    }));
+    assert_eq!(
+        incompatibilities.last().unwrap()["kind"],
+        serde_json::json!("derived")
+    );
+    assert_eq!(report["forks"], serde_json::json!([]));
+    assert!(report["message"]
+        .as_str()
+        .unwrap()
+        .contains("attrs==21.1.0 was yanked"));
+
+    Ok(())
+}
+
 /// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that we can override this
 /// requirement with an incompatible version.
 #[test]
@@ -6241,6 +6337,57 @@ fn compile_constraints_incompatible_version() -> Result<()> {
     Ok(())
 }
 
+/// Suggest relaxing a direct requirement that no available version satisfies.
+#[test]
+fn compile_relax_requirement_hint() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("idna>=3.7")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--no-deps"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only idna<3.7 is available and you require idna>=3.7, we can conclude that the requirements are unsatisfiable.
+
+          hint: Lowering idna>=3.7 to idna==3.6 would allow the requirements to be satisfied
+    "###
+    );
+
+    Ok(())
+}
+
+/// Suggest raising a direct requirement that excludes every available version, selecting the
+/// highest available version.
+#[test]
+fn compile_relax_requirement_raise_hint() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig<0.1")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--no-deps"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only iniconfig>=0.1 is available and you require iniconfig<0.1, we can conclude that the requirements are unsatisfiable.
+
+          hint: Raising iniconfig<0.1 to iniconfig==2.0.0 would allow the requirements to be satisfied
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with a `constraints.txt` file pinning one of
 /// its direct dependencies to an incompatible version.
 #[test]
@@ -6579,6 +6726,128 @@ fn pre_alias() -> Result<()> {
     Ok(())
 }
 
+/// Disallow pre-releases for a single package with `--prerelease-package`, while allowing them
+/// elsewhere.
+#[test]
+fn pre_release_package_override() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask<2.0.0rc4")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--prerelease=allow")
+            .arg("--prerelease-package")
+            .arg("flask=disallow"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --prerelease=allow --prerelease-package flask=disallow
+    click==7.1.2
+        # via flask
+    flask==1.1.4
+        # via -r requirements.in
+    itsdangerous==1.1.0
+        # via flask
+    jinja2==2.11.3
+        # via flask
+    markupsafe==2.1.5
+        # via jinja2
+    werkzeug==1.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a single package to its lowest compatible version with `--resolution-package`, while
+/// resolving the rest to their highest compatible versions.
+#[test]
+fn resolution_package_override() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--resolution-package")
+            .arg("idna=lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --resolution-package idna=lowest
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==2.8
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Read per-package resolution and pre-release overrides from `[tool.uv.pip]`.
+#[test]
+fn package_overrides_from_config() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.0.0"
+
+        [tool.uv.pip]
+        prerelease = "allow"
+
+        [tool.uv.pip.prerelease-package]
+        flask = "disallow"
+
+        [tool.uv.pip.resolution-package]
+        click = "lowest"
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask<2.0.0rc4")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in
+    click==5.1
+        # via flask
+    flask==1.1.4
+        # via -r requirements.in
+    itsdangerous==1.1.0
+        # via flask
+    jinja2==2.11.3
+        # via flask
+    markupsafe==2.1.5
+        # via jinja2
+    werkzeug==1.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Allow a pre-release for a version specifier in a constraint file.
 #[test]
 fn pre_release_constraint() -> Result<()> {
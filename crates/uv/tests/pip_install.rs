@@ -13,7 +13,7 @@ use url::Url;
 use common::{uv_snapshot, TestContext};
 use uv_fs::Simplified;
 
-use crate::common::{get_bin, venv_bin_path, BUILD_VENDOR_LINKS_URL};
+use crate::common::{create_venv, get_bin, venv_bin_path, BUILD_VENDOR_LINKS_URL};
 
 mod common;
 
@@ -495,6 +495,74 @@ build-backend = "poetry.core.masonry.api"
     Ok(())
 }
 
+/// Install a `pyproject.toml` file along with one of its PEP 735 dependency groups.
+#[test]
+fn install_pyproject_toml_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "project"
+version = "0.1.0"
+dependencies = ["iniconfig"]
+
+[dependency-groups]
+lint = ["typing-extensions"]
+test = [{ include-group = "lint" }, "sniffio"]
+"#,
+    )?;
+
+    uv_snapshot!(context.install()
+            .arg("-r")
+            .arg("pyproject.toml")
+            .arg("--group")
+            .arg("test"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+     + typing-extensions==4.10.0
+    "###
+    );
+
+    // Requesting an undefined group should fail.
+    uv_snapshot!(context.install()
+            .arg("-r")
+            .arg("pyproject.toml")
+            .arg("--group")
+            .arg("docs"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Group `docs` is not defined in: `pyproject.toml`
+    "###
+    );
+
+    // Groups can't be requested without a `pyproject.toml`.
+    uv_snapshot!(context.install()
+            .arg("iniconfig")
+            .arg("--group")
+            .arg("test"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requesting groups requires a `pyproject.toml` file.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Respect installed versions when resolving.
 #[test]
 fn respect_installed_and_reinstall() -> Result<()> {
@@ -5551,3 +5619,204 @@ fn local_index() -> Result<()> {
 
     Ok(())
 }
+
+/// Detect files that a package would overwrite, when they belong to another installed package.
+#[test]
+fn install_file_conflict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create several packages that all provide the `shared` module.
+    for name in ["first", "second", "third", "fourth"] {
+        let project = context.temp_dir.child(name);
+        project.child("pyproject.toml").write_str(&format!(
+            indoc! {r#"
+            [project]
+            name = "{}"
+            version = "0.1.0"
+
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+            "#},
+            name
+        ))?;
+        project.child("shared").child("__init__.py").touch()?;
+    }
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("./first"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+    "###
+    );
+
+    // With `--on-conflict fail`, abort before modifying the environment.
+    uv_snapshot!(context.filters(), context.install()
+        .arg("./second")
+        .arg("--on-conflict")
+        .arg("fail"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    error: Installation would overwrite files that belong to other packages:
+    `second` overwrites 1 file previously installed by `first`:
+      shared/__init__.py
+    "###
+    );
+
+    // By default, overwrite the files, but warn.
+    uv_snapshot!(context.filters(), context.install()
+        .arg("./third"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    warning: `third` overwrites 1 file previously installed by `first`:
+      shared/__init__.py
+    Installed 1 package in [TIME]
+     + third==0.1.0 (from file://[TEMP_DIR]/third)
+    "###
+    );
+
+    // With `--on-conflict allow`, overwrite the files silently.
+    uv_snapshot!(context.filters(), context.install()
+        .arg("./fourth")
+        .arg("--on-conflict")
+        .arg("allow"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + fourth==0.1.0 (from file://[TEMP_DIR]/fourth)
+    "###
+    );
+
+    Ok(())
+}
+
+/// `--user` installs aren't supported from within a virtual environment.
+#[test]
+fn install_user_virtualenv() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("iniconfig==2.0.0")
+        .arg("--user"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Can't perform a `--user` install: user site-packages are not visible in the virtual environment at [VENV]/
+    "###
+    );
+}
+
+/// Install a package relative to an alternate `--root` directory.
+#[test]
+fn install_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("iniconfig==2.0.0")
+        .arg("--root")
+        .arg("staging"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Ensure that the package was installed into the re-rooted `site-packages` directory...
+    let site_packages = fs_err::canonicalize(context.site_packages())?;
+    let relative = site_packages
+        .components()
+        .filter(|component| {
+            !matches!(
+                component,
+                std::path::Component::Prefix(_) | std::path::Component::RootDir
+            )
+        })
+        .collect::<std::path::PathBuf>();
+    assert!(context
+        .temp_dir
+        .join("staging")
+        .join(relative)
+        .join("iniconfig")
+        .join("__init__.py")
+        .is_file());
+
+    // ...and not into the virtual environment itself.
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
+/// Install from the cache via `--offline`, even if the cached index responses are missing.
+#[test]
+fn install_offline_cached_wheels() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate the cache.
+    uv_snapshot!(context.filters(), context.install()
+        .arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Remove the cached index responses, such that the wheel itself is the only record of the
+    // package.
+    fs_err::remove_dir_all(context.cache_dir.join("simple-v10"))?;
+
+    // Install with `--offline` into a fresh environment.
+    let venv = create_venv(&context.temp_dir, &context.cache_dir, "3.12");
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("iniconfig")
+        .arg("--offline")
+        .env("VIRTUAL_ENV", venv.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
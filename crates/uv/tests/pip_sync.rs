@@ -3195,7 +3195,7 @@ fn compile() -> Result<()> {
     Resolved 1 package in [TIME]
     Downloaded 1 package in [TIME]
     Installed 1 package in [TIME]
-    Bytecode compiled 3 files in [TIME]
+    Bytecode compiled 2 files in [TIME]
      + markupsafe==2.1.3
     "###
     );
@@ -3207,11 +3207,82 @@ fn compile() -> Result<()> {
         .join("__init__.cpython-312.pyc")
         .exists());
 
+    // The bytecode should be recorded in the `RECORD`.
+    let record = fs::read_to_string(
+        context
+            .site_packages()
+            .join("MarkupSafe-2.1.3.dist-info")
+            .join("RECORD"),
+    )?;
+    assert!(record.contains("markupsafe/__pycache__/__init__.cpython-312.pyc,sha256="));
+
     context.assert_command("import markupsafe").success();
 
     Ok(())
 }
 
+/// Install with bytecode compilation at multiple optimization levels.
+#[test]
+fn compile_optimization() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(sync_without_exclude_newer(&context)
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--compile-optimization")
+        .arg("0")
+        .arg("--compile-optimization")
+        .arg("2")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 2 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    let pycache = context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__");
+    assert!(pycache.join("__init__.cpython-312.pyc").exists());
+    assert!(!pycache.join("__init__.cpython-312.opt-1.pyc").exists());
+    assert!(pycache.join("__init__.cpython-312.opt-2.pyc").exists());
+
+    let record = fs::read_to_string(
+        context
+            .site_packages()
+            .join("MarkupSafe-2.1.3.dist-info")
+            .join("RECORD"),
+    )?;
+    assert!(record.contains("markupsafe/__pycache__/__init__.cpython-312.opt-2.pyc,sha256="));
+
+    // Uninstalling should remove the bytecode alongside the sources.
+    uv_snapshot!(uninstall_command(&context).arg("markupsafe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "###
+    );
+
+    assert!(!pycache.exists());
+
+    Ok(())
+}
+
 /// Test that the `PYC_INVALIDATION_MODE` option is recognized and that the error handling works.
 #[test]
 #[cfg_attr(target_os = "macos", ignore = "Fails spuriously on macOS")]
@@ -3259,7 +3330,7 @@ fn compile_invalid_pyc_invalidation_mode() -> Result<()> {
     Resolved 1 package in [TIME]
     Downloaded 1 package in [TIME]
     Installed 1 package in [TIME]
-    error: Failed to bytecode-compile Python file in: [SITE_PACKAGES]/
+    error: Failed to bytecode-compile Python files of the installed packages
       Caused by: Python process stderr:
     Invalid value for PYC_INVALIDATION_MODE "bogus", valid are "TIMESTAMP", "CHECKED_HASH", "UNCHECKED_HASH":
       Caused by: Bytecode compilation failed, expected "[SITE_PACKAGES]/[FILE].py", received: ""
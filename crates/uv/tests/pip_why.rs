@@ -0,0 +1,127 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext, EXCLUDE_NEWER};
+
+mod common;
+
+/// Create a `pip why` command with options shared across scenarios.
+fn why_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("pip")
+        .arg("why")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .arg("--exclude-newer")
+        .arg(EXCLUDE_NEWER)
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .env("UV_TEST_PYTHON_PATH", "/dev/null")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (2 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Explain a transitive dependency that's bounded by a constraint.
+#[test]
+fn why_constraint() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("idna<3.4")?;
+
+    uv_snapshot!(why_command(&context)
+            .arg("idna")
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna==3.3
+      -r requirements.in (anyio==3.7.0) -> anyio==3.7.0 (idna>=2.8) -> idna==3.3
+    Requirements on idna:
+      idna>=2.8 (anyio==3.7.0)
+      idna<3.4 (-c constraints.txt)
+    idna==3.4 is excluded by idna<3.4 (-c constraints.txt)
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Explain a direct requirement that was kept at its version by the existing output file.
+#[test]
+fn why_preference() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("idna")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("idna==3.3")?;
+
+    uv_snapshot!(why_command(&context)
+            .arg("idna")
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna==3.3
+      -r requirements.in (idna) -> idna==3.3
+    Requirements on idna:
+      idna (-r requirements.in)
+    idna==3.4 is available, but idna==3.3 was preferred by the existing output file
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // The output file should be left untouched.
+    requirements_txt.assert("idna==3.3");
+
+    Ok(())
+}
+
+/// Request an explanation for a package that isn't part of the resolution.
+#[test]
+fn why_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("idna")?;
+
+    uv_snapshot!(why_command(&context)
+            .arg("anyio")
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: `anyio` is not included in the resolution
+    "###
+    );
+
+    Ok(())
+}
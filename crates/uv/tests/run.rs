@@ -126,3 +126,158 @@ fn run_with_python_version() -> Result<()> {
 
     Ok(())
 }
+
+/// Run a PEP 723 script, with its dependencies installed into a cached environment.
+#[test]
+fn run_script() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let python_path = python_path_with_versions(&context.temp_dir, &["3.11", "3.12"])
+        .expect("Failed to create Python test path");
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig==2.0.0",
+        # ]
+        # ///
+
+        import importlib.metadata
+
+        print(importlib.metadata.version("iniconfig"))
+       "#
+    })?;
+
+    // The script's dependencies should be installed into a dedicated environment.
+    let mut command = context.run();
+    let command_with_args = command
+        .arg("--preview")
+        .arg("main.py")
+        .env("UV_TEST_PYTHON_PATH", &python_path);
+    uv_snapshot!(context.filters(), command_with_args, @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    2.0.0
+
+    ----- stderr -----
+    Reading inline script metadata from: main.py
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // Running the script again should reuse the environment.
+    let mut command = context.run();
+    let command_with_args = command
+        .arg("--preview")
+        .arg("main.py")
+        .env("UV_TEST_PYTHON_PATH", &python_path);
+    uv_snapshot!(context.filters(), command_with_args, @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    2.0.0
+
+    ----- stderr -----
+    Reading inline script metadata from: main.py
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Run a command with ephemeral requirements, reusing the cached environment on subsequent runs.
+#[test]
+fn run_with() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let python_path = python_path_with_versions(&context.temp_dir, &["3.11", "3.12"])
+        .expect("Failed to create Python test path");
+
+    // The requirements should be installed into a cached environment.
+    let mut command = context.run();
+    let command_with_args = command
+        .arg("--preview")
+        .arg("--isolated")
+        .arg("--with")
+        .arg("iniconfig==2.0.0")
+        .arg("python")
+        .arg("-c")
+        .arg("import importlib.metadata; print(importlib.metadata.version('iniconfig'))")
+        .env("UV_TEST_PYTHON_PATH", &python_path);
+    uv_snapshot!(context.filters(), command_with_args, @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    2.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // Running with the same requirements should reuse the environment.
+    let mut command = context.run();
+    let command_with_args = command
+        .arg("--preview")
+        .arg("--isolated")
+        .arg("--with")
+        .arg("iniconfig==2.0.0")
+        .arg("python")
+        .arg("-c")
+        .arg("import importlib.metadata; print(importlib.metadata.version('iniconfig'))")
+        .env("UV_TEST_PYTHON_PATH", &python_path);
+    uv_snapshot!(context.filters(), command_with_args, @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    2.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Report a failure to resolve the ephemeral requirements in a machine-readable format.
+#[test]
+fn run_with_error_format_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let python_path = python_path_with_versions(&context.temp_dir, &["3.12"])
+        .expect("Failed to create Python test path");
+
+    let output = context
+        .run()
+        .arg("--preview")
+        .arg("--isolated")
+        .arg("--error-format")
+        .arg("json")
+        .arg("--with")
+        .arg("attrs>20.3.0,<21.2.0")
+        .arg("python")
+        .arg("-c")
+        .arg("import attrs")
+        .env("UV_TEST_PYTHON_PATH", &python_path)
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert!(report["incompatibilities"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|incompatibility| {
+            incompatibility["kind"] == "unavailable"
+                && incompatibility["package"] == "attrs"
+                && incompatibility["reason"] == "yanked"
+        }));
+
+    Ok(())
+}
@@ -410,6 +410,127 @@ fn non_empty_dir_exists() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn non_empty_dir_exists_clear() -> Result<()> {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    // Create a non-empty directory at `.venv`. Creating a virtualenv at the same path with
+    // `--clear` should fail, since the directory isn't a virtualenv.
+    context.venv.create_dir_all()?;
+    context.venv.child("file").touch()?;
+
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    uv::venv::clear
+
+      × The directory `.venv` exists, but it's not a virtualenv; refusing to clear it
+    "###
+    );
+
+    context
+        .venv
+        .child("file")
+        .assert(predicates::path::is_file());
+
+    Ok(())
+}
+
+#[test]
+fn existing_venv_clear() -> Result<()> {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    // Create a virtualenv at `.venv`, and add a file to it.
+    context
+        .venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    context.venv.child("file").touch()?;
+
+    // Recreating the virtualenv with `--clear` should remove the existing contents.
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Creating virtualenv at: .venv
+    Activate with: source .venv/bin/activate
+    "###
+    );
+
+    context
+        .venv
+        .child("file")
+        .assert(predicates::path::missing());
+    context
+        .venv
+        .child("pyvenv.cfg")
+        .assert(predicates::path::is_file());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(unix)]
+fn existing_venv_clear_symlink() -> Result<()> {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    // Create a virtualenv elsewhere, and symlink it to `.venv`.
+    let target = context.temp_dir.child("target");
+    context
+        .venv_command()
+        .arg(target.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    target.child("file").touch()?;
+    std::os::unix::fs::symlink(target.path(), context.venv.path())?;
+
+    // Recreating the virtualenv with `--clear` should remove the existing contents, but preserve
+    // the symlink.
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Creating virtualenv at: .venv
+    Activate with: source .venv/bin/activate
+    "###
+    );
+
+    assert!(fs_err::symlink_metadata(context.venv.path())?.is_symlink());
+    target.child("file").assert(predicates::path::missing());
+    target
+        .child("pyvenv.cfg")
+        .assert(predicates::path::is_file());
+
+    Ok(())
+}
+
 #[test]
 fn non_empty_dir_exists_allow_existing() -> Result<()> {
     let context = VenvTestContext::new(&["3.12"]);
@@ -522,10 +643,10 @@ fn windows_shims() -> Result<()> {
 fn virtualenv_compatibility() {
     let context = VenvTestContext::new(&["3.12"]);
 
-    // Create a virtual environment at `.venv`, passing the redundant `--clear` flag.
+    // Create a virtual environment at `.venv`, passing the virtualenv-compatible `--no-wheel` flag.
     uv_snapshot!(context.filters(), context.venv_command()
         .arg(context.venv.as_os_str())
-        .arg("--clear")
+        .arg("--no-wheel")
         .arg("--python")
         .arg("3.12"), @r###"
     success: true
@@ -533,7 +654,7 @@ fn virtualenv_compatibility() {
     ----- stdout -----
 
     ----- stderr -----
-    warning: virtualenv's `--clear` has no effect (uv always clears the virtual environment).
+    warning: virtualenv's `--no-wheel` has no effect (uv omits `wheel` by default).
     Using Python 3.12.[X] interpreter at: [PATH]
     Creating virtualenv at: .venv
     Activate with: source .venv/bin/activate
@@ -642,3 +763,143 @@ fn path_with_trailing_space_gives_proper_error() {
     "###
     );
 }
+
+/// Upgrade an existing virtual environment in-place, preserving its contents.
+#[test]
+fn upgrade() -> Result<()> {
+    use predicates::prelude::PredicateBooleanExt;
+
+    let context = VenvTestContext::new(&["3.12"]);
+
+    // Create a virtual environment at `.venv`.
+    context
+        .venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    context.venv.child("file").touch()?;
+
+    // Point `home` at a stale location, as if the base interpreter were moved by an upgrade.
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?
+        .lines()
+        .map(|line| {
+            if line.starts_with("home = ") {
+                "home = /stale".to_string()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    pyvenv_cfg.write_str(&contents)?;
+
+    // Upgrade the virtual environment, defaulting to its existing minor version.
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Upgrading virtualenv at: .venv
+    "###
+    );
+
+    // The contents of the environment are preserved, and `home` is updated.
+    context
+        .venv
+        .child("file")
+        .assert(predicates::path::is_file());
+    pyvenv_cfg.assert(predicates::str::contains("home = /stale").not());
+
+    Ok(())
+}
+
+/// Upgrade a virtual environment whose `pyvenv.cfg` was written by the standard library's `venv`
+/// module, which records the Python version as `version` rather than `version_info`.
+#[test]
+fn upgrade_stdlib_pyvenv_cfg() -> Result<()> {
+    use predicates::prelude::PredicateBooleanExt;
+
+    let context = VenvTestContext::new(&["3.12"]);
+
+    // Create a virtual environment at `.venv`.
+    context
+        .venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    // Rewrite `pyvenv.cfg` in the style of the standard library, with a stale `home`.
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    let version = fs_err::read_to_string(&pyvenv_cfg)?
+        .lines()
+        .find_map(|line| {
+            line.strip_prefix("version_info = ")
+                .map(ToString::to_string)
+        })
+        .expect("`pyvenv.cfg` should contain `version_info`");
+    pyvenv_cfg.write_str(&format!(
+        "home = /stale\ninclude-system-site-packages = false\nversion = {version}\n"
+    ))?;
+
+    // Upgrade the virtual environment, defaulting to its existing minor version.
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Upgrading virtualenv at: .venv
+    "###
+    );
+
+    // The `home` is updated, and the `version` key is retained.
+    pyvenv_cfg.assert(predicates::str::contains("home = /stale").not());
+    pyvenv_cfg.assert(predicates::str::contains(format!("version = {version}")));
+
+    Ok(())
+}
+
+/// Upgrading to a different minor version is not supported.
+#[test]
+fn upgrade_incompatible_minor() {
+    let context = VenvTestContext::new(&["3.11", "3.12"]);
+
+    context
+        .venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.11")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Upgrading virtualenv at: .venv
+    uv::venv::upgrade
+
+      × Failed to upgrade virtualenv
+      ╰─▶ Can't upgrade the virtual environment at `.venv` from Python 3.11.[X] to Python 3.12.[X]; only upgrades to a patch release of the same minor version are supported
+    "###
+    );
+}
@@ -564,3 +564,159 @@ fn workspace_lock_idempotence_virtual_workspace() -> Result<()> {
     )?;
     Ok(())
 }
+
+/// Check that `uv run --all-packages` runs the command in each member of the workspace.
+#[test]
+fn test_uv_run_all_packages_virtual_workspace() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let work_dir = context.temp_dir.join("albatross-virtual-workspace");
+
+    copy_dir_ignore(
+        workspaces_dir().join("albatross-virtual-workspace"),
+        &work_dir,
+    )?;
+
+    let mut filters = context.filters();
+    filters.push((
+        r"Using Python 3.12.\[X\] interpreter at: .*",
+        "Using Python 3.12.[X] interpreter at: [PYTHON]",
+    ));
+
+    // Print the name of each member's directory, and fail in `seeds`.
+    uv_snapshot!(filters, universal_windows_filters=true, run_workspace(&context)
+        .arg("--all-packages")
+        .arg("python")
+        .arg("-c")
+        .arg("import os, sys; name = os.path.basename(os.getcwd()); print(name); sys.exit(name == 'seeds')")
+        .current_dir(&work_dir), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    albatross
+    bird-feeder
+    seeds
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PYTHON]
+    Creating virtualenv at: [VENV]/
+    Resolved 8 packages in [TIME]
+    Downloaded 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + albatross==0.1.0 (from file://[TEMP_DIR]/albatross-virtual-workspace/packages/albatross)
+     + anyio==4.3.0
+     + bird-feeder==1.0.0 (from file://[TEMP_DIR]/albatross-virtual-workspace/packages/bird-feeder)
+     + idna==3.6
+     + seeds==1.0.0 (from file://[TEMP_DIR]/albatross-virtual-workspace/packages/seeds)
+     + sniffio==1.3.1
+     + tqdm==4.66.2
+    Running in albatross ([TEMP_DIR]/albatross-virtual-workspace/packages/albatross)
+    Running in bird-feeder ([TEMP_DIR]/albatross-virtual-workspace/packages/bird-feeder)
+    Running in seeds ([TEMP_DIR]/albatross-virtual-workspace/packages/seeds)
+    albatross exited with code 0
+    bird-feeder exited with code 0
+    seeds exited with code 1
+    "###
+    );
+
+    // A relative script path should be resolved against the current directory, rather than
+    // each member's directory.
+    fs_err::write(
+        work_dir.join("main.py"),
+        "import os; print(os.path.basename(os.getcwd()))",
+    )?;
+
+    uv_snapshot!(filters, universal_windows_filters=true, run_workspace(&context)
+        .arg("--all-packages")
+        .arg("main.py")
+        .current_dir(&work_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross
+    bird-feeder
+    seeds
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    Audited 7 packages in [TIME]
+    Running in albatross ([TEMP_DIR]/albatross-virtual-workspace/packages/albatross)
+    Running in bird-feeder ([TEMP_DIR]/albatross-virtual-workspace/packages/bird-feeder)
+    Running in seeds ([TEMP_DIR]/albatross-virtual-workspace/packages/seeds)
+    albatross exited with code 0
+    bird-feeder exited with code 0
+    seeds exited with code 0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Check that `uv sync --package` and `uv sync --all-packages` install the selected members into
+/// the workspace environment.
+#[test]
+fn test_uv_sync_packages_virtual_workspace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    copy_dir_ignore(
+        workspaces_dir().join("albatross-virtual-workspace"),
+        &context.temp_dir,
+    )?;
+
+    context.lock().assert().success();
+
+    // Sync a single member.
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("seeds"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + idna==3.6
+     + seeds==1.0.0 (from file://[TEMP_DIR]/packages/seeds)
+    "###);
+
+    // Sync several members.
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("bird-feeder").arg("--package").arg("seeds"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Downloaded 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + bird-feeder==1.0.0 (from file://[TEMP_DIR]/packages/bird-feeder)
+     + sniffio==1.3.1
+    "###);
+
+    // Sync every member.
+    uv_snapshot!(context.filters(), context.sync().arg("--all-packages"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + albatross==0.1.0 (from file://[TEMP_DIR]/packages/albatross)
+     + tqdm==4.66.2
+    "###);
+
+    // Syncing a package that isn't a workspace member should fail.
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("pelican"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    error: Package `pelican` not found in workspace
+    "###);
+
+    Ok(())
+}